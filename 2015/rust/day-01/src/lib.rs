@@ -1,36 +1,60 @@
-pub fn process_part1(input: &str) -> String {
-    let mut total = 0;
-    for ch in input.chars() {
-        match ch {
-            '(' => {
-                total += 1;
-            }
-            ')' => {
-                total -= 1;
+use aoc_core::{Answer, AocError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+    type Parsed<'a> = &'a str;
+    type Part1 = i32;
+    type Part2 = Answer;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut total = 0;
+        for ch in input.chars() {
+            match ch {
+                '(' => {
+                    total += 1;
+                }
+                ')' => {
+                    total -= 1;
+                }
+                _ => {}
             }
-            _ => {}
         }
+        total
     }
-    total.to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let mut total = 0;
-    for (i, ch) in input.chars().enumerate() {
-        match ch {
-            '(' => {
-                total += 1;
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut total = 0;
+        for (i, ch) in input.chars().enumerate() {
+            match ch {
+                '(' => {
+                    total += 1;
+                }
+                ')' => {
+                    total -= 1;
+                }
+                _ => {}
             }
-            ')' => {
-                total -= 1;
+            if total == -1 {
+                return (i + 1).into();
             }
-            _ => {}
-        }
-        if total == -1 {
-            return (i + 1).to_string();
         }
+        total.into()
     }
-    total.to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part2(input)
 }
 
 // #[cfg(test)]
@@ -40,7 +64,7 @@ pub fn process_part2(input: &str) -> String {
 //     #[test]
 //     fn test_input() {
 //         let file = include_str!("../test-input-1.txt");
-//         assert_eq!(process_part1(file), "7");
-//         //assert_eq!(process_part2(file), "5");
+//         assert_eq!(process_part1(file).unwrap(), "7");
+//         //assert_eq!(process_part2(file).unwrap(), "5");
 //     }
 // }
@@ -1,6 +1,6 @@
-use day_01::process_part2;
+use aoc_2016_day_01::process_part2;
 
 fn main() {
-    let file = include_str!("../../input.txt");
-    println!("{}", process_part2(file));
+    let file = aoc_core::input::from_args(env!("CARGO_MANIFEST_DIR"));
+    aoc_core::print_or_exit(process_part2(&file));
 }
@@ -1,3 +1,4 @@
+use aoc_core::{Answer, AocError, Solution};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -84,25 +85,6 @@ fn advance(position: &Coord, direction: &Direction, count: i64) -> Coord {
     }
 }
 
-pub fn process_part1(input: &str) -> String {
-    let steps = parse(input);
-    let mut direction = Direction::North;
-    let mut position = Coord { x: 0, y: 0 };
-    for step in steps {
-        match step {
-            Instruction::L(count) => {
-                direction = turn_left(direction);
-                position = advance(&position, &direction, count);
-            }
-            Instruction::R(count) => {
-                direction = turn_right(direction);
-                position = advance(&position, &direction, count)
-            }
-        }
-    }
-    (position.x.abs() + position.y.abs()).to_string()
-}
-
 fn advance_with_history(
     mut position: Coord,
     direction: &Direction,
@@ -137,31 +119,72 @@ fn advance_with_history(
     (position, false)
 }
 
-pub fn process_part2(input: &str) -> String {
-    let steps = parse(input);
-    let mut direction = Direction::North;
-    let mut position = Coord { x: 0, y: 0 };
-    let mut history: Vec<Coord> = vec![position];
-    let mut leave: bool;
-    for step in steps {
-        match step {
-            Instruction::L(count) => {
-                direction = turn_left(direction);
-                (position, leave) = advance_with_history(position, &direction, count, &mut history);
-                if leave {
-                    break;
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 1;
+    type Parsed<'a> = &'a str;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let steps = parse(input);
+        let mut direction = Direction::North;
+        let mut position = Coord { x: 0, y: 0 };
+        for step in steps {
+            match step {
+                Instruction::L(count) => {
+                    direction = turn_left(direction);
+                    position = advance(&position, &direction, count);
+                }
+                Instruction::R(count) => {
+                    direction = turn_right(direction);
+                    position = advance(&position, &direction, count)
                 }
             }
-            Instruction::R(count) => {
-                direction = turn_right(direction);
-                (position, leave) = advance_with_history(position, &direction, count, &mut history);
-                if leave {
-                    break;
+        }
+        position.x.abs() + position.y.abs() 
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let steps = parse(input);
+        let mut direction = Direction::North;
+        let mut position = Coord { x: 0, y: 0 };
+        let mut history: Vec<Coord> = vec![position];
+        let mut leave: bool;
+        for step in steps {
+            match step {
+                Instruction::L(count) => {
+                    direction = turn_left(direction);
+                    (position, leave) = advance_with_history(position, &direction, count, &mut history);
+                    if leave {
+                        break;
+                    }
+                }
+                Instruction::R(count) => {
+                    direction = turn_right(direction);
+                    (position, leave) = advance_with_history(position, &direction, count, &mut history);
+                    if leave {
+                        break;
+                    }
                 }
             }
         }
+        position.x.abs() + position.y.abs() 
     }
-    (position.x.abs() + position.y.abs()).to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -170,9 +193,9 @@ mod tests {
 
     #[test]
     fn test_input() {
-        assert_eq!(process_part1("R2, L3"), "5");
-        assert_eq!(process_part1("R2, R2, R2"), "2");
-        assert_eq!(process_part1("R5, L5, R5, R3"), "12");
-        assert_eq!(process_part2("R8, R4, R4, R8"), "4")
+        assert_eq!(process_part1("R2, L3").unwrap(), "5");
+        assert_eq!(process_part1("R2, R2, R2").unwrap(), "2");
+        assert_eq!(process_part1("R5, L5, R5, R3").unwrap(), "12");
+        assert_eq!(process_part2("R8, R4, R4, R8").unwrap(), "4")
     }
 }
@@ -1,18 +0,0 @@
-pub fn process_part1(input: &str) -> String {
-    "works".to_string()
-}
-
-pub fn process_part2(input: &str) -> String {
-    "works".to_string()
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "works");
-    }
-}
@@ -1,14 +1,20 @@
-use day_00::*;
+use aoc_2017_day_01::*;
 
 fn main() {
     divan::main();
 }
 
+fn input() -> String {
+    aoc_core::input::from_env(env!("CARGO_MANIFEST_DIR"))
+}
+
 #[divan::bench]
-fn part1() {
-    process_part1(divan::black_box(include_str!("../input.txt")));
+fn part1(bencher: divan::Bencher) {
+    let input = input();
+    bencher.bench(|| process_part1(divan::black_box(&input)));
 }
 #[divan::bench]
-fn part2() {
-    process_part2(divan::black_box(include_str!("../input.txt")));
+fn part2(bencher: divan::Bencher) {
+    let input = input();
+    bencher.bench(|| process_part2(divan::black_box(&input)));
 }
@@ -1,59 +1,62 @@
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{self, anychar, line_ending},
-    multi::{many1, many_till},
-    sequence::terminated,
-    IResult, Parser,
-};
+use aoc_core::{Answer, AocError, Solution};
+use aoc_parse::{int, lines, parse_all};
 
-fn parse_number(input: &str) -> IResult<&str, i64> {
-    let (input, sign) = alt((tag("-"), tag("+")))(input)?;
-    let (input, num) = complete::i64(input)?;
-    let num = match sign {
-        "-" => -num,
-        "+" => num,
-        _ => panic!("invalid sign"),
-    };
-    Ok((input, num))
+fn parse(input: &str) -> Result<Vec<i64>, AocError> {
+    Ok(parse_all(input, lines(int))?)
 }
 
-fn parse(input: &str) -> Vec<i64> {
-    let (_, res) = many1(terminated(parse_number, alt((line_ending, tag("")))))(input)
-        .expect("parse should succeed");
-    res
-}
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2018;
+    const DAY: u8 = 1;
+    type Parsed<'a> = &'a str;
+    type Part1 = Result<i64, AocError>;
+    type Part2 = Result<i64, AocError>;
 
-pub fn process_part1(input: &str) -> String {
-    let changes = parse(input);
-    let mut sum = 0;
-    for change in changes {
-        sum += change;
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let changes = parse(input)?;
+        let mut sum = 0;
+        for change in changes {
+            sum += change;
+        }
+        Ok(sum)
     }
-    sum.to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let changes = parse(input);
-    let mut sum = 0;
-    let mut history = vec![sum];
-    let mut changes_iter = changes.iter();
-    loop {
-        sum += match changes_iter.next() {
-            Some(val) => val,
-            None => {
-                changes_iter = changes.iter();
-                changes_iter.next().unwrap()
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let changes = parse(input)?;
+        let mut sum = 0;
+        let mut history = vec![sum];
+        let mut changes_iter = changes.iter();
+        loop {
+            sum += match changes_iter.next() {
+                Some(val) => val,
+                None => {
+                    changes_iter = changes.iter();
+                    changes_iter.next().unwrap()
+                }
+            };
+            if history.contains(&sum) {
+                return Ok(sum);
+            } else {
+                history.push(sum);
             }
-        };
-        if history.contains(&sum) {
-            return sum.to_string();
-        } else {
-            history.push(sum);
         }
     }
 }
 
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part2(input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,6 +64,6 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "-3");
+        assert_eq!(process_part1(file).unwrap(), "-3");
     }
 }
@@ -1,29 +1,53 @@
-pub fn process_part1(input: &str) -> String {
-    let mut modules: Vec<i64> = Vec::new();
-    for line in input.lines() {
-        modules.push(line.parse().expect("parse should succeed"));
-    }
-    let mut sum = 0;
-    for module in modules {
-        sum += (module / 3) - 2;
+use aoc_core::{Answer, AocError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2019;
+    const DAY: u8 = 1;
+    type Parsed<'a> = &'a str;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
     }
-    sum.to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let mut modules: Vec<i64> = Vec::new();
-    for line in input.lines() {
-        modules.push(line.parse().expect("parse should succeed"));
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut modules: Vec<i64> = Vec::new();
+        for line in input.lines() {
+            modules.push(line.parse().expect("parse should succeed"));
+        }
+        let mut sum = 0;
+        for module in modules {
+            sum += (module / 3) - 2;
+        }
+        sum
     }
-    let mut sum = 0;
-    for module in modules {
-        let mut additonal_fuel = (module / 3) - 2;
-        while additonal_fuel > 0 {
-            sum += additonal_fuel;
-            additonal_fuel = (additonal_fuel / 3) - 2;
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut modules: Vec<i64> = Vec::new();
+        for line in input.lines() {
+            modules.push(line.parse().expect("parse should succeed"));
+        }
+        let mut sum = 0;
+        for module in modules {
+            let mut additonal_fuel = (module / 3) - 2;
+            while additonal_fuel > 0 {
+                sum += additonal_fuel;
+                additonal_fuel = (additonal_fuel / 3) - 2;
+            }
         }
+        sum
     }
-    sum.to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -33,6 +57,6 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "34241");
+        assert_eq!(process_part1(file).unwrap(), "34241");
     }
 }
@@ -1,33 +1,57 @@
-pub fn process_part1(input: &str) -> String {
-    let mut numbers: Vec<i64> = Vec::new();
-    for line in input.lines() {
-        numbers.push(line.parse().expect("parse should succeed"));
+use aoc_core::{Answer, AocError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 1;
+    type Parsed<'a> = &'a str;
+    type Part1 = Answer;
+    type Part2 = Answer;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
     }
-    for i in 1..numbers.len() {
-        for j in 0..i {
-            if numbers[i] + numbers[j] == 2020 {
-                return (numbers[i] * numbers[j]).to_string();
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut numbers: Vec<i64> = Vec::new();
+        for line in input.lines() {
+            numbers.push(line.parse().expect("parse should succeed"));
+        }
+        for i in 1..numbers.len() {
+            for j in 0..i {
+                if numbers[i] + numbers[j] == 2020 {
+                    return (numbers[i] * numbers[j]).into();
+                }
             }
         }
+        "no solution found".into()
     }
-    "no solution found".to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let mut numbers: Vec<i64> = Vec::new();
-    for line in input.lines() {
-        numbers.push(line.parse().expect("parse should succeed"));
-    }
-    for i in 2..numbers.len() {
-        for j in 1..i {
-            for k in 0..j {
-                if numbers[i] + numbers[j] + numbers[k] == 2020 {
-                    return (numbers[i] * numbers[j] * numbers[k]).to_string();
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut numbers: Vec<i64> = Vec::new();
+        for line in input.lines() {
+            numbers.push(line.parse().expect("parse should succeed"));
+        }
+        for i in 2..numbers.len() {
+            for j in 1..i {
+                for k in 0..j {
+                    if numbers[i] + numbers[j] + numbers[k] == 2020 {
+                        return (numbers[i] * numbers[j] * numbers[k]).into();
+                    }
                 }
             }
         }
+        "no solution found".into()
     }
-    "no solution found".to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -37,7 +61,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "514579");
-        assert_eq!(process_part2(file), "241861950");
+        assert_eq!(process_part1(file).unwrap(), "514579");
+        assert_eq!(process_part2(file).unwrap(), "241861950");
     }
 }
@@ -1,38 +1,61 @@
+use aoc_core::{Answer, AocError, Solution};
 use std::iter::zip;
 
-pub fn process_part1(input: &str) -> String {
-    let mut last_value: i32 = 0;
-    let mut increases: i32 = 0;
-    for (count, depth) in input.lines().enumerate() {
-        let new_depth: i32 = depth.parse().expect("should be an i32");
-        if count == 0 {
-            last_value = new_depth;
-        } else {
-            if new_depth > last_value {
-                increases += 1;
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 1;
+    type Parsed<'a> = &'a str;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut last_value: i32 = 0;
+        let mut increases: i32 = 0;
+        for (count, depth) in input.lines().enumerate() {
+            let new_depth: i32 = depth.parse().expect("should be an i32");
+            if count == 0 {
+                last_value = new_depth;
+            } else {
+                if new_depth > last_value {
+                    increases += 1;
+                }
+                last_value = new_depth;
             }
-            last_value = new_depth;
         }
+        increases
     }
-    increases.to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let mut increases: i32 = 0;
-    let depths: Vec<i32> = input
-        .lines()
-        .map(|l| l.parse::<i32>().expect("should be an i32"))
-        .collect();
-    let depths_iter1 = depths.windows(3);
-    let mut depths_iter2 = depths.windows(3);
-    depths_iter2.next();
-    for (last, new) in zip(depths_iter1, depths_iter2) {
-        if new.iter().sum::<i32>() > last.iter().sum::<i32>() {
-            increases += 1
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut increases: i32 = 0;
+        let depths: Vec<i32> = input
+            .lines()
+            .map(|l| l.parse::<i32>().expect("should be an i32"))
+            .collect();
+        let depths_iter1 = depths.windows(3);
+        let mut depths_iter2 = depths.windows(3);
+        depths_iter2.next();
+        for (last, new) in zip(depths_iter1, depths_iter2) {
+            if new.iter().sum::<i32>() > last.iter().sum::<i32>() {
+                increases += 1
+            }
         }
+
+        increases
     }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part1(input)
+}
 
-    increases.to_string()
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -42,7 +65,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "7");
-        assert_eq!(process_part2(file), "5");
+        assert_eq!(process_part1(file).unwrap(), "7");
+        assert_eq!(process_part2(file).unwrap(), "5");
     }
 }
@@ -1,32 +1,55 @@
-pub fn process_part1(input: &str) -> String {
-    let result = input
-        .split("\n\n")
-        .map(|group| {
-            group
-                .lines()
-                .filter_map(|item| item.parse::<u32>().ok())
-                .sum::<u32>()
-        })
-        .max()
-        .unwrap();
-    result.to_string()
+use aoc_core::{Answer, AocError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+    type Parsed<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let result = input
+            .split("\n\n")
+            .map(|group| {
+                group
+                    .lines()
+                    .filter_map(|item| item.parse::<u32>().ok())
+                    .sum::<u32>()
+            })
+            .max()
+            .unwrap();
+        result
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut bundles: Vec<u32> = input
+            .split("\n\n")
+            .map(|group| {
+                group
+                    .lines()
+                    .filter_map(|item| item.parse::<u32>().ok())
+                    .sum::<u32>()
+            })
+            .collect();
+        bundles.sort_by(|a,b| b.cmp(a));
+        let result: u32 = bundles.iter().take(3).sum();
+        result
+    }
 }
 
-pub fn process_part2(input: &str) -> String {
-    let mut bundles: Vec<u32> = input
-        .split("\n\n")
-        .map(|group| {
-            group
-                .lines()
-                .filter_map(|item| item.parse::<u32>().ok())
-                .sum::<u32>()
-        })
-        .collect();
-    bundles.sort_by(|a,b| b.cmp(a));
-    let result: u32 = bundles.iter().take(3).sum();
-    result.to_string()
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part1(input)
 }
 
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part2(input)
+}
 
 #[cfg(test)]
 mod tests {
@@ -36,7 +59,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "24000");
-        assert_eq!(process_part2(file.as_str()), "45000");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "24000");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "45000");
     }
 }
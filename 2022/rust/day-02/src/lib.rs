@@ -1,65 +1,100 @@
-pub fn process_part1(input: &str) -> String {
-    let result = input
-        .lines()
-        .map(|round| {
-            if round.chars().count() != 3 {dbg!(format!("incorrect input format"));}
-            round_calc(
-                round.chars().nth(0).unwrap(),
-                round.chars().nth(2).unwrap()
-            )
-        })
-        .sum::<i32>();
-    result.to_string()
+use aoc_core::{Answer, AocError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+    type Parsed<'a> = &'a str;
+    type Part1 = Result<i32, AocError>;
+    type Part2 = Result<i32, AocError>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        input
+            .lines()
+            .enumerate()
+            .map(|(number, round)| {
+                let (elf, player) = split_round(number, round)?;
+                round_calc(elf, player)
+            })
+            .sum()
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        input
+            .lines()
+            .enumerate()
+            .map(|(number, round)| {
+                let (elf, strategy) = split_round(number, round)?;
+                let player: char = strategy_adj(elf, strategy)?;
+                round_calc(elf, player)
+            })
+            .sum()
+    }
 }
 
-pub fn process_part2(input: &str) -> String {
-    let result = input
-        .lines()
-        .map(|round| {
-            if round.chars().count() != 3 {dbg!(format!("incorrect input format"));}
-            let elf: char = round.chars().nth(0).unwrap();
-            let strategy: char = round.chars().nth(2).unwrap();
-            let player: char = strategy_adj(elf, strategy);
-            round_calc(elf, player)
-        })
-        .sum::<i32>();
-    result.to_string()
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day02::solve_part1(input)
 }
 
-fn strategy_adj(elf: char, strategy: char) -> char {
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day02::solve_part2(input)
+}
+
+/// The two letters of a round like `A Y`.
+fn split_round(number: usize, round: &str) -> Result<(char, char), AocError> {
+    match round.chars().collect::<Vec<char>>()[..] {
+        [elf, ' ', player] => Ok((elf, player)),
+        _ => Err(AocError::parse(
+            number + 1,
+            1,
+            format!("expected a round like \"A Y\", got \"{round}\""),
+        )),
+    }
+}
+
+fn strategy_adj(elf: char, strategy: char) -> Result<char, AocError> {
     //X lose
     //Y draw
     //Z win
+    let unknown_elf = || AocError::unsupported(format!("the elf can't play {elf}"));
     match strategy {
         'X' => match elf {
-            'A' => 'Z',
-            'B' => 'X',
-            'C' => 'Y',
-            _ => todo!("shouldn't get here, strategy_adj, ? X")
+            'A' => Ok('Z'),
+            'B' => Ok('X'),
+            'C' => Ok('Y'),
+            _ => Err(unknown_elf()),
         },
         'Y' => match elf {
-            'A' => 'X',
-            'B' => 'Y',
-            'C' => 'Z',
-            _ => todo!("shouldn't get here, strategy_adj, ? Y")
+            'A' => Ok('X'),
+            'B' => Ok('Y'),
+            'C' => Ok('Z'),
+            _ => Err(unknown_elf()),
         },
         'Z' => match elf {
-            'A' => 'Y',
-            'B' => 'Z',
-            'C' => 'X',
-            _ => todo!("shouldn't get here, strategy_adj, ? Z")
+            'A' => Ok('Y'),
+            'B' => Ok('Z'),
+            'C' => Ok('X'),
+            _ => Err(unknown_elf()),
         }
-        _ => todo!("shouldn't get here, strategy_adj, _ ?")
+        _ => Err(AocError::unsupported(format!("there's no strategy {strategy}"))),
     }
 }
 
-fn round_calc(elf: char, player: char) -> i32 {
+fn round_calc(elf: char, player: char) -> Result<i32, AocError> {
     let shape_score: i32 = match player {
         'X' => 1,
         'Y' => 2,
         'Z' => 3,
-        _ => todo!("shouldn't get here"),
+        _ => return Err(AocError::unsupported(format!("you can't play {player}"))),
     };
+    if !('A'..='C').contains(&elf) {
+        return Err(AocError::unsupported(format!("the elf can't play {elf}")));
+    }
     let win_score: i32 = match (elf, player) {
         ('A','Y') => 6,
         ('B', 'Z') => 6,
@@ -68,7 +103,7 @@ fn round_calc(elf: char, player: char) -> i32 {
     };
     let draw_score: i32 = if elf == shifted(player) {3} else {0};
 
-    shape_score + win_score + draw_score
+    Ok(shape_score + win_score + draw_score)
 }
 
 fn shifted(input: char) -> char {
@@ -80,7 +115,6 @@ fn shifted(input: char) -> char {
     }
 }
 
-
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -89,7 +123,15 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "15");
-        assert_eq!(process_part2(file.as_str()), "12");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "15");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "12");
+        assert_eq!(
+            process_part1("A Y\nB\n").unwrap_err(),
+            AocError::parse(2, 1, "expected a round like \"A Y\", got \"B\"")
+        );
+        assert_eq!(
+            process_part2("A Y\nD X\n").unwrap_err(),
+            AocError::unsupported("the elf can't play D")
+        );
     }
 }
@@ -1,36 +1,60 @@
-#![feature(iter_array_chunks)]
-
-pub fn process_part1(input: &str) -> String {
-    let result: usize = input
-        .lines()
-        .map(|line| {
-            // we assume input is well-formed
-            let (first, second) = line.split_at(line.chars().count() / 2);
-            // we can do this because there is guaranteed to be exactly ONE collision
-            let collision = first
-                .chars()
-                .find(|letter| second.contains(*letter))
-                .unwrap();
-            map_priority(collision)
-        })
-        .sum();
-    result.to_string()
+use aoc_core::{Answer, AocError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let result: usize = input
+            .lines()
+            .map(|line| {
+                // we assume input is well-formed
+                let (first, second) = line.split_at(line.chars().count() / 2);
+                // we can do this because there is guaranteed to be exactly ONE collision
+                let collision = first
+                    .chars()
+                    .find(|letter| second.contains(*letter))
+                    .unwrap();
+                map_priority(collision)
+            })
+            .sum();
+        result
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let result: usize = input
+            .lines()
+            .collect::<Vec<&str>>()
+            .chunks_exact(3)
+            .map(|group| {
+                let (first, second, third) = (group[0], group[1], group[2]);
+                // we can do this because there is guaranteed to be exactly ONE collision
+                let collision = first
+                    .chars()
+                    .find(|letter| second.contains(*letter) && third.contains(*letter))
+                    .unwrap();
+                map_priority(collision)
+            })
+            .sum();
+        result
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day03::solve_part1(input)
 }
 
-pub fn process_part2(input: &str) -> String {
-    let result: usize = input
-        .lines()
-        .array_chunks::<3>()
-        .map(|[first, second, third]| {
-            // we can do this because there is guaranteed to be exactly ONE collision
-            let collision = first
-                .chars()
-                .find(|letter| second.contains(*letter) && third.contains(*letter))
-                .unwrap();
-            map_priority(collision)
-        })
-        .sum();
-    result.to_string()
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day03::solve_part2(input)
 }
 
 fn map_priority(input: char) -> usize {
@@ -49,7 +73,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "157");
-        assert_eq!(process_part2(file.as_str()), "70");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "157");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "70");
     }
 }
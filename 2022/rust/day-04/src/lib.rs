@@ -1,3 +1,4 @@
+use aoc_core::{Answer, AocError, Solution};
 use nom::{bytes::complete::tag, character::complete, *};
 
 struct LineData {
@@ -26,51 +27,73 @@ fn parse_line(input: &str) -> IResult<&str, LineData> {
     ))
 }
 
-pub fn process_part1(input: &str) -> String {
-    let result: usize = input
-        .lines()
-        .map(|line| {
-            if let Ok((_, d)) = parse_line(line) {
-                let left_size = d.left_end - d.left_start;
-                let right_size = d.right_end - d.right_start;
-                if left_size < right_size {
-                    if d.left_start >= d.right_start && d.left_end <= d.right_end {
-                        1
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let result: usize = input
+            .lines()
+            .map(|line| {
+                if let Ok((_, d)) = parse_line(line) {
+                    let left_size = d.left_end - d.left_start;
+                    let right_size = d.right_end - d.right_start;
+                    if left_size < right_size {
+                        if d.left_start >= d.right_start && d.left_end <= d.right_end {
+                            1
+                        } else {
+                            0
+                        }
                     } else {
-                        0
+                        // right is bigger
+                        if d.right_start >= d.left_start && d.right_end <= d.left_end {
+                            1
+                        } else {
+                            0
+                        }
                     }
                 } else {
-                    // right is bigger
-                    if d.right_start >= d.left_start && d.right_end <= d.left_end {
-                        1
-                    } else {
+                    0
+                }
+            })
+            .sum::<usize>();
+        result
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let result: usize = input
+            .lines()
+            .map(|line| {
+                if let Ok((_, d)) = parse_line(line) {
+                    if d.left_end < d.right_start || d.right_end < d.left_start {
                         0
+                    } else {
+                        1
                     }
+                } else {
+                    0
                 }
-            } else {
-                0
-            }
-        })
-        .sum::<usize>();
-    result.to_string()
+            })
+            .sum::<usize>();
+        result
+    }
 }
 
-pub fn process_part2(input: &str) -> String {
-    let result: usize = input
-        .lines()
-        .map(|line| {
-            if let Ok((_, d)) = parse_line(line) {
-                if d.left_end < d.right_start || d.right_end < d.left_start {
-                    0
-                } else {
-                    1
-                }
-            } else {
-                0
-            }
-        })
-        .sum::<usize>();
-    result.to_string()
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day04::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day04::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -81,7 +104,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "2");
-        assert_eq!(process_part2(file.as_str()), "4");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "2");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "4");
     }
 }
@@ -1,3 +1,4 @@
+use aoc_core::{Answer, AocError, Solution};
 use nom::{bytes::complete::tag, character::complete, *};
 
 struct MoveLine {
@@ -43,54 +44,75 @@ fn parse_move_lines(input: &str) -> Vec<MoveLine> {
     result
 }
 
-pub fn process_part1(input: &str) -> String {
-    let (crate_data, remainder) = input.split_at(input.find(" 1   2   3").unwrap());
-    let crate_data = crate_data.trim_end();
-    let (_, move_data) = remainder.split_at(remainder.find("\n\n").unwrap());
-    let move_data = move_data.trim();
-    let mut cargo = parse_crate_lines(crate_data);
-    let moves = parse_move_lines(move_data);
-    for crane_move in moves {
-        for _ in 0..crane_move.count {
-            let item = cargo[crane_move.start as usize].remove(0);
-            cargo[crane_move.end as usize].insert(0, item);
-        }
-    }
-    let mut result = String::new();
-    for cargo_box in cargo {
-        if !cargo_box.is_empty() {
-            result.push(cargo_box[0])
-        }
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+    type Parsed<'a> = &'a str;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
     }
-    result
-}
 
-pub fn process_part2(input: &str) -> String {
-    let (crate_data, remainder) = input.split_at(input.find(" 1   2   3").unwrap());
-    let crate_data = crate_data.trim_end();
-    let (_, move_data) = remainder.split_at(remainder.find("\n\n").unwrap());
-    let move_data = move_data.trim();
-    let mut cargo = parse_crate_lines(crate_data);
-    let moves = parse_move_lines(move_data);
-    for crane_move in moves {
-        let mut items: Vec<char> = Vec::new();
-        for _ in 0..crane_move.count {
-            items.push(cargo[crane_move.start as usize].remove(0));
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let (crate_data, remainder) = input.split_at(input.find(" 1   2   3").unwrap());
+        let crate_data = crate_data.trim_end();
+        let (_, move_data) = remainder.split_at(remainder.find("\n\n").unwrap());
+        let move_data = move_data.trim();
+        let mut cargo = parse_crate_lines(crate_data);
+        let moves = parse_move_lines(move_data);
+        for crane_move in moves {
+            for _ in 0..crane_move.count {
+                let item = cargo[crane_move.start as usize].remove(0);
+                cargo[crane_move.end as usize].insert(0, item);
+            }
         }
-        items.reverse();
-        for item in items {
-            cargo[crane_move.end as usize].insert(0, item);
+        let mut result = String::new();
+        for cargo_box in cargo {
+            if !cargo_box.is_empty() {
+                result.push(cargo_box[0])
+            }
         }
+        result
     }
-    let mut result = String::new();
-    for cargo_box in cargo {
-        if !cargo_box.is_empty() {
-            result.push(cargo_box[0])
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let (crate_data, remainder) = input.split_at(input.find(" 1   2   3").unwrap());
+        let crate_data = crate_data.trim_end();
+        let (_, move_data) = remainder.split_at(remainder.find("\n\n").unwrap());
+        let move_data = move_data.trim();
+        let mut cargo = parse_crate_lines(crate_data);
+        let moves = parse_move_lines(move_data);
+        for crane_move in moves {
+            let mut items: Vec<char> = Vec::new();
+            for _ in 0..crane_move.count {
+                items.push(cargo[crane_move.start as usize].remove(0));
+            }
+            items.reverse();
+            for item in items {
+                cargo[crane_move.end as usize].insert(0, item);
+            }
+        }
+        let mut result = String::new();
+        for cargo_box in cargo {
+            if !cargo_box.is_empty() {
+                result.push(cargo_box[0])
+            }
         }
+        result
     }
-    result
 }
 
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day05::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day05::solve_part2(input)
+}
 
 #[cfg(test)]
 mod tests {
@@ -100,7 +122,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "CMZ");
-        assert_eq!(process_part2(file.as_str()), "MCD");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "CMZ");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "MCD");
     }
 }
@@ -1,3 +1,4 @@
+use aoc_core::{Answer, AocError, Solution};
 use std::collections::BTreeSet;
 
 fn first_unique(input: &str, window: usize) -> Option<usize> {
@@ -18,14 +19,35 @@ fn first_unique(input: &str, window: usize) -> Option<usize> {
     }
 }
 
-pub fn process_part1(input: &str) -> String {
-    first_unique(input, 4).unwrap().to_string()
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        first_unique(input, 4).unwrap()
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        first_unique(input, 14).unwrap()
+    }
 }
 
-pub fn process_part2(input: &str) -> String {
-    first_unique(input, 14).unwrap().to_string()
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day06::solve_part1(input)
 }
 
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day06::solve_part2(input)
+}
 
 #[cfg(test)]
 mod tests {
@@ -38,14 +60,14 @@ mod tests {
         let file2 = fs::read_to_string("./test-input-2.txt").unwrap();
         let file3 = fs::read_to_string("./test-input-3.txt").unwrap();
         let file4 = fs::read_to_string("./test-input-4.txt").unwrap();
-        assert_eq!(process_part1(file1.as_str()), "5");
-        assert_eq!(process_part1(file2.as_str()), "6");
-        assert_eq!(process_part1(file3.as_str()), "10");
-        assert_eq!(process_part1(file4.as_str()), "11");
-        assert_eq!(process_part2(file1.as_str()), "23");
-        assert_eq!(process_part2(file2.as_str()), "23");
-        assert_eq!(process_part2(file3.as_str()), "29");
-        assert_eq!(process_part2(file4.as_str()), "26");
+        assert_eq!(process_part1(file1.as_str()).unwrap(), "5");
+        assert_eq!(process_part1(file2.as_str()).unwrap(), "6");
+        assert_eq!(process_part1(file3.as_str()).unwrap(), "10");
+        assert_eq!(process_part1(file4.as_str()).unwrap(), "11");
+        assert_eq!(process_part2(file1.as_str()).unwrap(), "23");
+        assert_eq!(process_part2(file2.as_str()).unwrap(), "23");
+        assert_eq!(process_part2(file3.as_str()).unwrap(), "29");
+        assert_eq!(process_part2(file4.as_str()).unwrap(), "26");
     }
 }
 
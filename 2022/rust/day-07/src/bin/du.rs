@@ -0,0 +1,6 @@
+use aoc_2022_day_07::du;
+
+fn main() {
+    let file = aoc_core::input::from_args(env!("CARGO_MANIFEST_DIR"));
+    aoc_core::print_or_exit(du(&file).map(Into::into));
+}
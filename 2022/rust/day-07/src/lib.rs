@@ -1,100 +1,56 @@
-use std::collections::BTreeMap;
+use aoc_core::{Answer, AocError, Solution};
 
-fn parse_tree(input: &str) -> BTreeMap<Vec<String>, u64> {
-    let mut tree: BTreeMap<Vec<String>, u64> = BTreeMap::new();
-    let mut cwd: Vec<String> = Vec::new();
-    for line in input.lines() { //.enumerate() {
-        match line.split_whitespace().next() {
-            Some("dir") => { continue; } // the names will propagate from cd
-            Some("$") => {
-                match line.split_whitespace().nth(1) {
-                    Some("ls") => continue, // don't care about the command itself
-                    Some("cd") => {
-                        match line.split_whitespace().nth(2) {
-                            Some("..") => { cwd.pop(); } // go up a directory
-                            Some("/") => cwd.push("/".to_string()), //no double forward slashes
-                            Some(name) => { 
-                                cwd.push(name.to_string() + "/");// given a directory to go deeper
-                                // collect cwd and push to list of all dirs
-                            },
-                            None => todo!(), // cd\n is bad input
-                        }
-                    }
-                    Some(_) => todo!(), // unknown command
-                    None => todo!(), // $ \n is bad input
-                }
-            }
-            None => {continue;} // continue on empty line
-            Some(num) => {
-                // must be a number
-                let size_result = num.parse::<u64>();
-                let size = match size_result {
-                    Ok(size) => {size},
-                    Err(_) => todo!(), // should never get here
-                };
-                match line.split_whitespace().nth(1) {
-                    Some(name) => {
-                        let mut location = cwd.clone();
-                        location.push(name.to_string());
-                        tree.insert(location, size); // append name with size
-                    },
-                    None => todo!(), // number followed by nothing is bad input
-                }
-            }
-        }
+pub mod vfs;
+
+use vfs::FileSystem;
+
+const DISK_SIZE: u64 = 70_000_000;
+const UPDATE_SIZE: u64 = 30_000_000;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+    type Parsed<'a> = Result<FileSystem, AocError>;
+    type Part1 = Result<u64, AocError>;
+    type Part2 = Result<u64, AocError>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        FileSystem::parse(input)
     }
-    tree
-}
 
-fn parse_size(input: BTreeMap<Vec<String>, u64>) -> BTreeMap<Vec<String>, u64> {
-    let mut size_tree: BTreeMap<Vec<String>, u64> = BTreeMap::new();
-    for key in input.keys() {
-        if '/' == key.last().unwrap().chars().last().unwrap(){
-            continue;
-        } else { // file
-            let mut new_key = key.clone();
-            for _ in 0..key.len()-1 { // update this and all outer dirs
-                new_key.pop();
-                if size_tree.contains_key(&new_key) {
-                    let initial = size_tree[&new_key];
-                    size_tree.insert(new_key.clone(), input[key] + initial);
-                } else {
-                    size_tree.insert(new_key.clone(), input[key]);
-                }
-            }
-        }
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let fs = input?;
+        Ok(fs.dirs_at_most(100_000).map(|dir| fs.size(dir)).sum())
     }
-    size_tree
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let fs = input?;
+        let free = DISK_SIZE.saturating_sub(fs.size(FileSystem::ROOT));
+        let needed = UPDATE_SIZE.saturating_sub(free);
+        fs.smallest_dir_at_least(needed)
+            .map(|dir| fs.size(dir))
+            .ok_or_else(|| AocError::solver("even the whole disk isn't enough to free up"))
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day07::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day07::solve_part2(input)
 }
 
-pub fn process_part1(input: &str) -> String {
-    let file_tree = parse_tree(input);
-    let size_tree = parse_size(file_tree);
-    let result = size_tree
-        .values()
-        .fold(0, |sum, value| {
-            if (*value) <= 100000 {
-                sum + (*value)
-            }
-            else { sum }
-        });
-    result.to_string()
+/// Every directory's total size, like `du`.
+pub fn du(input: &str) -> Result<String, AocError> {
+    Ok(FileSystem::parse(input)?.du())
 }
 
-pub fn process_part2(input: &str) -> String {
-    let file_tree = parse_tree(input);
-    let size_tree = parse_size(file_tree);
-    let root = vec!["/".to_string()];
-    let needed_space = 30000000 - (70000000 - size_tree[&root]);
-    let result = size_tree
-        .values()
-        .fold(size_tree[&root], |acc, value| {
-            if *value >= needed_space && *value < acc {
-                *value
-            }
-            else { acc }
-        });
-    result.to_string()
+/// The whole filesystem as a tree.
+pub fn tree(input: &str) -> Result<String, AocError> {
+    Ok(FileSystem::parse(input)?.tree())
 }
 
 #[cfg(test)]
@@ -105,7 +61,11 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "95437");
-        assert_eq!(process_part2(file.as_str()), "24933642");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "95437");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "24933642");
+        assert_eq!(
+            du(&file).unwrap(),
+            "584\t/a/e/\n94853\t/a/\n24933642\t/d/\n48381165\t/"
+        );
     }
 }
@@ -0,0 +1,229 @@
+//! The device's filesystem, rebuilt from a transcript of `cd` and `ls`
+//! commands, with every directory's total size worked out once it's built.
+
+use std::collections::BTreeMap;
+
+use aoc_core::AocError;
+
+/// A file or directory, by its index in the filesystem.
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node {
+    name: String,
+    parent: Option<NodeId>,
+    /// empty for files, and for directories that were never listed
+    children: BTreeMap<String, NodeId>,
+    is_dir: bool,
+    /// a file's own size, or everything under a directory
+    size: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSystem {
+    /// the root first, and every node after its parent
+    nodes: Vec<Node>,
+}
+
+impl FileSystem {
+    pub const ROOT: NodeId = 0;
+
+    /// Replays a transcript of `$ cd <dir>`, `$ cd ..`, `$ cd /` and `$ ls`
+    /// commands and their listings.
+    pub fn parse(transcript: &str) -> Result<FileSystem, AocError> {
+        let mut fs = FileSystem {
+            nodes: vec![Node {
+                name: "/".to_string(),
+                parent: None,
+                children: BTreeMap::new(),
+                is_dir: true,
+                size: 0,
+            }],
+        };
+        let mut cwd = FileSystem::ROOT;
+        for (number, line) in transcript.lines().enumerate() {
+            let error = |message: String| AocError::parse(number + 1, 1, message);
+            let words: Vec<&str> = line.split_whitespace().collect();
+            match words[..] {
+                [] => continue,
+                ["$", "ls"] => continue,
+                ["$", "cd", "/"] => cwd = FileSystem::ROOT,
+                ["$", "cd", ".."] => {
+                    cwd = fs.nodes[cwd]
+                        .parent
+                        .ok_or_else(|| error("cd .. from the root".to_string()))?;
+                }
+                ["$", "cd", name] => {
+                    cwd = fs.add(cwd, name, true, 0).map_err(error)?;
+                }
+                ["$", ..] => return Err(error(format!("unknown command \"{line}\""))),
+                ["dir", name] => {
+                    fs.add(cwd, name, true, 0).map_err(error)?;
+                }
+                [size, name] => {
+                    let size = size
+                        .parse()
+                        .map_err(|_| error(format!("expected a file size in \"{line}\"")))?;
+                    fs.add(cwd, name, false, size).map_err(error)?;
+                }
+                _ => return Err(error(format!("unexpected \"{line}\""))),
+            }
+        }
+        // children always come after their parents, so going backwards every
+        // directory is complete by the time it's added to its own parent
+        for id in (1..fs.nodes.len()).rev() {
+            let parent = fs.nodes[id].parent.expect("only the root has no parent");
+            fs.nodes[parent].size += fs.nodes[id].size;
+        }
+        Ok(fs)
+    }
+
+    /// The child of `dir` called `name`, added if it isn't there yet.
+    fn add(&mut self, dir: NodeId, name: &str, is_dir: bool, size: u64) -> Result<NodeId, String> {
+        if let Some(&id) = self.nodes[dir].children.get(name) {
+            let existing = &self.nodes[id];
+            if existing.is_dir != is_dir || existing.size != size {
+                return Err(format!("{} was already listed differently", self.path(id)));
+            }
+            return Ok(id);
+        }
+        let id = self.nodes.len();
+        self.nodes.push(Node {
+            name: name.to_string(),
+            parent: Some(dir),
+            children: BTreeMap::new(),
+            is_dir,
+            size,
+        });
+        self.nodes[dir].children.insert(name.to_string(), id);
+        Ok(id)
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.nodes[id].name
+    }
+
+    pub fn is_dir(&self, id: NodeId) -> bool {
+        self.nodes[id].is_dir
+    }
+
+    /// A file's size, or the total size of everything in a directory.
+    pub fn size(&self, id: NodeId) -> u64 {
+        self.nodes[id].size
+    }
+
+    /// The children of a directory, sorted by name.
+    pub fn children(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.nodes[id].children.values().copied()
+    }
+
+    /// The full path, with a trailing `/` on directories.
+    pub fn path(&self, id: NodeId) -> String {
+        let node = &self.nodes[id];
+        let Some(parent) = node.parent else {
+            return "/".to_string();
+        };
+        let slash = if node.is_dir { "/" } else { "" };
+        format!("{}{}{slash}", self.path(parent), node.name)
+    }
+
+    /// Every directory, the root first.
+    pub fn dirs(&self) -> impl Iterator<Item = NodeId> + '_ {
+        (0..self.nodes.len()).filter(|&id| self.nodes[id].is_dir)
+    }
+
+    /// The directories whose total size is at most `max`.
+    pub fn dirs_at_most(&self, max: u64) -> impl Iterator<Item = NodeId> + '_ {
+        self.dirs().filter(move |&id| self.size(id) <= max)
+    }
+
+    /// The smallest directory whose total size is at least `min`.
+    pub fn smallest_dir_at_least(&self, min: u64) -> Option<NodeId> {
+        self.dirs()
+            .filter(|&id| self.size(id) >= min)
+            .min_by_key(|&id| self.size(id))
+    }
+
+    /// Every directory's size and path, one per line, each after everything
+    /// inside it, the way `du` lists them.
+    pub fn du(&self) -> String {
+        let mut lines = vec![];
+        self.du_lines(FileSystem::ROOT, &mut lines);
+        lines.join("\n")
+    }
+
+    fn du_lines(&self, dir: NodeId, lines: &mut Vec<String>) {
+        for child in self.children(dir).filter(|&id| self.is_dir(id)) {
+            self.du_lines(child, lines);
+        }
+        lines.push(format!("{}\t{}", self.size(dir), self.path(dir)));
+    }
+
+    /// Everything as an indented tree, in the puzzle's own format plus each
+    /// directory's total size.
+    pub fn tree(&self) -> String {
+        let mut lines = vec![];
+        self.tree_lines(FileSystem::ROOT, 0, &mut lines);
+        lines.join("\n")
+    }
+
+    fn tree_lines(&self, id: NodeId, depth: usize, lines: &mut Vec<String>) {
+        let kind = if self.is_dir(id) { "dir" } else { "file" };
+        lines.push(format!(
+            "{:indent$}- {} ({kind}, size={})",
+            "",
+            self.name(id),
+            self.size(id),
+            indent = depth * 2
+        ));
+        for child in self.children(id) {
+            self.tree_lines(child, depth + 1, lines);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_system() {
+        let fs = FileSystem::parse(
+            "$ cd /\n$ ls\ndir a\n10 b\n$ cd a\n$ ls\n5 c\n$ cd ..\n$ ls\ndir a\n10 b\n",
+        )
+        .unwrap();
+        assert_eq!(fs.size(FileSystem::ROOT), 15);
+        assert_eq!(
+            fs.dirs().map(|id| fs.path(id)).collect::<Vec<_>>(),
+            ["/", "/a/"]
+        );
+        assert_eq!(
+            fs.dirs_at_most(5).map(|id| fs.name(id)).collect::<Vec<_>>(),
+            ["a"]
+        );
+        assert_eq!(fs.smallest_dir_at_least(6), Some(FileSystem::ROOT));
+        assert_eq!(fs.smallest_dir_at_least(16), None);
+        assert_eq!(fs.du(), "5\t/a/\n15\t/");
+        assert_eq!(
+            fs.tree(),
+            "- / (dir, size=15)\n  - a (dir, size=5)\n    - c (file, size=5)\n  - b (file, size=10)"
+        );
+
+        assert_eq!(
+            FileSystem::parse("$ cd /\n$ cd ..\n").unwrap_err(),
+            AocError::parse(2, 1, "cd .. from the root")
+        );
+        assert_eq!(
+            FileSystem::parse("$ ls\n$ rm -rf a\n").unwrap_err(),
+            AocError::parse(2, 1, "unknown command \"$ rm -rf a\"")
+        );
+        assert_eq!(
+            FileSystem::parse("$ ls\nlots b\n").unwrap_err(),
+            AocError::parse(2, 1, "expected a file size in \"lots b\"")
+        );
+        assert_eq!(
+            FileSystem::parse("$ ls\n10 b\n$ cd b\n").unwrap_err(),
+            AocError::parse(3, 1, "/b was already listed differently")
+        );
+    }
+}
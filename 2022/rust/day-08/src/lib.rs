@@ -1,3 +1,5 @@
+use aoc_core::{Answer, AocError, Solution};
+
 fn parse_grid(input: &str) -> Vec<Vec<u32>> {
     let grid: Vec<Vec<u32>> = input
         .lines()
@@ -15,14 +17,7 @@ fn parse_grid(input: &str) -> Vec<Vec<u32>> {
 }
 
 fn check_inward(grid: Vec<Vec<u32>>) -> u32 {
-    let mut down_visible_grid: Vec<Vec<bool>> = Vec::new();
-    for _ in 0..grid.len() {
-        let mut row: Vec<bool> = Vec::new();
-        for _ in 0..grid[0].len() {
-            row.push(false);
-        }
-        down_visible_grid.push(row);
-    }
+    let mut down_visible_grid: Vec<Vec<bool>> = vec![vec![false; grid[0].len()]; grid.len()];
     let mut right_visible_grid = down_visible_grid.clone();
     let mut up_visible_grid = down_visible_grid.clone();
     let mut left_visible_grid = down_visible_grid.clone();
@@ -131,14 +126,7 @@ fn check_inward(grid: Vec<Vec<u32>>) -> u32 {
 }
 
 fn check_outward(grid: Vec<Vec<u32>>) -> u32 {
-    let mut down_range_grid: Vec<Vec<u32>> = Vec::new();
-    for _ in 0..grid.len() {
-        let mut row: Vec<u32> = Vec::new();
-        for _ in 0..grid[0].len() {
-            row.push(0);
-        }
-        down_range_grid.push(row);
-    }
+    let mut down_range_grid: Vec<Vec<u32>> = vec![vec![0; grid[0].len()]; grid.len()];
     let mut right_range_grid = down_range_grid.clone();
     let mut up_range_grid = down_range_grid.clone();
     let mut left_range_grid = down_range_grid.clone();
@@ -225,15 +213,37 @@ fn check_outward(grid: Vec<Vec<u32>>) -> u32 {
     max
 }
 
-pub fn process_part1(input: &str) -> String {
-    let grid = parse_grid(input);
-    let count = check_inward(grid);
-    count.to_string()
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+    type Parsed<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let grid = parse_grid(input);
+        
+        check_inward(grid)
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let grid = parse_grid(input);
+        check_outward(grid)
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day08::solve_part1(input)
 }
 
-pub fn process_part2(input: &str) -> String {
-    let grid = parse_grid(input);
-    check_outward(grid).to_string()
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day08::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -244,7 +254,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "21");
-        assert_eq!(process_part2(file.as_str()), "8")
+        assert_eq!(process_part1(file.as_str()).unwrap(), "21");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "8")
     }
 }
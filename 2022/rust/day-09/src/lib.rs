@@ -1,3 +1,4 @@
+use aoc_core::{Answer, AocError, Solution};
 use std::fmt::Debug;
 
 #[derive(Debug)]
@@ -99,23 +100,23 @@ impl HeadTailGrid {
         // x T x
         // x x x
         if self.seg_x[h] == self.seg_x[s] && self.seg_y[h] == self.seg_y[s] { //same
-            return true
+            true
         } else if self.seg_x[h] == self.seg_x[s]+1 && self.seg_y[h] == self.seg_y[s] { //right
-            return true
+            true
         } else if self.seg_x[s] != 0 && self.seg_x[h] == self.seg_x[s]-1 && self.seg_y[h] == self.seg_y[s] { //left
-            return true
+            true
         } else if self.seg_x[h] == self.seg_x[s] && self.seg_y[h] == self.seg_y[s]+1 { //down
-            return true
+            true
         } else if self.seg_y[s] != 0 && self.seg_x[h] == self.seg_x[s] && self.seg_y[h] == self.seg_y[s]-1 { //up
-            return true
+            true
         } else if self.seg_x[s] != 0 && self.seg_y[s] != 0 && self.seg_x[h] == self.seg_x[s]-1 && self.seg_y[h] == self.seg_y[s]-1 { //up left
-            return true
+            true
         } else if self.seg_y[s] != 0 && self.seg_x[h] == self.seg_x[s]+1 && self.seg_y[h] == self.seg_y[s]-1 { //up right
-            return true
+            true
         } else if self.seg_x[h] == self.seg_x[s]+1 && self.seg_y[h] == self.seg_y[s]+1 { //down right
-            return true
+            true
         } else if self.seg_x[s] != 0 && self.seg_x[h] == self.seg_x[s]-1 && self.seg_y[h] == self.seg_y[s]+1 { //down left
-            return true
+            true
         } else { false }
     }
     fn update_tails(&mut self) {
@@ -185,8 +186,7 @@ impl HeadTailGrid {
     fn head_up(&mut self) {
         if self.seg_y[0] == 0 { // need new row at START
             // generate new row
-            let mut row: Vec<GridData> = Vec::new();
-            row.reserve(300);
+            let mut row: Vec<GridData> = Vec::with_capacity(300);
             for _ in 0..self.cols {
                 let col = GridData { tail_visit: false };
                 row.push(col)
@@ -205,8 +205,7 @@ impl HeadTailGrid {
     fn head_down(&mut self) {
         if self.seg_y[0] == self.rows-1 { // need new row at END
             // generate new row
-            let mut row: Vec<GridData> = Vec::new();
-            row.reserve(300);
+            let mut row: Vec<GridData> = Vec::with_capacity(300);
             for _ in 0..self.cols {
                 let col = GridData { tail_visit: false };
                 row.push(col)
@@ -272,22 +271,43 @@ fn parse_steps(input: &str) -> Vec<Move> {
     result
 }
 
-pub fn process_part1(input: &str) -> String {
-    let moves = parse_steps(input);
-    let mut grid = HeadTailGrid::new(1);
-    grid.process_moves(moves);
-    dbg!(&grid);
-    grid.count_tail_visits().to_string()
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let moves = parse_steps(input);
+        let mut grid = HeadTailGrid::new(1);
+        grid.process_moves(moves);
+        dbg!(&grid);
+        grid.count_tail_visits()
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let moves = parse_steps(input);
+        let mut grid = HeadTailGrid::new(9);
+        grid.process_moves(moves);
+        dbg!(&grid);
+        grid.count_tail_visits()
+    }
 }
 
-pub fn process_part2(input: &str) -> String {
-    let moves = parse_steps(input);
-    let mut grid = HeadTailGrid::new(9);
-    grid.process_moves(moves);
-    dbg!(&grid);
-    grid.count_tail_visits().to_string()
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day09::solve_part1(input)
 }
 
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day09::solve_part2(input)
+}
 
 #[cfg(test)]
 mod tests {
@@ -297,9 +317,9 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "13");
-        assert_eq!(process_part2(file.as_str()), "1");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "13");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "1");
         let file2 = fs::read_to_string("./test-input-2.txt").unwrap();
-        assert_eq!(process_part2(file2.as_str()), "36");
+        assert_eq!(process_part2(file2.as_str()).unwrap(), "36");
     }
 }
@@ -0,0 +1,6 @@
+use aoc_2022_day_10::render_part2;
+
+fn main() {
+    let file = aoc_core::input::from_args(env!("CARGO_MANIFEST_DIR"));
+    println!("{}", render_part2(&file));
+}
@@ -1,3 +1,5 @@
+use aoc_core::{Answer, AocError, Solution};
+
 #[derive(Debug)]
 enum Instruction {
     Noop,
@@ -57,25 +59,16 @@ fn signal_strength(instructions: Vec<Instruction>, important_cycles: Vec<i64>) -
     signal_counter
 }
 
-fn update_screen(cycle: i64, x: i64, screen: &mut Vec<Vec<char>>) {
+fn update_screen(cycle: i64, x: i64, screen: &mut [Vec<char>]) {
     if (cycle % 40) == x || (cycle % 40)+1 == x || (cycle % 40)-1 == x {
-        screen[((cycle/40) as usize)][((cycle%40) as usize)] = '#';                 
+        screen[(cycle/40) as usize][(cycle%40) as usize] = '#';                 
     }
 }
 
 fn draw_screen(instructions: Vec<Instruction>) -> String {
     let mut current_cycle: i64 = 0;
     let mut x: i64 = 1;
-    let mut screen: Vec<Vec<char>> = Vec::new();
-    screen.reserve(6);
-    for _ in 0..6 {
-        let mut row: Vec<char> = Vec::new();
-        row.reserve(40);
-        for _ in 0..40 {
-            row.push('.');
-        }
-        screen.push(row);
-    }
+    let mut screen: Vec<Vec<char>> = vec![vec!['.'; 40]; 6];
 
     update_screen(current_cycle, x, &mut screen);
 
@@ -108,19 +101,45 @@ fn draw_screen(instructions: Vec<Instruction>) -> String {
 
 }
 
-pub fn process_part1(input: &str) -> String {
-    let ins = gen_instructions(input);
-    let important_cycles = vec![20, 60, 100, 140, 180, 220];
-    let result = signal_strength(ins, important_cycles);
-    result.to_string()
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+    type Parsed<'a> = &'a str;
+    type Part1 = i64;
+    type Part2 = Result<String, AocError>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let ins = gen_instructions(input);
+        let important_cycles = vec![20, 60, 100, 140, 180, 220];
+        
+        signal_strength(ins, important_cycles)
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let ins = gen_instructions(input);
+
+        Ok(aoc_ocr::recognise(&draw_screen(ins))?)
+    }
 }
 
-pub fn process_part2(input: &str) -> String {
-    let ins = gen_instructions(input);
-    let result = draw_screen(ins);
-    result
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day10::solve_part1(input)
 }
 
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day10::solve_part2(input)
+}
+
+/// The screen part 2 reads its letters from.
+pub fn render_part2(input: &str) -> String {
+    draw_screen(gen_instructions(input))
+}
 
 #[cfg(test)]
 mod tests {
@@ -130,8 +149,8 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "13140");
-        assert_eq!(process_part2(file.as_str()), 
+        assert_eq!(process_part1(file.as_str()).unwrap(), "13140");
+        assert_eq!(render_part2(file.as_str()), 
 "##..##..##..##..##..##..##..##..##..##..
 ###...###...###...###...###...###...###.
 ####....####....####....####....####....
@@ -1,4 +1,4 @@
-#![feature(iter_array_chunks)]
+use aoc_core::{Answer, AocError, Solution};
 
 enum Operator {
     Add,
@@ -23,67 +23,92 @@ struct Monkey {
     inspections: u64,
 }
 
-fn parse_nodes(input: &str) -> Vec<Monkey> {
-    let result: Vec<Monkey> = input
-        .lines()
-        .array_chunks::<7>()
-        .map(|item|{
-            let number = item[0].split_whitespace().nth(1).expect("invalid input, monkey").
-                split_once(':').expect("invalid input, monkey").0.parse::<u64>().expect("invalid input, monkey") as usize;
-            let items = item[1].split_once(':').expect("invalid input, items").1.split(',').map(|text| {
-                text.trim().parse::<u64>().expect("invalid input, items")
-            }).collect::<Vec<u64>>();
-            let operator: Operator = match item[2].split("  Operation: new = old ").nth(1).expect("invalid input").chars().next() {
-                Some('+') => Operator::Add,
-                Some('-') => Operator::Subtract,
-                Some('*') => Operator::Multiply,
-                Some('/') => Operator::Divide,
-                Some(_) => todo!(), //invalid input
-                None => todo!(), //invalid input
-            };
-            let operand: Operand = match item[2].split_whitespace().nth(5).expect("invalid input, operand").parse() {
-                Ok(a) => Operand::Value(a),
-                _ => Operand::Old,
-            };
-            let modulo = item[3].split_whitespace().nth(3).expect("invalid input, modulo").parse().expect("invalid input, modulo");
-            let if_true = item[4].split_whitespace().nth(5).expect("invalid input, if true").parse().expect("invalid input, if true");
-            let if_false = item[5].split_whitespace().nth(5).expect("invalid input, if false").parse().expect("invalid input, if false");
-            Monkey{ number, items, operator, operand, modulo, if_true, if_false, inspections: 0 }
-        }).collect::<Vec<Monkey>>();
-    for (i, item) in result.iter().enumerate() {
-        if item.number != i {
-            panic!("input was invalid");
-        }        
+fn parse_nodes(input: &str) -> Result<Vec<Monkey>, AocError> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut result = Vec::new();
+    for (i, item) in lines.chunks(7).enumerate() {
+        let error = |line: usize, what: &str| {
+            AocError::parse(i * 7 + line + 1, 1, format!("invalid input, {what}"))
+        };
+        let word = |line: usize, nth: usize, what: &str| {
+            item.get(line)
+                .and_then(|text| text.split_whitespace().nth(nth))
+                .ok_or_else(|| error(line, what))
+        };
+        let parse_number = |line: usize, nth: usize, what: &str| {
+            word(line, nth, what)?
+                .trim_end_matches(':')
+                .parse::<u64>()
+                .map_err(|_| error(line, what))
+        };
+        let number = parse_number(0, 1, "monkey")? as usize;
+        let items = item
+            .get(1)
+            .and_then(|text| text.split_once(':'))
+            .ok_or_else(|| error(1, "items"))?
+            .1
+            .split(',')
+            .map(|text| text.trim().parse::<u64>().map_err(|_| error(1, "items")))
+            .collect::<Result<Vec<u64>, AocError>>()?;
+        let operator: Operator = match word(2, 4, "operator")? {
+            "+" => Operator::Add,
+            "-" => Operator::Subtract,
+            "*" => Operator::Multiply,
+            "/" => Operator::Divide,
+            _ => return Err(error(2, "operator")),
+        };
+        let operand: Operand = match word(2, 5, "operand")?.parse() {
+            Ok(a) => Operand::Value(a),
+            _ => Operand::Old,
+        };
+        let modulo = parse_number(3, 3, "modulo")?;
+        if modulo == 0 {
+            return Err(error(3, "modulo"));
+        }
+        let if_true = parse_number(4, 5, "if true")? as usize;
+        let if_false = parse_number(5, 5, "if false")? as usize;
+        if number != i {
+            return Err(error(0, "monkey"));
+        }
+        result.push(Monkey{ number, items, operator, operand, modulo, if_true, if_false, inspections: 0 });
     }
-    result
+    for item in &result {
+        if item.if_true.max(item.if_false) >= result.len() {
+            return Err(AocError::unsupported(format!(
+                "monkey {} throws to a monkey that isn't there",
+                item.number
+            )));
+        }
+    }
+    Ok(result)
 }
 
 fn inspect_items(input: &mut Monkey) -> Vec<(usize, u64)> {
     let mut result = Vec::new();
-    while let Some(item) = (*input).items.pop() {
-        let mut new_item = match (*input).operator {
-            Operator::Add => item + (match (*input).operand {
+    while let Some(item) = input.items.pop() {
+        let mut new_item = match input.operator {
+            Operator::Add => item + (match input.operand {
                 Operand::Value(v) => v,
                 Operand::Old => item,
             }),
-            Operator::Subtract => item - (match (*input).operand {
+            Operator::Subtract => item - (match input.operand {
                 Operand::Value(v) => v,
                 Operand::Old => item,
             }),
-            Operator::Multiply => item * (match (*input).operand {
+            Operator::Multiply => item * (match input.operand {
                 Operand::Value(v) => v,
                 Operand::Old => item,
             }),
-            Operator::Divide => item / (match (*input).operand {
+            Operator::Divide => item / (match input.operand {
                 Operand::Value(v) => v,
                 Operand::Old => item,
             }),
         };
-        new_item = new_item / 3;
-        (*input).inspections += 1;
-        match new_item % (*input).modulo == 0 {
-            true => result.push(((*input).if_true, new_item)),
-            false => result.push(((*input).if_false, new_item)),
+        new_item /= 3;
+        input.inspections += 1;
+        match new_item % input.modulo == 0 {
+            true => result.push((input.if_true, new_item)),
+            false => result.push((input.if_false, new_item)),
         }
     };
     result
@@ -91,85 +116,106 @@ fn inspect_items(input: &mut Monkey) -> Vec<(usize, u64)> {
 
 fn inspect_items2(input: &mut Monkey, rmdr_thm: u64) -> Vec<(usize, u64)> {
     let mut result = Vec::new();
-    while let Some(item) = (*input).items.pop() {
-        let mut new_item = match (*input).operator {
-            Operator::Add => item + (match (*input).operand {
+    while let Some(item) = input.items.pop() {
+        let mut new_item = match input.operator {
+            Operator::Add => item + (match input.operand {
                 Operand::Value(v) => v,
                 Operand::Old => item,
             }),
-            Operator::Subtract => item - (match (*input).operand {
+            Operator::Subtract => item - (match input.operand {
                 Operand::Value(v) => v,
                 Operand::Old => item,
             }),
-            Operator::Multiply => item * (match (*input).operand {
+            Operator::Multiply => item * (match input.operand {
                 Operand::Value(v) => v,
                 Operand::Old => item,
             }),
-            Operator::Divide => item / (match (*input).operand {
+            Operator::Divide => item / (match input.operand {
                 Operand::Value(v) => v,
                 Operand::Old => item,
             }),
         };
-        (*input).inspections += 1;
-        new_item = new_item % rmdr_thm;
-        match new_item % (*input).modulo == 0 {
-            true => result.push(((*input).if_true, new_item)),
-            false => result.push(((*input).if_false, new_item)),
+        input.inspections += 1;
+        new_item %= rmdr_thm;
+        match new_item % input.modulo == 0 {
+            true => result.push((input.if_true, new_item)),
+            false => result.push((input.if_false, new_item)),
         }
     };
     result
 }
 
-fn insert_pairs(input: Vec<(usize, u64)>, list: &mut Vec<Monkey>) {
+fn insert_pairs(input: Vec<(usize, u64)>, list: &mut [Monkey]) {
     for (destination, item) in input {
         list[destination].items.push(item);
     }
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut list = parse_nodes(input);
-    for _ in 0..20 { // rounds
-        for i in 0..list.len() {
-            let outgoing_pairs = inspect_items(&mut list[i]);
-            insert_pairs(outgoing_pairs, &mut list);
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+    type Parsed<'a> = &'a str;
+    type Part1 = Result<u64, AocError>;
+    type Part2 = Result<u64, AocError>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut list = parse_nodes(input)?;
+        for _ in 0..20 { // rounds
+            for i in 0..list.len() {
+                let outgoing_pairs = inspect_items(&mut list[i]);
+                insert_pairs(outgoing_pairs, &mut list);
+            }
         }
+        let mut inspections: Vec<u64> = list
+            .iter()
+            .map(|item| {
+                item.inspections
+            })
+            .collect::<Vec<_>>();
+        inspections.sort_by(|a, b| b.cmp(a));
+        Ok(inspections[0] * inspections[1])
     }
-    let mut inspections: Vec<u64> = list
-        .iter()
-        .map(|item| {
-            item.inspections
-        })
-        .collect::<Vec<_>>();
-    inspections.sort_by(|a, b| b.cmp(a));
-    (inspections[0] * inspections[1]).to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let first_few_primes: [u64; 25] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97];
-    let mut list = parse_nodes(input);
-    let remainder_theorem_magic_number: u64 = list
-        .iter()
-        .map(|item| {
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let first_few_primes: [u64; 25] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97];
+        let mut list = parse_nodes(input)?;
+        let mut remainder_theorem_magic_number: u64 = 1;
+        for item in &list {
             if !first_few_primes.contains(&item.modulo) {
-                panic!("cannot do remainder theorem")
                 // they could also be _relatively_ prime, but the inputs are all prime in this case
+                return Err(AocError::unsupported("cannot do remainder theorem"));
             }
-            item.modulo
-        }).product();
-    for _ in 0..10000 { // rounds
-        for i in 0..list.len() {
-            let outgoing_pairs = inspect_items2(&mut list[i], remainder_theorem_magic_number);
-            insert_pairs(outgoing_pairs, &mut list);
+            remainder_theorem_magic_number *= item.modulo;
         }
+        for _ in 0..10000 { // rounds
+            for i in 0..list.len() {
+                let outgoing_pairs = inspect_items2(&mut list[i], remainder_theorem_magic_number);
+                insert_pairs(outgoing_pairs, &mut list);
+            }
+        }
+        let mut inspections: Vec<u64> = list
+            .iter()
+            .map(|item| {
+                item.inspections
+            })
+            .collect::<Vec<_>>();
+        inspections.sort_by(|a, b| b.cmp(a));
+        Ok(inspections[0] * inspections[1])
     }
-    let mut inspections: Vec<u64> = list
-        .iter()
-        .map(|item| {
-            item.inspections
-        })
-        .collect::<Vec<_>>();
-    inspections.sort_by(|a, b| b.cmp(a));
-    (inspections[0] * inspections[1]).to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day11::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day11::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -180,7 +226,12 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "10605");
-        assert_eq!(process_part2(file.as_str()), "2713310158");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "10605");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "2713310158");
+        let broken = file.replacen("new = old * 19", "new = old % 19", 1);
+        assert_eq!(
+            process_part1(&broken).unwrap_err(),
+            AocError::parse(3, 1, "invalid input, operator")
+        );
     }
 }
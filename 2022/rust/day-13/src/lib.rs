@@ -1,5 +1,4 @@
-#![feature(iter_intersperse)]
-
+use aoc_core::{Answer, AocError, Solution};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -37,8 +36,8 @@ impl Display for Packet {
                     "[{}]",
                     list.iter()
                         .map(|v| v.to_string())
-                        .intersperse(",".to_string())
-                        .collect::<String>()
+                        .collect::<Vec<String>>()
+                        .join(",")
                 ),
                 Packet::Number(num) => num.to_string(),
             }
@@ -48,9 +47,8 @@ impl Display for Packet {
 
 fn packet(input: &str) -> IResult<&str, Packet> {
     alt((
-        delimited(tag("["), separated_list0(tag(","), packet), tag("]"))
-            .map(|vec| Packet::List(vec)),
-        nom::character::complete::u32.map(|num| Packet::Number(num)),
+        delimited(tag("["), separated_list0(tag(","), packet), tag("]")).map(Packet::List),
+        nom::character::complete::u32.map(Packet::Number),
     ))(input)
 }
 
@@ -86,11 +84,11 @@ impl Ord for Packet {
                         }
                     }
                     if l_len > r_len {
-                        return Ordering::Greater;
+                        Ordering::Greater
                     } else if r_len > l_len {
-                        return Ordering::Less;
+                        Ordering::Less
                     } else {
-                        return Ordering::Equal;
+                        Ordering::Equal
                     }
                 }
                 Packet::Number(r_val) => {
@@ -99,15 +97,15 @@ impl Ord for Packet {
                     match Packet::List((*l_list).clone())
                         .cmp(&Packet::List(vec![Packet::Number(*r_val)]))
                     {
-                        Ordering::Less => return Ordering::Less,
-                        Ordering::Greater => return Ordering::Greater,
+                        Ordering::Less => Ordering::Less,
+                        Ordering::Greater => Ordering::Greater,
                         Ordering::Equal => {
                             if l_len > r_len {
-                                return Ordering::Greater;
+                                Ordering::Greater
                             } else if l_len < r_len {
-                                return Ordering::Less;
+                                Ordering::Less
                             } else {
-                                return Ordering::Equal;
+                                Ordering::Equal
                             }
                         }
                     }
@@ -118,28 +116,24 @@ impl Ord for Packet {
                     match (Packet::List(vec![Packet::Number(*l_val)]))
                         .cmp(&Packet::List((*r_list).clone()))
                     {
-                        Ordering::Less => {
-                            return Ordering::Less;
-                        }
-                        Ordering::Greater => {
-                            return Ordering::Greater;
-                        }
+                        Ordering::Less => Ordering::Less,
+                        Ordering::Greater => Ordering::Greater,
                         Ordering::Equal => {
                             if r_list.len() > 1 {
-                                return Ordering::Less;
+                                Ordering::Less
                             } else {
-                                return Ordering::Equal;
+                                Ordering::Equal
                             }
                         }
                     }
                 }
                 Packet::Number(r_val) => {
                     if l_val < r_val {
-                        return Ordering::Less;
+                        Ordering::Less
                     } else if r_val < l_val {
-                        return Ordering::Greater;
+                        Ordering::Greater
                     } else {
-                        return Ordering::Equal;
+                        Ordering::Equal
                     }
                 }
             },
@@ -161,44 +155,64 @@ impl PartialEq for Packet {
 
 impl Eq for Packet {}
 
-pub fn process_part1(input: &str) -> String {
-    let (_, pair_list) = pairs(input).unwrap();
-    let mut correct_count = 0;
-    let mut pair_count = 1;
-    for pair in pair_list {
-        match pair.left.cmp(&pair.right) {
-            Ordering::Less => {
-                correct_count += pair_count;
-            }
-            Ordering::Equal => {
-                panic!("should not get here")
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 13;
+    type Parsed<'a> = &'a str;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let (_, pair_list) = pairs(input).unwrap();
+        let mut correct_count = 0;
+        for (pair_count, pair) in (1..).zip(pair_list) {
+            match pair.left.cmp(&pair.right) {
+                Ordering::Less => {
+                    correct_count += pair_count;
+                }
+                Ordering::Equal => {
+                    panic!("should not get here")
+                }
+                Ordering::Greater => {}
             }
-            Ordering::Greater => {}
         }
-        pair_count += 1;
+        correct_count
     }
-    correct_count.to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let (_, pair_input) = pairs(input).unwrap();
-    let mut pair_list: Vec<Packet> = Vec::new();
-    for pair in pair_input {
-        pair_list.push(pair.left);
-        pair_list.push(pair.right);
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let (_, pair_input) = pairs(input).unwrap();
+        let mut pair_list: Vec<Packet> = Vec::new();
+        for pair in pair_input {
+            pair_list.push(pair.left);
+            pair_list.push(pair.right);
+        }
+        pair_list.push(Packet::List(vec![Packet::List(vec![Packet::Number(2)])]));
+        pair_list.push(Packet::List(vec![Packet::List(vec![Packet::Number(6)])]));
+        pair_list.sort();
+        let mut iter = pair_list.iter();
+        let mut iter2 = pair_list.iter();
+        let pos1 = iter
+            .position(|x| *x == Packet::List(vec![Packet::List(vec![Packet::Number(2)])]))
+            .unwrap();
+        let pos2 = iter2
+            .position(|x| *x == Packet::List(vec![Packet::List(vec![Packet::Number(6)])]))
+            .unwrap();
+        (pos1 + 1) * (pos2 + 1) 
     }
-    pair_list.push(Packet::List(vec![Packet::List(vec![Packet::Number(2)])]));
-    pair_list.push(Packet::List(vec![Packet::List(vec![Packet::Number(6)])]));
-    pair_list.sort();
-    let mut iter = pair_list.iter();
-    let mut iter2 = pair_list.iter();
-    let pos1 = iter
-        .position(|x| *x == Packet::List(vec![Packet::List(vec![Packet::Number(2)])]))
-        .unwrap();
-    let pos2 = iter2
-        .position(|x| *x == Packet::List(vec![Packet::List(vec![Packet::Number(6)])]))
-        .unwrap();
-    ((pos1 + 1) * (pos2 + 1)).to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day13::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day13::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -208,7 +222,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "13");
-        assert_eq!(process_part2(file), "140");
+        assert_eq!(process_part1(file).unwrap(), "13");
+        assert_eq!(process_part2(file).unwrap(), "140");
     }
 }
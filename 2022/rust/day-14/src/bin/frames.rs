@@ -0,0 +1,6 @@
+use aoc_2022_day_14::frames;
+
+fn main() {
+    let file = aoc_core::input::from_args(env!("CARGO_MANIFEST_DIR"));
+    aoc_core::print_or_exit(frames(&file).map(Into::into));
+}
@@ -0,0 +1,269 @@
+//! Sand poured into a cave of rock, one grain at a time, over a dense grid
+//! just big enough for wherever the sand can get to.
+
+use aoc_core::AocError;
+use aoc_grid::{Coord, Grid};
+
+/// A spot in the cave, `y` growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// What's past the edges of the rock scan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    /// Nothing: sand that gets below the lowest rock falls forever, and the
+    /// pouring stops there.
+    Abyss,
+    /// A floor two below the lowest rock, going on forever both ways, so
+    /// sand piles up until it blocks the source.
+    Floor,
+    /// The same floor, with walls just past the leftmost and rightmost
+    /// rocks.
+    Walls,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Air,
+    Rock,
+    Sand,
+}
+
+/// Where a grain ends up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Grain {
+    Rested(Point),
+    /// It fell off into the abyss.
+    Lost,
+    /// The source is buried, so nothing more comes out.
+    Blocked,
+}
+
+/// Where a step down would take a grain.
+enum Below {
+    Open(Point),
+    Solid,
+    Gone,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cave {
+    cells: Grid<Cell>,
+    /// the `x` of the grid's first column
+    left: i32,
+    boundary: Boundary,
+    source: Point,
+    /// the path the last grain fell along before it came to rest; the next
+    /// grain falls the same way until it gets to the end of it
+    path: Vec<Point>,
+    rested: usize,
+}
+
+impl Cave {
+    /// A cave with rock along every line between consecutive points of each
+    /// path, and sand pouring in at `source`.
+    pub fn new(paths: &[Vec<Point>], source: Point, boundary: Boundary) -> Result<Cave, AocError> {
+        let points = || paths.iter().flatten().chain([&source]);
+        let lowest = points().map(|point| point.y).max().unwrap_or(source.y);
+        if points().any(|point| point.y < 0) {
+            return Err(AocError::unsupported("the cave starts at a y of 0"));
+        }
+        let floor = lowest + 2;
+        let min_x = points().map(|point| point.x).min().unwrap_or(source.x);
+        let max_x = points().map(|point| point.x).max().unwrap_or(source.x);
+        let (left, right) = match boundary {
+            // a grain that goes past the side of every rock falls off, so
+            // one column spare either side is enough to see it go
+            Boundary::Abyss => (min_x - 1, max_x + 1),
+            Boundary::Walls => (min_x, max_x),
+            // the sand can only spread out one column either side per row
+            Boundary::Floor => (min_x.min(source.x - floor), max_x.max(source.x + floor)),
+        };
+        let height = match boundary {
+            Boundary::Abyss => lowest + 1,
+            Boundary::Floor | Boundary::Walls => floor,
+        };
+        let mut cave = Cave {
+            cells: Grid::new((right - left + 1) as usize, height as usize, Cell::Air),
+            left,
+            boundary,
+            source,
+            path: vec![source],
+            rested: 0,
+        };
+        for path in paths {
+            for pair in path.windows(2) {
+                let (from, to) = (pair[0], pair[1]);
+                if from.x != to.x && from.y != to.y {
+                    return Err(AocError::unsupported(format!(
+                        "rock runs diagonally from {},{} to {},{}",
+                        from.x, from.y, to.x, to.y
+                    )));
+                }
+                for y in from.y.min(to.y)..=from.y.max(to.y) {
+                    for x in from.x.min(to.x)..=from.x.max(to.x) {
+                        let at = cave
+                            .coord(Point { x, y })
+                            .expect("the grid covers every rock");
+                        cave.cells[at] = Cell::Rock;
+                    }
+                }
+            }
+        }
+        if cave.get(source) == Some(Cell::Rock) {
+            cave.path.clear();
+        }
+        Ok(cave)
+    }
+
+    fn coord(&self, point: Point) -> Option<Coord> {
+        let x = usize::try_from(point.x - self.left).ok()?;
+        let y = usize::try_from(point.y).ok()?;
+        let coord = Coord::new(x, y);
+        self.cells.contains(coord).then_some(coord)
+    }
+
+    pub fn get(&self, point: Point) -> Option<Cell> {
+        self.coord(point).map(|coord| self.cells[coord])
+    }
+
+    /// How many grains have come to rest so far.
+    pub fn rested(&self) -> usize {
+        self.rested
+    }
+
+    fn below(&self, point: Point) -> Below {
+        match self.get(point) {
+            Some(Cell::Air) => Below::Open(point),
+            Some(Cell::Rock | Cell::Sand) => Below::Solid,
+            None if self.boundary == Boundary::Abyss => Below::Gone,
+            // off the grid is either the floor or a wall
+            None => Below::Solid,
+        }
+    }
+
+    /// Pours one grain, which falls down if it can, else down to the left,
+    /// else down to the right, until it can't move or falls out of the cave.
+    pub fn drop_grain(&mut self) -> Grain {
+        while let Some(&at) = self.path.last() {
+            let mut fell = false;
+            for dx in [0, -1, 1] {
+                match self.below(Point {
+                    x: at.x + dx,
+                    y: at.y + 1,
+                }) {
+                    Below::Open(next) => {
+                        self.path.push(next);
+                        fell = true;
+                        break;
+                    }
+                    Below::Solid => {}
+                    Below::Gone => return Grain::Lost,
+                }
+            }
+            if !fell {
+                let coord = self.coord(at).expect("the path stays on the grid");
+                self.cells[coord] = Cell::Sand;
+                self.path.pop();
+                self.rested += 1;
+                return Grain::Rested(at);
+            }
+        }
+        Grain::Blocked
+    }
+
+    /// Pours until a grain falls into the abyss or the source is blocked,
+    /// returning how many grains came to rest.
+    pub fn fill(&mut self) -> usize {
+        while let Grain::Rested(_) = self.drop_grain() {}
+        self.rested
+    }
+
+    /// The cave as it is now: `#` for rock, `o` for sand at rest, `~` along
+    /// the path the next grain will start down, and `+` for the source.
+    pub fn draw_map(&self) -> String {
+        let mut lines = vec![];
+        for (y, row) in self.cells.rows().enumerate() {
+            let line: String = row
+                .iter()
+                .enumerate()
+                .map(|(x, cell)| {
+                    let point = Point {
+                        x: self.left + x as i32,
+                        y: y as i32,
+                    };
+                    match cell {
+                        Cell::Rock => '#',
+                        Cell::Sand => 'o',
+                        Cell::Air if point == self.source => '+',
+                        Cell::Air if self.path.contains(&point) => '~',
+                        Cell::Air => '.',
+                    }
+                })
+                .collect();
+            lines.push(line);
+        }
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    #[test]
+    fn test_boundaries() {
+        // a cup two wide with a ledge out to the right
+        let paths = [
+            vec![point(9, 2), point(9, 4), point(12, 4), point(12, 2)],
+            vec![point(13, 3), point(15, 3)],
+        ];
+        let mut abyss = Cave::new(&paths, point(10, 0), Boundary::Abyss).unwrap();
+        assert_eq!(abyss.drop_grain(), Grain::Rested(point(10, 3)));
+        // the cup fills up to its rim, then the next grain runs off the left
+        assert_eq!(abyss.fill(), 5);
+        assert_eq!(abyss.drop_grain(), Grain::Lost);
+        assert_eq!(
+            abyss.draw_map(),
+            [
+                "..+......",
+                ".~o......",
+                "~#oo#....",
+                "~#oo####.",
+                "~####....",
+            ]
+            .join("\n")
+        );
+
+        // the floor is at y 6, so the pile is a triangle 11 wide
+        let mut floor = Cave::new(&paths, point(10, 0), Boundary::Floor).unwrap();
+        let mut walls = Cave::new(&paths, point(10, 0), Boundary::Walls).unwrap();
+        assert!(walls.fill() < floor.fill());
+        assert_eq!(floor.drop_grain(), Grain::Blocked);
+        assert_eq!(floor.get(point(10, 0)), Some(Cell::Sand));
+        assert_eq!(walls.get(point(10, 0)), Some(Cell::Sand));
+        assert_eq!(floor.get(point(5, 5)), Some(Cell::Sand));
+        assert_eq!(walls.get(point(5, 5)), None);
+
+        assert!(Cave::new(
+            &[vec![point(1, 1), point(2, 2)]],
+            point(0, 0),
+            Boundary::Abyss
+        )
+        .is_err());
+        let mut buried = Cave::new(
+            &[vec![point(0, 0), point(0, 1)]],
+            point(0, 0),
+            Boundary::Floor,
+        )
+        .unwrap();
+        assert_eq!(buried.drop_grain(), Grain::Blocked);
+    }
+}
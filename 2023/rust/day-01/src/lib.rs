@@ -1,64 +1,88 @@
-pub fn process_part1(input: &str) -> String {
-    let value = input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .filter_map(|symbol| symbol.to_digit(10))
-                .collect::<Vec<u32>>()
-        })
-        .collect::<Vec<Vec<u32>>>()
-        .into_iter()
-        .map(|line| match (line.first(), line.last()) {
-            (Some(a), Some(b)) => {
-                //println!("{}", a * 10 + b);
-                a * 10 + b
-            }
-            _ => 0,
-        })
-        .sum::<u32>();
-    format!("{value}")
+use aoc_core::{Answer, AocError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+    type Parsed<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let value = input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .filter_map(|symbol| symbol.to_digit(10))
+                    .collect::<Vec<u32>>()
+            })
+            .collect::<Vec<Vec<u32>>>()
+            .into_iter()
+            .map(|line| match (line.first(), line.last()) {
+                (Some(a), Some(b)) => {
+                    //println!("{}", a * 10 + b);
+                    a * 10 + b
+                }
+                _ => 0,
+            })
+            .sum::<u32>();
+        value
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let value = input
+            .lines()
+            .map(|line| {
+                let mut it = (0..line.len()).filter_map(|index| {
+                    let reduced_line = &line[index..];
+                    let result = if reduced_line.starts_with("one") {
+                        '1'
+                    } else if reduced_line.starts_with("two") {
+                        '2'
+                    } else if reduced_line.starts_with("three") {
+                        '3'
+                    } else if reduced_line.starts_with("four") {
+                        '4'
+                    } else if reduced_line.starts_with("five") {
+                        '5'
+                    } else if reduced_line.starts_with("six") {
+                        '6'
+                    } else if reduced_line.starts_with("seven") {
+                        '7'
+                    } else if reduced_line.starts_with("eight") {
+                        '8'
+                    } else if reduced_line.starts_with("nine") {
+                        '9'
+                    } else {
+                        reduced_line.chars().next().unwrap()
+                    };
+                    result.to_digit(10)
+                });
+                let first = it.next().expect("should be a number");
+
+                match it.next_back() {
+                    Some(num) => format!("{first}{num}"),
+                    None => format!("{first}{first}"),
+                }
+                .parse::<u32>()
+                .expect("should be a valid number")
+            })
+            .sum::<u32>();
+        value
+    }
 }
 
-pub fn process_part2(input: &str) -> String {
-    let value = input
-        .lines()
-        .map(|line| {
-            let mut it = (0..line.len()).filter_map(|index| {
-                let reduced_line = &line[index..];
-                let result = if reduced_line.starts_with("one") {
-                    '1'
-                } else if reduced_line.starts_with("two") {
-                    '2'
-                } else if reduced_line.starts_with("three") {
-                    '3'
-                } else if reduced_line.starts_with("four") {
-                    '4'
-                } else if reduced_line.starts_with("five") {
-                    '5'
-                } else if reduced_line.starts_with("six") {
-                    '6'
-                } else if reduced_line.starts_with("seven") {
-                    '7'
-                } else if reduced_line.starts_with("eight") {
-                    '8'
-                } else if reduced_line.starts_with("nine") {
-                    '9'
-                } else {
-                    reduced_line.chars().next().unwrap()
-                };
-                result.to_digit(10)
-            });
-            let first = it.next().expect("should be a number");
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part1(input)
+}
 
-            match it.last() {
-                Some(num) => format!("{first}{num}"),
-                None => format!("{first}{first}"),
-            }
-            .parse::<u32>()
-            .expect("should be a valid number")
-        })
-        .sum::<u32>();
-    format!("{value}")
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -69,8 +93,8 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "142");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "142");
         let file2 = fs::read_to_string("./test-input-2.txt").unwrap();
-        assert_eq!(process_part2(file2.as_str()), "281");
+        assert_eq!(process_part2(file2.as_str()).unwrap(), "281");
     }
 }
@@ -1,3 +1,5 @@
+use aoc_core::{Answer, AocError, Solution};
+
 const RED_LIMIT: u32 = 12;
 const GREEN_LIMIT: u32 = 13;
 const BLUE_LIMIT: u32 = 14;
@@ -17,7 +19,7 @@ fn process_game_type1(input: &str) -> u32 {
         let pull_segment = round.split(", ");
         for segment in pull_segment {
             let (count, color) = (
-                segment.split_whitespace().nth(0).unwrap(),
+                segment.split_whitespace().next().unwrap(),
                 segment.split_whitespace().nth(1).unwrap(),
             );
             match color {
@@ -61,7 +63,7 @@ fn process_game_type2(input: &str) -> u32 {
         let pull_segment = round.split(", ");
         for segment in pull_segment {
             let (count, color) = (
-                segment.split_whitespace().nth(0).unwrap(),
+                segment.split_whitespace().next().unwrap(),
                 segment.split_whitespace().nth(1).unwrap(),
             );
             match color {
@@ -90,20 +92,42 @@ fn process_game_type2(input: &str) -> u32 {
     needed_red * needed_green * needed_blue
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut possible_id_sum = 0;
-    for game in input.lines() {
-        possible_id_sum += process_game_type1(game);
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+    type Parsed<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut possible_id_sum = 0;
+        for game in input.lines() {
+            possible_id_sum += process_game_type1(game);
+        }
+        possible_id_sum
     }
-    format!("{possible_id_sum}")
-}
 
-pub fn process_part2(input: &str) -> String {
-    let mut sum = 0;
-    for game in input.lines() {
-        sum += process_game_type2(game);
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut sum = 0;
+        for game in input.lines() {
+            sum += process_game_type2(game);
+        }
+        sum
     }
-    format!("{sum}")
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day02::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day02::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -114,8 +138,8 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "8");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "8");
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part2(file.as_str()), "2286")
+        assert_eq!(process_part2(file.as_str()).unwrap(), "2286")
     }
 }
@@ -1,3 +1,5 @@
+use aoc_core::{Answer, AocError, Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Position {
     y: usize,
@@ -41,7 +43,7 @@ struct Symbol {
 
 fn check_if_label_is_part_number(
     label: &mut Label,
-    schematic: &Vec<Vec<Symbol>>,
+    schematic: &[Vec<Symbol>],
     lines: usize,
     cols: usize,
 ) -> bool {
@@ -50,72 +52,75 @@ fn check_if_label_is_part_number(
     let label_width = label.length;
     // now we walk around each item in the label_list and look for clues I mean parts
     // start at left of first letter
-    if x_start != 0 && x_start - 1 < cols && y_start < lines {
-        if !(schematic[y_start][x_start - 1].symbol.is_alphanumeric())
-            && (schematic[y_start][x_start - 1].symbol != '.')
-        {
-            label.part_location = PartData::Present(schematic[y_start][x_start - 1].clone());
-            return true;
-        }
+    if x_start != 0
+        && x_start - 1 < cols
+        && y_start < lines
+        && !(schematic[y_start][x_start - 1].symbol.is_alphanumeric())
+        && (schematic[y_start][x_start - 1].symbol != '.')
+    {
+        label.part_location = PartData::Present(schematic[y_start][x_start - 1]);
+        return true;
     }
     // go up one
-    if x_start != 0 && x_start - 1 <= cols && y_start != 0 && y_start - 1 < lines {
-        if !(schematic[y_start - 1][x_start - 1].symbol.is_alphanumeric())
-            && (schematic[y_start - 1][x_start - 1].symbol != '.')
-        {
-            label.part_location = PartData::Present(schematic[y_start - 1][x_start - 1].clone());
-            return true;
-        }
+    if x_start != 0
+        && x_start - 1 <= cols
+        && y_start != 0
+        && y_start - 1 < lines
+        && !(schematic[y_start - 1][x_start - 1].symbol.is_alphanumeric())
+        && (schematic[y_start - 1][x_start - 1].symbol != '.')
+    {
+        label.part_location = PartData::Present(schematic[y_start - 1][x_start - 1]);
+        return true;
     }
     // go down one
-    if x_start != 0 && x_start - 1 <= cols && y_start + 1 < lines {
-        if !(schematic[y_start + 1][x_start - 1].symbol.is_alphanumeric())
-            && (schematic[y_start + 1][x_start - 1].symbol != '.')
-        {
-            label.part_location = PartData::Present(schematic[y_start + 1][x_start - 1].clone());
-            return true;
-        }
+    if x_start != 0
+        && x_start - 1 <= cols
+        && y_start + 1 < lines
+        && !(schematic[y_start + 1][x_start - 1].symbol.is_alphanumeric())
+        && (schematic[y_start + 1][x_start - 1].symbol != '.')
+    {
+        label.part_location = PartData::Present(schematic[y_start + 1][x_start - 1]);
+        return true;
     }
     let mut walk = 0;
     while walk <= label_width {
         // we need to go all the way past to the diagnal above and to the right so <= not <
-        if x_start + walk < cols && y_start != 0 && y_start - 1 < lines {
-            if !(schematic[y_start - 1][x_start + walk]
+        if x_start + walk < cols
+            && y_start != 0
+            && y_start - 1 < lines
+            && !(schematic[y_start - 1][x_start + walk]
                 .symbol
                 .is_alphanumeric())
-                && (schematic[y_start - 1][x_start + walk].symbol != '.')
-            {
-                label.part_location =
-                    PartData::Present(schematic[y_start - 1][x_start + walk].clone());
-                return true;
-            }
+            && (schematic[y_start - 1][x_start + walk].symbol != '.')
+        {
+            label.part_location = PartData::Present(schematic[y_start - 1][x_start + walk]);
+            return true;
         }
         walk += 1;
     }
     // now we do square on the right
-    if x_start + label_width < cols && y_start < lines {
-        if !(schematic[y_start][x_start + label_width]
+    if x_start + label_width < cols
+        && y_start < lines
+        && !(schematic[y_start][x_start + label_width]
             .symbol
             .is_alphanumeric())
-            && (schematic[y_start][x_start + label_width].symbol != '.')
-        {
-            label.part_location =
-                PartData::Present(schematic[y_start][x_start + label_width].clone());
-            return true;
-        }
+        && (schematic[y_start][x_start + label_width].symbol != '.')
+    {
+        label.part_location = PartData::Present(schematic[y_start][x_start + label_width]);
+        return true;
     }
     // now do the bottom
     walk = 0;
     while walk <= label_width {
-        if x_start + walk < cols && y_start + 1 < lines {
-            if !(schematic[y_start + 1][x_start + walk]
+        if x_start + walk < cols
+            && y_start + 1 < lines
+            && !(schematic[y_start + 1][x_start + walk]
                 .symbol
                 .is_alphanumeric())
-                && (schematic[y_start + 1][x_start + walk].symbol != '.')
-            {
-                label.part_location = PartData::Present(schematic[y_start + 1][x_start + walk]);
-                return true;
-            }
+            && (schematic[y_start + 1][x_start + walk].symbol != '.')
+        {
+            label.part_location = PartData::Present(schematic[y_start + 1][x_start + walk]);
+            return true;
         }
         walk += 1;
     }
@@ -132,7 +137,7 @@ fn get_line_width(input: &str) -> usize {
 }
 
 fn build_schematic(input: &str) -> Vec<Vec<Symbol>> {
-    let line_width = get_line_width(input);
+    let _line_width = get_line_width(input);
     // input sanity checks, not needed since they pass
     //for line in input.lines() {
     //    if line.len() > line_width {
@@ -155,11 +160,11 @@ fn build_schematic(input: &str) -> Vec<Vec<Symbol>> {
         }
         line_num += 1;
     }
-    let total_lines = line_num;
+    let _total_lines = line_num;
     // schematic built, now parse for all the labels
     let mut label_list: Vec<Label> = Vec::default();
-    let mut line_iter = schematic.iter();
-    while let Some(line) = line_iter.next() {
+    let line_iter = schematic.iter();
+    for line in line_iter {
         let mut symbol_iter = line.iter().peekable();
         while let Some(symbol) = symbol_iter.next() {
             if symbol.symbol.is_numeric() {
@@ -167,10 +172,7 @@ fn build_schematic(input: &str) -> Vec<Vec<Symbol>> {
                 let x_pos = symbol.pos.x;
                 let mut new_label_chars: Vec<char> = Vec::default();
                 new_label_chars.push(symbol.symbol);
-                while symbol_iter
-                    .peek()
-                    .is_some_and(|v| (**v).symbol.is_numeric())
-                {
+                while symbol_iter.peek().is_some_and(|v| v.symbol.is_numeric()) {
                     new_label_chars.push(
                         symbol_iter
                             .next()
@@ -192,10 +194,10 @@ fn build_schematic(input: &str) -> Vec<Vec<Symbol>> {
     schematic
 }
 
-fn build_label_list(schematic: &Vec<Vec<Symbol>>) -> Vec<Label> {
+fn build_label_list(schematic: &[Vec<Symbol>]) -> Vec<Label> {
     let mut label_list: Vec<Label> = Vec::default();
-    let mut line_iter = schematic.iter();
-    while let Some(line) = line_iter.next() {
+    let line_iter = schematic.iter();
+    for line in line_iter {
         let mut symbol_iter = line.iter().peekable();
         while let Some(symbol) = symbol_iter.next() {
             if symbol.symbol.is_numeric() {
@@ -203,10 +205,7 @@ fn build_label_list(schematic: &Vec<Vec<Symbol>>) -> Vec<Label> {
                 let x_pos = symbol.pos.x;
                 let mut new_label_chars: Vec<char> = Vec::default();
                 new_label_chars.push(symbol.symbol);
-                while symbol_iter
-                    .peek()
-                    .is_some_and(|v| (**v).symbol.is_numeric())
-                {
+                while symbol_iter.peek().is_some_and(|v| v.symbol.is_numeric()) {
                     new_label_chars.push(
                         symbol_iter
                             .next()
@@ -228,83 +227,103 @@ fn build_label_list(schematic: &Vec<Vec<Symbol>>) -> Vec<Label> {
     label_list
 }
 
-pub fn process_part1(input: &str) -> String {
-    let line_width = get_line_width(input);
-    let schematic: Vec<Vec<Symbol>> = build_schematic(input);
-    // schematic built, now parse for all the labels
-    let mut label_list: Vec<Label> = build_label_list(&schematic);
-    // now we walk around each item in the label_list and look for clues I mean parts
-    for label in label_list.iter_mut() {
-        match check_if_label_is_part_number(label, &schematic, schematic.len(), line_width) {
-            true => label.is_part_number = IsPartNumber::True,
-            false => label.is_part_number = IsPartNumber::False,
-        }
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+    type Parsed<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
     }
-    label_list
-        .iter()
-        .map(|label| match label.is_part_number {
-            IsPartNumber::Unchecked => panic!("should have been checked"),
-            IsPartNumber::True => label.value,
-            IsPartNumber::False => 0,
-        })
-        .sum::<u32>()
-        .to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let line_width = get_line_width(input);
-    let schematic: Vec<Vec<Symbol>> = build_schematic(input);
-    // schematic built, now parse for all the labels
-    let mut label_list: Vec<Label> = build_label_list(&schematic);
-    // now we walk around each item in the label_list and look for clues I mean parts
-    for label in label_list.iter_mut() {
-        match check_if_label_is_part_number(label, &schematic, schematic.len(), line_width) {
-            true => label.is_part_number = IsPartNumber::True,
-            false => label.is_part_number = IsPartNumber::False,
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let line_width = get_line_width(input);
+        let schematic: Vec<Vec<Symbol>> = build_schematic(input);
+        // schematic built, now parse for all the labels
+        let mut label_list: Vec<Label> = build_label_list(&schematic);
+        // now we walk around each item in the label_list and look for clues I mean parts
+        for label in label_list.iter_mut() {
+            match check_if_label_is_part_number(label, &schematic, schematic.len(), line_width) {
+                true => label.is_part_number = IsPartNumber::True,
+                false => label.is_part_number = IsPartNumber::False,
+            }
         }
+        label_list
+            .iter()
+            .map(|label| match label.is_part_number {
+                IsPartNumber::Unchecked => panic!("should have been checked"),
+                IsPartNumber::True => label.value,
+                IsPartNumber::False => 0,
+            })
+            .sum::<u32>()
     }
-    let mut possible_gears: Vec<GearData> = Vec::default();
-    //determine all possible gears
-    for label in label_list {
-        match label.part_location {
-            PartData::Unchecked => panic!("all parts should have been checked"),
-            PartData::None => continue,
-            PartData::Present(data) => {
-                if data.symbol == '*' {
-                    let mut gear_found = false;
-                    for gear in possible_gears.iter_mut() {
-                        if gear.pos == data.pos {
-                            gear.label2 = label.value;
-                            gear_found = true;
-                            break;
-                        } else {
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let line_width = get_line_width(input);
+        let schematic: Vec<Vec<Symbol>> = build_schematic(input);
+        // schematic built, now parse for all the labels
+        let mut label_list: Vec<Label> = build_label_list(&schematic);
+        // now we walk around each item in the label_list and look for clues I mean parts
+        for label in label_list.iter_mut() {
+            match check_if_label_is_part_number(label, &schematic, schematic.len(), line_width) {
+                true => label.is_part_number = IsPartNumber::True,
+                false => label.is_part_number = IsPartNumber::False,
+            }
+        }
+        let mut possible_gears: Vec<GearData> = Vec::default();
+        //determine all possible gears
+        for label in label_list {
+            match label.part_location {
+                PartData::Unchecked => panic!("all parts should have been checked"),
+                PartData::None => continue,
+                PartData::Present(data) => {
+                    if data.symbol == '*' {
+                        let mut gear_found = false;
+                        for gear in possible_gears.iter_mut() {
+                            if gear.pos == data.pos {
+                                gear.label2 = label.value;
+                                gear_found = true;
+                                break;
+                            } else {
+                                continue;
+                            }
+                        }
+                        if gear_found {
                             continue;
+                        } else {
+                            possible_gears.push(GearData {
+                                label1: label.value,
+                                label2: 0,
+                                pos: data.pos,
+                            })
                         }
                     }
-                    if gear_found {
-                        continue;
-                    } else {
-                        possible_gears.push(GearData {
-                            label1: label.value,
-                            label2: 0,
-                            pos: data.pos,
-                        })
-                    }
                 }
             }
         }
+        possible_gears
+            .iter()
+            .map(|gear| {
+                if gear.label2 == 0 {
+                    0
+                } else {
+                    gear.label1 * gear.label2
+                }
+            })
+            .sum::<u32>()
     }
-    possible_gears
-        .iter()
-        .map(|gear| {
-            if gear.label2 == 0 {
-                0
-            } else {
-                gear.label1 * gear.label2
-            }
-        })
-        .sum::<u32>()
-        .to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day03::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day03::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -315,7 +334,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "4361");
-        assert_eq!(process_part2(file.as_str()), "467835")
+        assert_eq!(process_part1(file.as_str()).unwrap(), "4361");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "467835")
     }
 }
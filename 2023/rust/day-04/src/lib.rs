@@ -1,3 +1,5 @@
+use aoc_core::{Answer, AocError, Solution};
+
 fn count_sorted_matches(winning: Vec<u32>, picked: Vec<u32>) -> u32 {
     let mut matches = 0;
     for winner in winning.iter() {
@@ -20,7 +22,7 @@ fn process_game_winner_count(game: &str) -> u32 {
         .nth(1)
         .expect("should be input after :")
         .split('|')
-        .nth(0)
+        .next()
         .expect("should be input before |")
         .split_ascii_whitespace()
         .filter_map(|num| num.parse::<u32>().ok())
@@ -42,42 +44,58 @@ fn process_game_winner_count(game: &str) -> u32 {
 
 fn calculate_score(wins: u32) -> u32 {
     if wins == 0 {
-        return 0;
+        0
     } else {
         u32::pow(2, wins - 1)
     }
 }
 
-pub fn process_part1(input: &str) -> String {
-    input
-        .lines()
-        .map(|game| process_game_winner_count(game))
-        .map(|wins| calculate_score(wins))
-        .sum::<u32>()
-        .to_string()
-}
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+    type Parsed<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
 
-pub fn process_part2(input: &str) -> String {
-    let mut copy_total: Vec<u32> = Vec::default();
-    for _ in input.lines() {
-        copy_total.push(1);
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        input
+            .lines()
+            .map(process_game_winner_count)
+            .map(calculate_score)
+            .sum::<u32>()
     }
-    let mut game_id: usize = 1;
-    for game in input.lines() {
-        let wins: usize = process_game_winner_count(game)
-            .try_into()
-            .expect("u32 should fit in usize");
-        if wins > 0 {
-            for new_copy in (game_id + 1)..(game_id + 1 + wins) {
-                if (new_copy - 1) < copy_total.len() {
-                    // vec is 0 indexed so we need to offset the id
-                    copy_total[new_copy - 1] += copy_total[game_id - 1]; // vec is 0 indexed so we need to offset the id
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut copy_total: Vec<u32> = vec![1; input.lines().count()];
+        for (game_id, game) in (1..).zip(input.lines()) {
+            let wins: usize = process_game_winner_count(game)
+                .try_into()
+                .expect("u32 should fit in usize");
+            if wins > 0 {
+                for new_copy in (game_id + 1)..(game_id + 1 + wins) {
+                    if (new_copy - 1) < copy_total.len() {
+                        // vec is 0 indexed so we need to offset the id
+                        copy_total[new_copy - 1] += copy_total[game_id - 1]; // vec is 0 indexed so we need to offset the id
+                    }
                 }
             }
         }
-        game_id += 1; // move to next game
+        copy_total.iter().sum::<u32>()
     }
-    copy_total.iter().sum::<u32>().to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day04::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day04::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -88,7 +106,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "13");
-        assert_eq!(process_part2(file.as_str()), "30");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "13");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "30");
     }
 }
@@ -1,3 +1,6 @@
+use aoc_core::{Answer, AocError, Solution};
+use aoc_interval::{IntervalSet, ShiftMap};
+
 #[derive(Debug, Clone)]
 struct Group {
     source: String,
@@ -7,15 +10,9 @@ struct Group {
 
 #[derive(Debug, Clone, Copy)]
 struct RemapRange {
-    source_start: usize,
-    destination_start: usize,
-    range: usize,
-}
-
-#[derive(Debug, Clone, Copy)]
-struct SeedRange {
-    start: usize,
-    range: usize,
+    source_start: i64,
+    destination_start: i64,
+    range: i64,
 }
 
 fn parse_group(group: &str) -> Group {
@@ -24,7 +21,7 @@ fn parse_group(group: &str) -> Group {
         .next()
         .expect("group &str ended early")
         .split('-')
-        .nth(0)
+        .next()
         .expect("should be a source")
         .to_string();
     let destination = group
@@ -43,17 +40,17 @@ fn parse_group(group: &str) -> Group {
             let destination_start = map_iter
                 .next()
                 .expect("dest start should be present")
-                .parse::<usize>()
+                .parse::<i64>()
                 .expect("dest should be a number");
             let source_start = map_iter
                 .next()
                 .expect("source start should be present")
-                .parse::<usize>()
+                .parse::<i64>()
                 .expect("source should be a number");
             let range = map_iter
                 .next()
                 .expect("range should be present")
-                .parse::<usize>()
+                .parse::<i64>()
                 .expect("range should be a number");
             RemapRange {
                 source_start,
@@ -69,110 +66,98 @@ fn parse_group(group: &str) -> Group {
     }
 }
 
-fn follow_remaps(seed: &usize, remap_groups: &Vec<Group>) -> usize {
-    let mut current_id = seed.clone();
-    let mut current_type: String = String::from("seed");
+/// Each group's remaps as one map, checking every group picks up where the
+/// one before it left off.
+fn shift_maps(remap_groups: &[Group]) -> Vec<ShiftMap> {
+    let mut current_type = "seed";
+    let mut maps = vec![];
     for group in remap_groups {
         if group.source != current_type {
             panic!(
                 "remap misaigned by source, expected {} but started on {current_type}",
                 group.source
             )
-        } else {
-            for remap in group.remaps.clone() {
-                if current_id == remap.source_start
-                    || (current_id > remap.source_start
-                        && current_id < remap.source_start + remap.range)
-                {
-                    let offset = current_id - remap.source_start;
-                    //println!(
-                    //    "updating {current_type} from {current_id} to {} with id {}",
-                    //    group.destination,
-                    //    remap.destination_start + offset
-                    //);
-                    current_id = remap.destination_start + offset;
-                    break;
-                } else {
-                    continue; // do not change current_id, and check next remap range
-                }
-            }
-            current_type = group.destination.clone();
         }
+        let mut map = ShiftMap::new();
+        for remap in &group.remaps {
+            map.add(
+                remap.source_start..remap.source_start + remap.range,
+                remap.destination_start - remap.source_start,
+            );
+        }
+        maps.push(map);
+        current_type = &group.destination;
     }
-    current_id
+    maps
 }
 
-fn follow_remaps_input_ranges(seed: SeedRange, remap_groups: &Vec<Group>) -> usize {
-    let mut min = usize::MAX;
-    for i in seed.start..seed.start + seed.range - 1 {
-        // this approach will take tens of minutes with inputs that are on the order of billions of seeds
-        let val = follow_remaps(&i, remap_groups);
-        if val < min {
-            min = val;
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+    type Parsed<'a> = &'a str;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut group_iter = input.split("\n\n");
+        let starting_seeds = group_iter
+            .next()
+            .expect("input is malformed")
+            .split(":")
+            .nth(1)
+            .expect("input should contain values after :")
+            .split_ascii_whitespace()
+            .filter_map(|num| num.parse::<i64>().ok())
+            .collect::<Vec<i64>>();
+        let remap_groups = group_iter.map(parse_group).collect::<Vec<Group>>();
+        let maps = shift_maps(&remap_groups);
+        starting_seeds
+            .iter()
+            .map(|&seed| maps.iter().fold(seed, |id, map| map.get(id)))
+            .min()
+            .expect("should be a min")
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut group_iter = input.split("\n\n");
+        let mut starting_seeds_iter = group_iter
+            .next()
+            .expect("input is malformed")
+            .split(":")
+            .nth(1)
+            .expect("input should contain values after :")
+            .split_ascii_whitespace();
+        let mut seeds = IntervalSet::new();
+        while let Some(num) = starting_seeds_iter.next() {
+            let start = num.parse::<i64>().expect("should be a number");
+            let range = starting_seeds_iter
+                .next()
+                .expect("seed values must come in pairs")
+                .parse::<i64>()
+                .expect("should be a number");
+            seeds.insert(start..start + range);
         }
+        let remap_groups = group_iter.map(parse_group).collect::<Vec<Group>>();
+        shift_maps(&remap_groups)
+            .iter()
+            .fold(seeds, |ids, map| map.apply(&ids))
+            .min()
+            .expect("should be a min")
     }
-    min
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut group_iter = input.split("\n\n");
-    let starting_seeds = group_iter
-        .next()
-        .expect("input is malformed")
-        .split(":")
-        .nth(1)
-        .expect("input should contain values after :")
-        .split_ascii_whitespace()
-        .filter_map(|num| num.parse::<usize>().ok())
-        .collect::<Vec<usize>>();
-    let remap_groups = group_iter
-        .map(|group| parse_group(group))
-        .collect::<Vec<Group>>();
-    let ending_locations = starting_seeds
-        .iter()
-        .map(|seed| follow_remaps(&seed, &remap_groups))
-        .collect::<Vec<usize>>();
-    ending_locations
-        .iter()
-        .min()
-        .expect("should be a min")
-        .to_string()
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day05::solve_part1(input)
 }
 
-pub fn process_part2(input: &str) -> String {
-    let mut group_iter = input.split("\n\n");
-    let mut starting_seeds_iter = group_iter
-        .next()
-        .expect("input is malformed")
-        .split(":")
-        .nth(1)
-        .expect("input should contain values after :")
-        .split_ascii_whitespace();
-    let mut seed_ranges: Vec<SeedRange> = Vec::default();
-    loop {
-        let start = match starting_seeds_iter.next() {
-            Some(num) => num.parse::<usize>().expect("should be a number"),
-            None => break,
-        };
-        let range = starting_seeds_iter
-            .next()
-            .expect("seed values must come in pairs")
-            .parse::<usize>()
-            .expect("should be a number");
-        seed_ranges.push(SeedRange { start, range })
-    }
-    let remap_groups = group_iter
-        .map(|group| parse_group(group))
-        .collect::<Vec<Group>>();
-    let ending_locations = seed_ranges
-        .iter()
-        .map(|seed| follow_remaps_input_ranges(seed.clone(), &remap_groups))
-        .collect::<Vec<usize>>();
-    ending_locations
-        .iter()
-        .min()
-        .expect("should be a min")
-        .to_string()
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day05::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -183,7 +168,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "35");
-        assert_eq!(process_part2(file.as_str()), "46");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "35");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "46");
     }
 }
@@ -1,3 +1,5 @@
+use aoc_core::{Answer, AocError, Solution};
+
 fn determine_win_strategy_count(time: u64, distance: u64) -> u64 {
     let mut wins = 0;
     for i in 0..time {
@@ -8,59 +10,80 @@ fn determine_win_strategy_count(time: u64, distance: u64) -> u64 {
     wins
 }
 
-pub fn process_part1(input: &str) -> String {
-    let times: Vec<u64> = input
-        .lines()
-        .nth(0)
-        .expect("some input")
-        .split("Time:")
-        .nth(1)
-        .expect("need times")
-        .split_ascii_whitespace()
-        .map(|num| num.parse::<u64>().expect("times should be numbers"))
-        .collect::<Vec<u64>>();
-    let distances: Vec<u64> = input
-        .lines()
-        .nth(1)
-        .expect("more input")
-        .split("Distance:")
-        .nth(1)
-        .expect("need distances")
-        .split_ascii_whitespace()
-        .map(|dist| dist.parse::<u64>().expect("distances should be numbers"))
-        .collect::<Vec<u64>>();
-    dbg!(&times);
-    dbg!(&distances);
-    std::iter::zip(times, distances)
-        .map(|(time, distance)| determine_win_strategy_count(time, distance))
-        .product::<u64>()
-        .to_string()
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+    type Parsed<'a> = &'a str;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let times: Vec<u64> = input
+            .lines()
+            .next()
+            .expect("some input")
+            .split("Time:")
+            .nth(1)
+            .expect("need times")
+            .split_ascii_whitespace()
+            .map(|num| num.parse::<u64>().expect("times should be numbers"))
+            .collect::<Vec<u64>>();
+        let distances: Vec<u64> = input
+            .lines()
+            .nth(1)
+            .expect("more input")
+            .split("Distance:")
+            .nth(1)
+            .expect("need distances")
+            .split_ascii_whitespace()
+            .map(|dist| dist.parse::<u64>().expect("distances should be numbers"))
+            .collect::<Vec<u64>>();
+        dbg!(&times);
+        dbg!(&distances);
+        std::iter::zip(times, distances)
+            .map(|(time, distance)| determine_win_strategy_count(time, distance))
+            .product::<u64>()
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let time: u64 = input
+            .lines()
+            .next()
+            .expect("some input")
+            .split("Time:")
+            .nth(1)
+            .expect("need times")
+            .replace(" ", "")
+            .parse::<u64>()
+            .expect("time should a number");
+        let distance = input
+            .lines()
+            .nth(1)
+            .expect("more input")
+            .split("Distance:")
+            .nth(1)
+            .expect("need distances")
+            .replace(" ", "")
+            .parse::<u64>()
+            .expect("distance should a number");
+        dbg!(&time);
+        dbg!(&distance);
+        determine_win_strategy_count(time, distance)
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day06::solve_part1(input)
 }
 
-pub fn process_part2(input: &str) -> String {
-    let time: u64 = input
-        .lines()
-        .nth(0)
-        .expect("some input")
-        .split("Time:")
-        .nth(1)
-        .expect("need times")
-        .replace(" ", "")
-        .parse::<u64>()
-        .expect("time should a number");
-    let distance = input
-        .lines()
-        .nth(1)
-        .expect("more input")
-        .split("Distance:")
-        .nth(1)
-        .expect("need distances")
-        .replace(" ", "")
-        .parse::<u64>()
-        .expect("distance should a number");
-    dbg!(&time);
-    dbg!(&distance);
-    determine_win_strategy_count(time, distance).to_string()
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day06::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -71,7 +94,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "288");
-        assert_eq!(process_part2(file.as_str()), "71503");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "288");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "71503");
     }
 }
@@ -1,3 +1,4 @@
+use aoc_core::{Answer, AocError, Solution};
 use std::cmp::Ordering;
 
 #[derive(PartialEq, PartialOrd, Ord, Eq, Copy, Clone, Debug)]
@@ -24,7 +25,7 @@ impl TryFrom<&Vec<Card>> for HandType {
         //Check of each type
         let mut count_array: [u8; 13] = [0; 13];
         for card in hand {
-            count_array[(card.clone() as usize) - 2] += 1;
+            count_array[(*card as usize) - 2] += 1;
         }
         let mut pair_present = false;
         let mut triple_present = false;
@@ -44,13 +45,13 @@ impl TryFrom<&Vec<Card>> for HandType {
             }
         }
         if triple_present && pair_present {
-            return Ok(HandType::FullHouse);
+            Ok(HandType::FullHouse)
         } else if triple_present {
-            return Ok(HandType::ThreeOfAKind);
+            Ok(HandType::ThreeOfAKind)
         } else if pair_present {
-            return Ok(HandType::OnePair);
+            Ok(HandType::OnePair)
         } else {
-            return Ok(HandType::HighCard);
+            Ok(HandType::HighCard)
         }
     }
 }
@@ -202,7 +203,7 @@ impl TryFrom<&Vec<JokerCard>> for JokerHandType {
         //Check of each type
         let mut count_array: [u8; 13] = [0; 13];
         for card in hand {
-            count_array[(card.clone() as usize) - 1] += 1;
+            count_array[(*card as usize) - 1] += 1;
         }
         let mut pair_count = 0;
         let mut true_pair_count = 0;
@@ -240,21 +241,21 @@ impl TryFrom<&Vec<JokerCard>> for JokerHandType {
         if (true_triple_present && true_pair_count > 0)
             || (count_array[0] == 1 && true_pair_count >= 2)
         {
-            return Ok(JokerHandType::FullHouse);
+            Ok(JokerHandType::FullHouse)
         } else if triple_present {
-            return Ok(JokerHandType::ThreeOfAKind);
+            Ok(JokerHandType::ThreeOfAKind)
         } else if true_pair_count == 2 {
-            return Ok(JokerHandType::TwoPair);
+            Ok(JokerHandType::TwoPair)
         } else if pair_count >= 2 {
             if count_array[0] >= 2 {
-                return Ok(JokerHandType::TwoPair);
+                Ok(JokerHandType::TwoPair)
             } else {
-                return Ok(JokerHandType::OnePair);
+                Ok(JokerHandType::OnePair)
             }
         } else if pair_count == 1 {
-            return Ok(JokerHandType::OnePair);
+            Ok(JokerHandType::OnePair)
         } else {
-            return Ok(JokerHandType::HighCard);
+            Ok(JokerHandType::HighCard)
         }
     }
 }
@@ -306,80 +307,100 @@ impl PartialEq for JokerHandData {
     }
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut all_hands: Vec<HandData> = input
-        .lines()
-        .map(|input_line| {
-            let raw_hand = input_line
-                .split_ascii_whitespace()
-                .nth(0)
-                .expect("should be a hand");
-            let bid: u64 = input_line
-                .split_ascii_whitespace()
-                .nth(1)
-                .expect("should be text for a bid")
-                .parse()
-                .expect("bid should be a number");
-            let hand: Vec<Card> = raw_hand
-                .chars()
-                .map(|character| {
-                    character
-                        .try_into()
-                        .expect(format!("{character} should be a valid card").as_str())
-                })
-                .collect();
-            HandData {
-                hand_type: (&hand).try_into().expect("hand length was incorrect"),
-                hand,
-                bid,
-            }
-        })
-        .collect();
-    all_hands.sort();
-    all_hands
-        .iter()
-        .enumerate()
-        .map(|(i, hand)| ((i as u64) + 1) * hand.bid)
-        .sum::<u64>()
-        .to_string()
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+    type Parsed<'a> = &'a str;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut all_hands: Vec<HandData> = input
+            .lines()
+            .map(|input_line| {
+                let raw_hand = input_line
+                    .split_ascii_whitespace()
+                    .next()
+                    .expect("should be a hand");
+                let bid: u64 = input_line
+                    .split_ascii_whitespace()
+                    .nth(1)
+                    .expect("should be text for a bid")
+                    .parse()
+                    .expect("bid should be a number");
+                let hand: Vec<Card> = raw_hand
+                    .chars()
+                    .map(|character| {
+                        character
+                            .try_into()
+                            .unwrap_or_else(|_| panic!("{character} should be a valid card"))
+                    })
+                    .collect();
+                HandData {
+                    hand_type: (&hand).try_into().expect("hand length was incorrect"),
+                    hand,
+                    bid,
+                }
+            })
+            .collect();
+        all_hands.sort();
+        all_hands
+            .iter()
+            .enumerate()
+            .map(|(i, hand)| ((i as u64) + 1) * hand.bid)
+            .sum::<u64>()
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut all_hands: Vec<JokerHandData> = input
+            .lines()
+            .map(|input_line| {
+                let raw_hand = input_line
+                    .split_ascii_whitespace()
+                    .next()
+                    .expect("should be a hand");
+                let bid: u64 = input_line
+                    .split_ascii_whitespace()
+                    .nth(1)
+                    .expect("should be text for a bid")
+                    .parse()
+                    .expect("bid should be a number");
+                let hand: Vec<JokerCard> = raw_hand
+                    .chars()
+                    .map(|character| {
+                        character
+                            .try_into()
+                            .unwrap_or_else(|_| panic!("{character} should be a valid card"))
+                    })
+                    .collect();
+                JokerHandData {
+                    hand_type: (&hand).try_into().expect("hand length was incorrect"),
+                    hand,
+                    bid,
+                }
+            })
+            .collect();
+        all_hands.sort();
+        all_hands
+            .iter()
+            .enumerate()
+            .map(|(i, hand)| ((i as u64) + 1) * hand.bid)
+            .sum::<u64>()
+    }
 }
 
-pub fn process_part2(input: &str) -> String {
-    let mut all_hands: Vec<JokerHandData> = input
-        .lines()
-        .map(|input_line| {
-            let raw_hand = input_line
-                .split_ascii_whitespace()
-                .nth(0)
-                .expect("should be a hand");
-            let bid: u64 = input_line
-                .split_ascii_whitespace()
-                .nth(1)
-                .expect("should be text for a bid")
-                .parse()
-                .expect("bid should be a number");
-            let hand: Vec<JokerCard> = raw_hand
-                .chars()
-                .map(|character| {
-                    character
-                        .try_into()
-                        .expect(format!("{character} should be a valid card").as_str())
-                })
-                .collect();
-            JokerHandData {
-                hand_type: (&hand).try_into().expect("hand length was incorrect"),
-                hand,
-                bid,
-            }
-        })
-        .collect();
-    all_hands.sort();
-    all_hands
-        .iter()
-        .enumerate()
-        .map(|(i, hand)| ((i as u64) + 1) * hand.bid)
-        .sum::<u64>()
-        .to_string()
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day07::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day07::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -390,12 +411,12 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "6440");
-        assert_eq!(process_part2(file.as_str()), "5905");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "6440");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "5905");
     }
     #[test]
     fn test_big_input() {
         let file = fs::read_to_string("./input.txt").unwrap();
-        assert_eq!(process_part2(file.as_str()), "idk lmao");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "249620106");
     }
 }
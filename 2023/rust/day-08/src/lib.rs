@@ -1,3 +1,4 @@
+use aoc_core::{Answer, AocError, Solution};
 use std::collections::BTreeMap;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -53,129 +54,69 @@ fn lcm(n: u64, m: u64) -> u64 {
     n * m / gcd(n, m)
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut lines_itr = input.lines();
-    let steps: Vec<Step> = lines_itr
-        .next()
-        .expect("should be a set of steps")
-        .chars()
-        .map(|i| {
-            i.try_into()
-                .expect(format!("{i} should be an R or L").as_str())
-        })
-        .collect();
-    let nodes: BTreeMap<String, Node> = lines_itr
-        .filter_map(|line| {
-            let node_name = match line.split(" = ").nth(0) {
-                Some(name) => name.trim(),
-                None => return None,
-            };
-            let left_node = match line.split(&['(', ','][..]).nth(1) {
-                Some(name) => name.trim(),
-                None => return None,
-            };
-            let right_node = match line.split(&[',', ')'][..]).nth(1) {
-                Some(name) => name.trim(),
-                None => return None,
-            };
-            Some((
-                node_name.to_string(),
-                Node {
-                    left: left_node.to_string(),
-                    right: right_node.to_string(),
-                },
-            ))
-        })
-        .collect();
-    let mut step_count: u64 = 0;
-    let mut current_node: String = String::from("AAA");
-    let mut direction_iter = steps.iter();
-    loop {
-        match direction_iter.next() {
-            Some(Step::Left) => {
-                current_node = nodes
-                    .get(&current_node)
-                    .expect(format!("{current_node} not in list").as_str())
-                    .left
-                    .clone();
-            }
-            Some(Step::Right) => {
-                current_node = nodes
-                    .get(&current_node)
-                    .expect(format!("{current_node} not in list").as_str())
-                    .right
-                    .clone();
-            }
-            None => {
-                direction_iter = steps.iter();
-                continue;
-            }
-        }
-        step_count += 1;
-        if current_node == String::from("ZZZ") {
-            break;
-        }
-    }
-    step_count.to_string()
-}
+pub struct Day08;
 
-pub fn process_part2(input: &str) -> String {
-    let mut lines_itr = input.lines();
-    let steps: Vec<Step> = lines_itr
-        .next()
-        .expect("should be a set of steps")
-        .chars()
-        .map(|i| {
-            i.try_into()
-                .expect(format!("{i} should be an R or L").as_str())
-        })
-        .collect();
-    let nodes: BTreeMap<String, Node> = lines_itr
-        .filter_map(|line| {
-            let node_name = match line.split(" = ").nth(0) {
-                Some(name) => name.trim(),
-                None => return None,
-            };
-            let left_node = match line.split(&['(', ','][..]).nth(1) {
-                Some(name) => name.trim(),
-                None => return None,
-            };
-            let right_node = match line.split(&[',', ')'][..]).nth(1) {
-                Some(name) => name.trim(),
-                None => return None,
-            };
-            Some((
-                node_name.to_string(),
-                Node {
-                    left: left_node.to_string(),
-                    right: right_node.to_string(),
-                },
-            ))
-        })
-        .collect();
-    let mut step_counts: Vec<u64> = Vec::default();
-    let mut current_nodes: Vec<String> = Vec::default();
-    for node_name in nodes.keys() {
-        if node_name.ends_with('A') {
-            current_nodes.push(node_name.clone())
-        }
+impl Solution for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+    type Parsed<'a> = &'a str;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
     }
-    for current_node in current_nodes.iter_mut() {
-        let mut step_count = 0;
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut lines_itr = input.lines();
+        let steps: Vec<Step> = lines_itr
+            .next()
+            .expect("should be a set of steps")
+            .chars()
+            .map(|i| {
+                i.try_into()
+                    .unwrap_or_else(|_| panic!("{i} should be an R or L"))
+            })
+            .collect();
+        let nodes: BTreeMap<String, Node> = lines_itr
+            .filter_map(|line| {
+                let node_name = match line.split(" = ").next() {
+                    Some(name) => name.trim(),
+                    None => return None,
+                };
+                let left_node = match line.split(&['(', ','][..]).nth(1) {
+                    Some(name) => name.trim(),
+                    None => return None,
+                };
+                let right_node = match line.split(&[',', ')'][..]).nth(1) {
+                    Some(name) => name.trim(),
+                    None => return None,
+                };
+                Some((
+                    node_name.to_string(),
+                    Node {
+                        left: left_node.to_string(),
+                        right: right_node.to_string(),
+                    },
+                ))
+            })
+            .collect();
+        let mut step_count: u64 = 0;
+        let mut current_node: String = String::from("AAA");
         let mut direction_iter = steps.iter();
         loop {
             match direction_iter.next() {
                 Some(Step::Left) => {
-                    *current_node = nodes
-                        .get(current_node)
-                        .expect(format!("{current_node} not in list").as_str())
+                    current_node = nodes
+                        .get(&current_node)
+                        .unwrap_or_else(|| panic!("{current_node} not in list"))
                         .left
                         .clone();
                 }
                 Some(Step::Right) => {
-                    *current_node = nodes
-                        .get(current_node)
-                        .expect(format!("{current_node} not in list").as_str())
+                    current_node = nodes
+                        .get(&current_node)
+                        .unwrap_or_else(|| panic!("{current_node} not in list"))
                         .right
                         .clone();
                 }
@@ -185,17 +126,99 @@ pub fn process_part2(input: &str) -> String {
                 }
             }
             step_count += 1;
-            if current_node.ends_with('Z') {
+            if current_node == "ZZZ" {
                 break;
             }
         }
-        step_counts.push(step_count)
+        step_count
     }
-    let mut res = 1;
-    for step_count in step_counts.iter() {
-        res = lcm(res, *step_count)
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut lines_itr = input.lines();
+        let steps: Vec<Step> = lines_itr
+            .next()
+            .expect("should be a set of steps")
+            .chars()
+            .map(|i| {
+                i.try_into()
+                    .unwrap_or_else(|_| panic!("{i} should be an R or L"))
+            })
+            .collect();
+        let nodes: BTreeMap<String, Node> = lines_itr
+            .filter_map(|line| {
+                let node_name = match line.split(" = ").next() {
+                    Some(name) => name.trim(),
+                    None => return None,
+                };
+                let left_node = match line.split(&['(', ','][..]).nth(1) {
+                    Some(name) => name.trim(),
+                    None => return None,
+                };
+                let right_node = match line.split(&[',', ')'][..]).nth(1) {
+                    Some(name) => name.trim(),
+                    None => return None,
+                };
+                Some((
+                    node_name.to_string(),
+                    Node {
+                        left: left_node.to_string(),
+                        right: right_node.to_string(),
+                    },
+                ))
+            })
+            .collect();
+        let mut step_counts: Vec<u64> = Vec::default();
+        let mut current_nodes: Vec<String> = Vec::default();
+        for node_name in nodes.keys() {
+            if node_name.ends_with('A') {
+                current_nodes.push(node_name.clone())
+            }
+        }
+        for current_node in current_nodes.iter_mut() {
+            let mut step_count = 0;
+            let mut direction_iter = steps.iter();
+            loop {
+                match direction_iter.next() {
+                    Some(Step::Left) => {
+                        *current_node = nodes
+                            .get(current_node)
+                            .unwrap_or_else(|| panic!("{current_node} not in list"))
+                            .left
+                            .clone();
+                    }
+                    Some(Step::Right) => {
+                        *current_node = nodes
+                            .get(current_node)
+                            .unwrap_or_else(|| panic!("{current_node} not in list"))
+                            .right
+                            .clone();
+                    }
+                    None => {
+                        direction_iter = steps.iter();
+                        continue;
+                    }
+                }
+                step_count += 1;
+                if current_node.ends_with('Z') {
+                    break;
+                }
+            }
+            step_counts.push(step_count)
+        }
+        let mut res = 1;
+        for step_count in step_counts.iter() {
+            res = lcm(res, *step_count)
+        }
+        res
     }
-    res.to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day08::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day08::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -208,8 +231,8 @@ mod tests {
         let file1 = fs::read_to_string("./test-input-1.txt").unwrap();
         let file2 = fs::read_to_string("./test-input-2.txt").unwrap();
         let file3 = fs::read_to_string("./test-input-3.txt").unwrap();
-        assert_eq!(process_part1(file1.as_str()), "2");
-        assert_eq!(process_part1(file2.as_str()), "6");
-        assert_eq!(process_part2(file3.as_str()), "6");
+        assert_eq!(process_part1(file1.as_str()).unwrap(), "2");
+        assert_eq!(process_part1(file2.as_str()).unwrap(), "6");
+        assert_eq!(process_part2(file3.as_str()).unwrap(), "6");
     }
 }
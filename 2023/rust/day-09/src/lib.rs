@@ -1,3 +1,5 @@
+use aoc_core::{Answer, AocError, Solution};
+
 fn all_zero(input: &Vec<i64>) -> bool {
     for num in input {
         if *num != 0 {
@@ -35,17 +37,16 @@ fn process_line_forwards(line: &str) -> i64 {
         if i == 0 {
             depths[i].push(0);
         } else {
-            let delta: i64 = depths[i - 1].last().expect("should not be empty").clone();
-            let new_val: i64 = delta + (depths[i].last().expect("should not be empty").clone());
+            let delta: i64 = *depths[i - 1].last().expect("should not be empty");
+            let new_val: i64 = delta + *depths[i].last().expect("should not be empty");
             depths[i].push(new_val);
         }
     }
-    depths
+    *depths
         .last()
         .expect("should not be empty")
         .last()
         .expect("should not be empty")
-        .clone()
 }
 
 fn process_line_backwards(line: &str) -> i64 {
@@ -58,33 +59,46 @@ fn process_line_backwards(line: &str) -> i64 {
         if i == 0 {
             depths[i].push(0);
         } else {
-            let delta: i64 = depths[i - 1].last().expect("should not be empty").clone();
-            let new_val: i64 = (depths[i].last().expect("should not be empty").clone()) - delta;
+            let delta: i64 = *depths[i - 1].last().expect("should not be empty");
+            let new_val: i64 = *depths[i].last().expect("should not be empty") - delta;
             depths[i].push(new_val);
         }
     }
-    depths
+    *depths
         .last()
         .expect("should not be empty")
         .last()
         .expect("should not be empty")
-        .clone()
 }
 
-pub fn process_part1(input: &str) -> String {
-    input
-        .lines()
-        .map(|line| process_line_forwards(line))
-        .sum::<i64>()
-        .to_string()
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+    type Parsed<'a> = &'a str;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        input.lines().map(process_line_forwards).sum::<i64>()
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        input.lines().map(process_line_backwards).sum::<i64>()
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day09::solve_part1(input)
 }
 
-pub fn process_part2(input: &str) -> String {
-    input
-        .lines()
-        .map(|line| process_line_backwards(line))
-        .sum::<i64>()
-        .to_string()
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day09::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -95,7 +109,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "114");
-        assert_eq!(process_part2(file.as_str()), "2");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "114");
+        assert_eq!(process_part2(file.as_str()).unwrap(), "2");
     }
 }
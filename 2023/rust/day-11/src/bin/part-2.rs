@@ -1,7 +1,6 @@
-use day_11::process_part2;
-use std::fs;
+use aoc_2023_day_11::process_part2;
 
 fn main() {
-    let file = fs::read_to_string("./input.txt").unwrap();
-    println!("{}", process_part2(&file, 1_000_000));
+    let file = aoc_core::input::from_args(env!("CARGO_MANIFEST_DIR"));
+    aoc_core::print_or_exit(process_part2(&file));
 }
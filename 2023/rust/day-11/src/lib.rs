@@ -1,3 +1,5 @@
+use aoc_core::{Answer, AocError, Solution};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Galaxy,
@@ -22,7 +24,7 @@ impl TryFrom<char> for Tile {
     }
 }
 
-fn find_empty_rows(input: &Vec<Vec<Tile>>) -> Vec<usize> {
+fn find_empty_rows(input: &[Vec<Tile>]) -> Vec<usize> {
     let mut res: Vec<usize> = Vec::default();
     let mut empty_row = true;
     for y in 0..input.len() {
@@ -42,7 +44,7 @@ fn find_empty_rows(input: &Vec<Vec<Tile>>) -> Vec<usize> {
     res
 }
 
-fn find_empty_columns(input: &Vec<Vec<Tile>>) -> Vec<usize> {
+fn find_empty_columns(input: &[Vec<Tile>]) -> Vec<usize> {
     let mut res: Vec<usize> = Vec::default();
     let mut empty_column = true;
     for x in 0..input[0].len() {
@@ -72,7 +74,7 @@ fn expand_rows(rows: Vec<usize>, grid: &mut Vec<Vec<Tile>>) {
     }
 }
 
-fn expand_columns(columns: Vec<usize>, grid: &mut Vec<Vec<Tile>>) {
+fn expand_columns(columns: Vec<usize>, grid: &mut [Vec<Tile>]) {
     for column in columns.iter().rev() {
         for i in 0..grid.len() {
             grid[i].insert(*column, Tile::Space)
@@ -80,7 +82,7 @@ fn expand_columns(columns: Vec<usize>, grid: &mut Vec<Vec<Tile>>) {
     }
 }
 
-fn find_galaxy_positions(grid: &Vec<Vec<Tile>>) -> Vec<Position> {
+fn find_galaxy_positions(grid: &[Vec<Tile>]) -> Vec<Position> {
     let mut list: Vec<Position> = Vec::default();
     for y in 0..grid.len() {
         for x in 0..grid[y].len() {
@@ -117,47 +119,11 @@ fn sum_all_pairs(input: Vec<Position>) -> usize {
     dist
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut grid: Vec<Vec<Tile>> = input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|char| char.try_into().expect("should be a valid tile"))
-                .collect::<Vec<Tile>>()
-        })
-        .collect::<Vec<Vec<Tile>>>();
-    /*println!("before expansion");
-    for row in grid.iter() {
-        for tile in row {
-            match tile {
-                Tile::Galaxy => print!("#"),
-                Tile::Space => print!("."),
-            }
-        }
-        print!("\n")
-    }*/
-    expand_rows(find_empty_rows(&grid), &mut grid);
-    expand_columns(find_empty_columns(&grid), &mut grid);
-    /*println!("after expansion");
-    for row in grid.iter() {
-        for tile in row {
-            match tile {
-                Tile::Galaxy => print!("#"),
-                Tile::Space => print!("."),
-            }
-        }
-        print!("\n")
-    }*/
-    let positions: Vec<Position> = find_galaxy_positions(&grid);
-    //println!("galaxy positions: {positions:?}");
-    sum_all_pairs(positions).to_string()
-}
-
 fn find_expanded_manhattan_distance(
     pos1: &Position,
     pos2: &Position,
-    expanded_columns: &Vec<usize>,
-    expanded_rows: &Vec<usize>,
+    expanded_columns: &[usize],
+    expanded_rows: &[usize],
     expansion_rate: usize,
 ) -> usize {
     let mut dist = 0;
@@ -195,9 +161,9 @@ fn find_expanded_manhattan_distance(
 }
 
 fn sum_all_pairs_with_expansion(
-    input: &Vec<Position>,
-    expanded_rows: &Vec<usize>,
-    expanded_columns: &Vec<usize>,
+    input: &[Position],
+    expanded_rows: &[usize],
+    expanded_columns: &[usize],
     expansion_rate: usize,
 ) -> usize {
     let mut dist = 0;
@@ -215,7 +181,73 @@ fn sum_all_pairs_with_expansion(
     dist
 }
 
-pub fn process_part2(input: &str, expansion_rate: usize) -> String {
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut grid: Vec<Vec<Tile>> = input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|char| char.try_into().expect("should be a valid tile"))
+                    .collect::<Vec<Tile>>()
+            })
+            .collect::<Vec<Vec<Tile>>>();
+        /*println!("before expansion");
+        for row in grid.iter() {
+            for tile in row {
+                match tile {
+                    Tile::Galaxy => print!("#"),
+                    Tile::Space => print!("."),
+                }
+            }
+            print!("\n")
+        }*/
+        expand_rows(find_empty_rows(&grid), &mut grid);
+        expand_columns(find_empty_columns(&grid), &mut grid);
+        /*println!("after expansion");
+        for row in grid.iter() {
+            for tile in row {
+                match tile {
+                    Tile::Galaxy => print!("#"),
+                    Tile::Space => print!("."),
+                }
+            }
+            print!("\n")
+        }*/
+        let positions: Vec<Position> = find_galaxy_positions(&grid);
+        //println!("galaxy positions: {positions:?}");
+        sum_all_pairs(positions)
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        sum_with_expansion_rate(input, 1_000_000)
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day11::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day11::solve_part2(input)
+}
+
+pub fn process_part2_with_rate(input: &str, expansion_rate: usize) -> String {
+    sum_with_expansion_rate(input, expansion_rate).to_string()
+}
+
+fn sum_with_expansion_rate(input: &str, expansion_rate: usize) -> usize {
     let grid: Vec<Vec<Tile>> = input
         .lines()
         .map(|line| {
@@ -233,7 +265,6 @@ pub fn process_part2(input: &str, expansion_rate: usize) -> String {
         &expanded_columns,
         expansion_rate,
     )
-    .to_string()
 }
 
 #[cfg(test)]
@@ -244,8 +275,8 @@ mod tests {
     #[test]
     fn test_input() {
         let file = fs::read_to_string("./test-input-1.txt").unwrap();
-        assert_eq!(process_part1(file.as_str()), "374");
-        assert_eq!(process_part2(file.as_str(), 10), "1030");
-        assert_eq!(process_part2(file.as_str(), 100), "8410");
+        assert_eq!(process_part1(file.as_str()).unwrap(), "374");
+        assert_eq!(process_part2_with_rate(file.as_str(), 10), "1030");
+        assert_eq!(process_part2_with_rate(file.as_str(), 100), "8410");
     }
 }
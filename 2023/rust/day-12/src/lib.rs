@@ -1,3 +1,4 @@
+use aoc_core::{Answer, AocError, Solution};
 use std::fmt::Display;
 
 use itertools::{repeat_n, Itertools};
@@ -33,9 +34,10 @@ impl TryFrom<char> for Tile {
     }
 }
 
+#[allow(dead_code)]
 fn possible_options(
-    group: &Vec<Tile>,
-    constraints: &Vec<u64>,
+    _group: &Vec<Tile>,
+    _constraints: &Vec<u64>,
     unknowns: u64,
 ) -> impl Iterator<Item = Vec<Tile>> {
     repeat_n(
@@ -45,15 +47,15 @@ fn possible_options(
     .multi_cartesian_product()
 }
 
-fn check_option(group: &Vec<Tile>, option: &Vec<Tile>, constraints: &Vec<u64>) -> bool {
+#[allow(dead_code)]
+fn check_option(group: &[Tile], option: &[Tile], constraints: &[u64]) -> bool {
     let mut option_iter = option.iter();
     let filled_group: Vec<Tile> = group
         .iter()
         .map(|tile| match tile {
-            Tile::Unknown => option_iter
+            Tile::Unknown => *option_iter
                 .next()
-                .expect("should have enough options for unknowns")
-                .clone(),
+                .expect("should have enough options for unknowns"),
             Tile::Operational => Tile::Operational,
             Tile::Damaged => Tile::Damaged,
         })
@@ -70,7 +72,7 @@ fn check_option(group: &Vec<Tile>, option: &Vec<Tile>, constraints: &Vec<u64>) -
 fn process_line(input: &str) -> u64 {
     let group: Vec<Tile> = input
         .split_ascii_whitespace()
-        .nth(0)
+        .next()
         .expect("must have layout")
         .chars()
         .map(|symbol| symbol.try_into().expect("must be valid symbol"))
@@ -96,11 +98,11 @@ fn process_line(input: &str) -> u64 {
     determine_variations(&group, &constraints)
 }
 
-fn determine_variations(tiles: &Vec<Tile>, constraints: &Vec<u64>) -> u64 {
-    let mut constraints = constraints.clone();
+fn determine_variations(tiles: &[Tile], constraints: &[u64]) -> u64 {
+    let mut constraints = constraints.to_vec();
     constraints.push(0);
     let max_run: usize = *constraints.iter().max().expect("there should be a max") as usize;
-    let mut tiles = tiles.clone();
+    let mut tiles = tiles.to_vec();
     tiles.push(Tile::Operational);
 
     let tile_count = tiles.len(); //n
@@ -109,8 +111,8 @@ fn determine_variations(tiles: &Vec<Tile>, constraints: &Vec<u64>) -> u64 {
         vec![vec![vec![None; max_run + 1usize]; constraint_count]; tile_count];
     for current_tile in 0..tile_count {
         let x = tiles[current_tile];
-        let mut combinations_if_current_operational: Option<u64> = None;
-        let mut combinations_if_current_is_damaged: Option<u64> = None;
+        let mut combinations_if_current_operational: Option<u64>;
+        let mut combinations_if_current_is_damaged: Option<u64>;
         for current_run in 0..constraint_count {
             for current_damaged_count in 0..((constraints[current_run] + 1) as usize) {
                 if current_tile == 0 {
@@ -169,10 +171,10 @@ fn determine_variations(tiles: &Vec<Tile>, constraints: &Vec<u64>) -> u64 {
                 } else {
                     // current tile > 0, run=0, damaged=0
                     // only possibility is every unknown is operational
-                    combinations_if_current_operational = if *(&tiles[0..current_tile]
+                    combinations_if_current_operational = if tiles[0..current_tile]
                         .iter()
                         .map(|val| if *val == Tile::Damaged { 1 } else { 0 })
-                        .sum::<u64>())
+                        .sum::<u64>()
                         == 0
                     {
                         Some(1)
@@ -209,13 +211,13 @@ fn determine_variations(tiles: &Vec<Tile>, constraints: &Vec<u64>) -> u64 {
     // dp[last tile][last run][no damaged]
     // we added an extra no damaged at the end so it's the total combinations
     let ans = dp[tile_count - 1][dp[tile_count - 1].len() - 1][0];
-    return ans.expect("this should have a value");
+    ans.expect("this should have a value")
 }
 
 fn process_line2(input: &str) -> u64 {
     let small_group: Vec<Tile> = input
         .split_ascii_whitespace()
-        .nth(0)
+        .next()
         .expect("must have layout")
         .chars()
         .map(|symbol| symbol.try_into().expect("must be valid symbol"))
@@ -248,31 +250,45 @@ fn process_line2(input: &str) -> u64 {
         }
     }
     let mut constraint_iter = constraints.iter();
-    let mut constraints_string = String::from(format!(
+    let mut constraints_string = format!(
         "{}",
         constraint_iter.next().expect("there should be a value")
-    ));
+    );
     for val in constraint_iter {
         constraints_string = format!("{constraints_string}, {val}");
     }
-    let res = determine_variations(&group, &constraints);
-    res
+
+    determine_variations(&group, &constraints)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+    type Parsed<'a> = &'a str;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        input.lines().map(process_line).sum::<u64>()
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        input.lines().map(process_line2).sum::<u64>()
+    }
 }
 
-pub fn process_part1(input: &str) -> String {
-    input
-        .lines()
-        .map(|line| process_line(line))
-        .sum::<u64>()
-        .to_string()
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day12::solve_part1(input)
 }
 
-pub fn process_part2(input: &str) -> String {
-    input
-        .lines()
-        .map(|line| process_line2(line))
-        .sum::<u64>()
-        .to_string()
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day12::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -282,55 +298,55 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "21");
-        assert_eq!(process_part2(file), "525152");
+        assert_eq!(process_part1(file).unwrap(), "21");
+        assert_eq!(process_part2(file).unwrap(), "525152");
     }
     #[test]
     fn test_1() {
-        assert_eq!(process_part1("???.### 1,1,3"), "1")
+        assert_eq!(process_part1("???.### 1,1,3").unwrap(), "1")
     }
     #[test]
     fn test_2() {
-        assert_eq!(process_part1(".??..??...?##. 1,1,3"), "4")
+        assert_eq!(process_part1(".??..??...?##. 1,1,3").unwrap(), "4")
     }
     #[test]
     fn test_3() {
-        assert_eq!(process_part1("?#?#?#?#?#?#?#? 1,3,1,6"), "1")
+        assert_eq!(process_part1("?#?#?#?#?#?#?#? 1,3,1,6").unwrap(), "1")
     }
     #[test]
     fn test_4() {
-        assert_eq!(process_part1("????.#...#... 4,1,1"), "1")
+        assert_eq!(process_part1("????.#...#... 4,1,1").unwrap(), "1")
     }
     #[test]
     fn test_5() {
-        assert_eq!(process_part1("????.######..#####. 1,6,5"), "4")
+        assert_eq!(process_part1("????.######..#####. 1,6,5").unwrap(), "4")
     }
     #[test]
     fn test_6() {
-        assert_eq!(process_part1("?###???????? 3,2,1"), "10")
+        assert_eq!(process_part1("?###???????? 3,2,1").unwrap(), "10")
     }
     #[test]
     fn test_7() {
-        assert_eq!(process_part2("???.### 1,1,3"), "1")
+        assert_eq!(process_part2("???.### 1,1,3").unwrap(), "1")
     }
     #[test]
     fn test_8() {
-        assert_eq!(process_part2(".??..??...?##. 1,1,3"), "16384")
+        assert_eq!(process_part2(".??..??...?##. 1,1,3").unwrap(), "16384")
     }
     #[test]
     fn test_9() {
-        assert_eq!(process_part2("?#?#?#?#?#?#?#? 1,3,1,6"), "1")
+        assert_eq!(process_part2("?#?#?#?#?#?#?#? 1,3,1,6").unwrap(), "1")
     }
     #[test]
     fn test_10() {
-        assert_eq!(process_part2("????.#...#... 4,1,1"), "16")
+        assert_eq!(process_part2("????.#...#... 4,1,1").unwrap(), "16")
     }
     #[test]
     fn test_11() {
-        assert_eq!(process_part2("????.######..#####. 1,6,5"), "2500")
+        assert_eq!(process_part2("????.######..#####. 1,6,5").unwrap(), "2500")
     }
     #[test]
     fn test_12() {
-        assert_eq!(process_part2("?###???????? 3,2,1"), "506250")
+        assert_eq!(process_part2("?###???????? 3,2,1").unwrap(), "506250")
     }
 }
@@ -1,6 +0,0 @@
-use day_00::process_part2;
-
-fn main() {
-    let file = include_str!("../../input.txt");
-    println!("{}", process_part2(file));
-}
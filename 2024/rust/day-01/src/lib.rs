@@ -1,3 +1,4 @@
+use aoc_core::{Answer, AocError, Solution};
 use std::collections::HashMap;
 use std::iter::zip;
 
@@ -19,35 +20,57 @@ fn split_numbers_with_count(line: &str, counts: &mut HashMap<i32, i32>) -> (i32,
     (res1, res2)
 }
 
-pub fn process_part1(input: &str) -> String {
-    let (mut list1, mut list2) = input
-        .lines()
-        .map(split_numbers)
-        .collect::<(Vec<i32>, Vec<i32>)>();
-    list1.sort();
-    list2.sort();
-    if list1.len() != list2.len() {
-        panic!("list sizes do not match, check input")
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+    type Parsed<'a> = &'a str;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
     }
-    let mut total = 0;
-    for (l1, l2) in zip(list1, list2) {
-        total += (l1 - l2).abs()
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let (mut list1, mut list2) = input
+            .lines()
+            .map(split_numbers)
+            .collect::<(Vec<i32>, Vec<i32>)>();
+        list1.sort();
+        list2.sort();
+        if list1.len() != list2.len() {
+            panic!("list sizes do not match, check input")
+        }
+        let mut total = 0;
+        for (l1, l2) in zip(list1, list2) {
+            total += (l1 - l2).abs()
+        }
+        total
     }
-    format!("{total}")
-}
 
-pub fn process_part2(input: &str) -> String {
-    let mut counts: HashMap<i32, i32> = HashMap::new();
-    let (list1, mut list2) = input
-        .lines()
-        .map(|line| split_numbers_with_count(line, &mut counts))
-        .collect::<(Vec<i32>, Vec<i32>)>();
-    list2.sort();
-    let mut total = 0;
-    for l1 in list1 {
-        total += l1 * counts.get(&l1).unwrap_or(&0);
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut counts: HashMap<i32, i32> = HashMap::new();
+        let (list1, mut list2) = input
+            .lines()
+            .map(|line| split_numbers_with_count(line, &mut counts))
+            .collect::<(Vec<i32>, Vec<i32>)>();
+        list2.sort();
+        let mut total = 0;
+        for l1 in list1 {
+            total += l1 * counts.get(&l1).unwrap_or(&0);
+        }
+        total
     }
-    format!("{total}")
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -63,7 +86,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "11");
-        assert_eq!(process_part2(file), "31");
+        assert_eq!(process_part1(file).unwrap(), "11");
+        assert_eq!(process_part2(file).unwrap(), "31");
     }
 }
@@ -1,3 +1,5 @@
+use aoc_core::{Answer, AocError, Solution};
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 enum LevelKind {
     Safe,
@@ -11,7 +13,7 @@ enum Direction {
     Neither,
 }
 
-fn process_levels(levels: &Vec<i32>) -> LevelKind {
+fn process_levels(levels: &[i32]) -> LevelKind {
     if levels.len() < 2 {
         panic!("incorrectly formatted input")
     }
@@ -56,27 +58,12 @@ fn process_levels(levels: &Vec<i32>) -> LevelKind {
     LevelKind::Safe
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut safe_reports: i32 = 0;
-    input.lines().for_each(|line| {
-        let levels: Vec<i32> = line
-            .split_whitespace()
-            .flat_map(|x| x.parse::<i32>())
-            .collect();
-        match process_levels(&levels) {
-            LevelKind::NotSafe => (),
-            LevelKind::Safe => safe_reports += 1,
-        }
-    });
-    format!("{safe_reports}")
-}
-
-fn process_updated_levels(levels: &Vec<i32>) -> LevelKind {
+fn process_updated_levels(levels: &[i32]) -> LevelKind {
     if process_levels(levels) == LevelKind::Safe {
         return LevelKind::Safe;
     }
     for i in 0..levels.len() {
-        let mut new_levels = levels.clone();
+        let mut new_levels = levels.to_vec();
         new_levels.remove(i);
         if process_levels(&new_levels) == LevelKind::Safe {
             return LevelKind::Safe;
@@ -85,19 +72,56 @@ fn process_updated_levels(levels: &Vec<i32>) -> LevelKind {
     LevelKind::NotSafe
 }
 
-pub fn process_part2(input: &str) -> String {
-    let mut safe_reports: i32 = 0;
-    input.lines().for_each(|line| {
-        let levels: Vec<i32> = line
-            .split_whitespace()
-            .flat_map(|x| x.parse::<i32>())
-            .collect();
-        match process_updated_levels(&levels) {
-            LevelKind::NotSafe => (),
-            LevelKind::Safe => safe_reports += 1,
-        }
-    });
-    format!("{safe_reports}")
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+    type Parsed<'a> = &'a str;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut safe_reports: i32 = 0;
+        input.lines().for_each(|line| {
+            let levels: Vec<i32> = line
+                .split_whitespace()
+                .flat_map(|x| x.parse::<i32>())
+                .collect();
+            match process_levels(&levels) {
+                LevelKind::NotSafe => (),
+                LevelKind::Safe => safe_reports += 1,
+            }
+        });
+        safe_reports
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut safe_reports: i32 = 0;
+        input.lines().for_each(|line| {
+            let levels: Vec<i32> = line
+                .split_whitespace()
+                .flat_map(|x| x.parse::<i32>())
+                .collect();
+            match process_updated_levels(&levels) {
+                LevelKind::NotSafe => (),
+                LevelKind::Safe => safe_reports += 1,
+            }
+        });
+        safe_reports
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day02::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day02::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -107,7 +131,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "2");
-        assert_eq!(process_part2(file), "4");
+        assert_eq!(process_part1(file).unwrap(), "2");
+        assert_eq!(process_part2(file).unwrap(), "4");
     }
 }
@@ -1,3 +1,4 @@
+use aoc_core::{Answer, AocError, Solution};
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -29,18 +30,6 @@ fn parse(input: &str) -> IResult<&str, Vec<Instruction>> {
     many1(many_till(anychar, parse_mult_pair).map(|(_discard, ins)| ins))(input)
 }
 
-pub fn process_part1(input: &str) -> String {
-    let (_, pairs) = parse(input).expect("parse should succeed");
-    pairs
-        .iter()
-        .map(|instruction| match instruction {
-            Instruction::Mul(x, y) => x * y,
-            _ => panic!("shouldn't get this instruction type in part1"),
-        })
-        .sum::<i32>()
-        .to_string()
-}
-
 fn parse_conditional_mult(input: &str) -> IResult<&str, Instruction> {
     alt((
         value(Instruction::Disable, tag("don't()")),
@@ -53,25 +42,57 @@ fn conditional_parse(input: &str) -> IResult<&str, Vec<Instruction>> {
     many1(many_till(anychar, parse_conditional_mult).map(|(_discard, ins)| ins))(input)
 }
 
-pub fn process_part2(input: &str) -> String {
-    let mut enabled = true;
-    let (_, pairs) = conditional_parse(input).expect("parse should succeed");
-    pairs
-        .iter()
-        .map(|instruction| match (instruction, enabled) {
-            (Instruction::Mul(x, y), true) => x * y,
-            (Instruction::Mul(_, _), false) => 0,
-            (Instruction::Enable, _) => {
-                enabled = true;
-                0
-            }
-            (Instruction::Disable, _) => {
-                enabled = false;
-                0
-            }
-        })
-        .sum::<i32>()
-        .to_string()
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+    type Parsed<'a> = &'a str;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let (_, pairs) = parse(input).expect("parse should succeed");
+        pairs
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::Mul(x, y) => x * y,
+                _ => panic!("shouldn't get this instruction type in part1"),
+            })
+            .sum::<i32>()
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut enabled = true;
+        let (_, pairs) = conditional_parse(input).expect("parse should succeed");
+        pairs
+            .iter()
+            .map(|instruction| match (instruction, enabled) {
+                (Instruction::Mul(x, y), true) => x * y,
+                (Instruction::Mul(_, _), false) => 0,
+                (Instruction::Enable, _) => {
+                    enabled = true;
+                    0
+                }
+                (Instruction::Disable, _) => {
+                    enabled = false;
+                    0
+                }
+            })
+            .sum::<i32>()
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day03::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day03::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -81,8 +102,8 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "161");
+        assert_eq!(process_part1(file).unwrap(), "161");
         let file = include_str!("../test-input-2.txt");
-        assert_eq!(process_part2(file), "48");
+        assert_eq!(process_part2(file).unwrap(), "48");
     }
 }
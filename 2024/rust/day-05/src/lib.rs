@@ -1,9 +1,9 @@
+use aoc_core::{Answer, AocError, Solution};
+use aoc_parse::{comma_list, int, lines, parse_all};
 use nom::{
-    bytes::complete::tag,
-    character::complete::{self, anychar, line_ending},
-    multi::{many1, many_till, separated_list1},
-    sequence::{delimited, preceded, separated_pair, terminated},
-    IResult, Parser,
+    character::complete::{char, line_ending},
+    sequence::{pair, separated_pair},
+    IResult,
 };
 use std::{collections::HashMap, hash::Hash};
 
@@ -21,21 +21,16 @@ struct PairRules {
 
 type Updates = Vec<Vec<i32>>;
 
-fn parse_page_pair(input: &str) -> IResult<&str, (i32, i32)> {
-    separated_pair(complete::i32, tag("|"), complete::i32)(input)
+fn parse_page_pair(input: &str) -> IResult<&str, Pair> {
+    let (input, (first, second)) = separated_pair(int, char('|'), int)(input)?;
+    Ok((input, Pair { first, second }))
 }
 
-fn parse_rules(input: &str) -> IResult<&str, PairRules> {
+fn build_rules(pairs: Vec<Pair>) -> PairRules {
     let mut rules = PairRules {
         find_second: HashMap::new(),
         find_first: HashMap::new(),
     };
-    let (input, pairs): (_, Vec<Pair>) =
-        many1(many_till(anychar, parse_page_pair).map(|(_, (a, b))| Pair {
-            first: a,
-            second: b,
-        }))(input)
-        .expect("parse should succeed");
     for pair in pairs {
         match rules.find_first.get(&pair.second) {
             Some(vec) => {
@@ -58,40 +53,36 @@ fn parse_rules(input: &str) -> IResult<&str, PairRules> {
             }
         }
     }
-    Ok((input, rules))
+    rules
 }
 
-fn parse_updates(input: &str) -> IResult<&str, Updates> {
-    separated_list1(line_ending, separated_list1(tag(","), complete::i32))(input)
-}
-
-fn parse(input: &str) -> (PairRules, Updates) {
-    let (input, rules) = terminated(parse_rules, line_ending)(input).expect("parse should succeed");
-    let (_, updates) = preceded(line_ending, parse_updates)(input).expect("parse should succeed");
-    (rules, updates)
+fn parse(input: &str) -> Result<(PairRules, Updates), AocError> {
+    let (pairs, updates) = parse_all(
+        input,
+        separated_pair(
+            lines(parse_page_pair),
+            pair(line_ending, line_ending),
+            lines(comma_list(int)),
+        ),
+    )?;
+    Ok((build_rules(pairs), updates))
 }
 
 fn is_before(left: i32, right: i32, rules: &PairRules) -> bool {
-    match rules.find_second.get(&left) {
-        Some(vec) => {
-            if vec.contains(&right) {
-                return true;
-            }
+    if let Some(vec) = rules.find_second.get(&left) {
+        if vec.contains(&right) {
+            return true;
         }
-        None => {}
     }
-    match rules.find_first.get(&right) {
-        Some(vec) => {
-            if vec.contains(&left) {
-                return true;
-            }
+    if let Some(vec) = rules.find_first.get(&right) {
+        if vec.contains(&left) {
+            return true;
         }
-        None => {}
     }
-    return false;
+    false
 }
 
-fn check_rule(update: &Vec<i32>, rules: &PairRules) -> bool {
+fn check_rule(update: &[i32], rules: &PairRules) -> bool {
     for pair in update.windows(2) {
         if !is_before(pair[0], pair[1], rules) {
             return false;
@@ -100,24 +91,8 @@ fn check_rule(update: &Vec<i32>, rules: &PairRules) -> bool {
     true
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut middle_page_sum = 0;
-    let (rules, updates) = parse(input);
-    for update in updates {
-        if check_rule(&update, &rules) {
-            if update.len() % 2 != 1 {
-                panic!("update lengths need to be odd")
-            } else {
-                middle_page_sum += update[update.len() / 2]
-            }
-        } else {
-        }
-    }
-    middle_page_sum.to_string()
-}
-
-fn get_middle_of_correctly_sorted(update: &Vec<i32>, rules: &PairRules) -> i32 {
-    let mut update = update.clone();
+fn get_middle_of_correctly_sorted(update: &[i32], rules: &PairRules) -> i32 {
+    let mut update = update.to_vec();
     let mut swap_counter = 0;
     let swap_limit = update.len() * update.len();
     while (!check_rule(&update, rules)) && (swap_counter < swap_limit) {
@@ -125,13 +100,12 @@ fn get_middle_of_correctly_sorted(update: &Vec<i32>, rules: &PairRules) -> i32 {
             if !is_before(update[i - 1], update[i], rules) {
                 update.swap(i - 1, i);
                 break;
-            } else {
             }
         }
         swap_counter += 1;
     }
     if swap_counter < swap_limit {
-        return update[update.len() / 2];
+        update[update.len() / 2]
     } else {
         dbg!(update);
         dbg!(swap_counter);
@@ -139,20 +113,56 @@ fn get_middle_of_correctly_sorted(update: &Vec<i32>, rules: &PairRules) -> i32 {
     }
 }
 
-pub fn process_part2(input: &str) -> String {
-    let mut middle_page_sum = 0;
-    let (rules, updates) = parse(input);
-    for update in updates {
-        if !check_rule(&update, &rules) {
-            if update.len() % 2 != 1 {
-                panic!("update lists need to be odd lengths")
-            } else {
-                middle_page_sum += get_middle_of_correctly_sorted(&update, &rules)
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+    type Parsed<'a> = &'a str;
+    type Part1 = Result<i32, AocError>;
+    type Part2 = Result<i32, AocError>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut middle_page_sum = 0;
+        let (rules, updates) = parse(input)?;
+        for update in updates {
+            if check_rule(&update, &rules) {
+                if update.len() % 2 != 1 {
+                    return Err(AocError::unsupported("update lengths need to be odd"));
+                } else {
+                    middle_page_sum += update[update.len() / 2]
+                }
+            }
+        }
+        Ok(middle_page_sum)
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut middle_page_sum = 0;
+        let (rules, updates) = parse(input)?;
+        for update in updates {
+            if !check_rule(&update, &rules) {
+                if update.len() % 2 != 1 {
+                    return Err(AocError::unsupported("update lists need to be odd lengths"));
+                } else {
+                    middle_page_sum += get_middle_of_correctly_sorted(&update, &rules)
+                }
             }
-        } else {
         }
+        Ok(middle_page_sum)
     }
-    middle_page_sum.to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day05::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day05::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -162,7 +172,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "143");
-        assert_eq!(process_part2(file), "123");
+        assert_eq!(process_part1(file).unwrap(), "143");
+        assert_eq!(process_part2(file).unwrap(), "123");
     }
 }
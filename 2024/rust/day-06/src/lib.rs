@@ -1,3 +1,10 @@
+use aoc_core::{Answer, AocError, Solution};
+use aoc_grid::{Coord, Direction, Grid};
+use aoc_parse::{grid, parse_all};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Clear,
@@ -5,6 +12,19 @@ enum Tile {
     Guard,
 }
 
+impl TryFrom<char> for Tile {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Tile::Clear),
+            '#' => Ok(Tile::Obstructed),
+            '^' => Ok(Tile::Guard),
+            _ => Err(c),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct TileData {
     tile_type: Tile,
@@ -12,23 +32,9 @@ struct TileData {
     facing_direciton_when_visited: Vec<Direction>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    North,
-    East,
-    South,
-    West,
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Coord {
-    x: usize,
-    y: usize,
-}
-
 #[derive(Debug, Clone)]
 struct MapData {
-    map: Vec<Vec<TileData>>,
+    map: Grid<TileData>,
     guard_facing: Direction,
     guard_location: Coord,
     visited_total: usize,
@@ -46,7 +52,7 @@ fn print_map(map: &MapData) {
         "\n\nguard is at {},{}, facing: {:?} and has visited {} tiles\n",
         map.guard_location.x, map.guard_location.y, map.guard_facing, map.visited_total
     );
-    for row in &map.map {
+    for row in map.map.rows() {
         for tile in row {
             match (tile.tile_type, tile.visited) {
                 (Tile::Clear, true) => {
@@ -69,142 +75,74 @@ fn print_map(map: &MapData) {
                 }
             }
         }
-        print!("\n");
+        println!();
     }
 }
 
-fn parse(input: &str) -> MapData {
-    let mut guard_facing: Direction = Direction::North;
-    let mut guard_location: Coord = Coord { x: 0, y: 0 };
-    let mut map: Vec<Vec<TileData>> = Vec::new();
-    for (y, row) in input.lines().enumerate() {
-        let mut map_row: Vec<TileData> = Vec::new();
-        for (x, char) in row.chars().enumerate() {
-            match char {
-                '.' => {
-                    map_row.push(TileData {
-                        tile_type: Tile::Clear,
-                        visited: false,
-                        facing_direciton_when_visited: vec![],
-                    });
-                }
-                '#' => {
-                    map_row.push(TileData {
-                        tile_type: Tile::Obstructed,
-                        visited: false,
-                        facing_direciton_when_visited: vec![],
-                    });
-                }
-                '^' => {
-                    map_row.push(TileData {
-                        tile_type: Tile::Guard,
-                        visited: true,
-                        facing_direciton_when_visited: vec![Direction::North],
-                    });
-                    guard_facing = Direction::North;
-                    guard_location = Coord { x, y };
-                }
-                _ => {
-                    panic!("invalid character in parse");
-                }
-            }
-        }
-        map.push(map_row);
-    }
-    MapData {
+fn parse(input: &str) -> Result<MapData, AocError> {
+    let tiles: Grid<Tile> = parse_all(input, grid)?;
+    let guard_location = tiles
+        .position(|tile| *tile == Tile::Guard)
+        .ok_or_else(|| AocError::unsupported("there should be a guard"))?;
+    let map = tiles.map(|&tile_type| TileData {
+        tile_type,
+        visited: tile_type == Tile::Guard,
+        facing_direciton_when_visited: if tile_type == Tile::Guard {
+            vec![Direction::North]
+        } else {
+            vec![]
+        },
+    });
+    Ok(MapData {
         map,
-        guard_facing,
+        guard_facing: Direction::North,
         visited_total: 1usize,
         guard_location,
-    }
+    })
 }
 
 fn iterate_guard_once(map: &mut MapData) -> EndType {
-    let next_peek_coord = match map.guard_facing {
-        Direction::North => {
-            if map.guard_location.y == 0 {
-                return EndType::LeftMap;
-            } else {
-                Coord {
-                    x: map.guard_location.x,
-                    y: map.guard_location.y - 1,
-                }
-            }
-        }
-        Direction::East => {
-            if map.guard_location.x == map.map[0].len() - 1 {
-                return EndType::LeftMap;
-            } else {
-                Coord {
-                    x: map.guard_location.x + 1,
-                    y: map.guard_location.y,
-                }
-            }
-        }
-        Direction::South => {
-            if map.guard_location.y == map.map.len() - 1 {
-                return EndType::LeftMap;
-            } else {
-                Coord {
-                    x: map.guard_location.x,
-                    y: map.guard_location.y + 1,
-                }
-            }
-        }
-        Direction::West => {
-            if map.guard_location.x == 0 {
-                return EndType::LeftMap;
-            } else {
-                Coord {
-                    x: map.guard_location.x - 1,
-                    y: map.guard_location.y,
-                }
-            }
-        }
+    let Some(next_peek_coord) = map.map.step(map.guard_location, map.guard_facing) else {
+        return EndType::LeftMap;
     };
-    match map.map[next_peek_coord.y][next_peek_coord.x].tile_type {
+    match map.map[next_peek_coord].tile_type {
         Tile::Clear => {
             // first check for loop
-            if map.map[next_peek_coord.y][next_peek_coord.x]
+            if map.map[next_peek_coord]
                 .facing_direciton_when_visited
                 .contains(&map.guard_facing)
             {
-                return EndType::LoopFound;
+                EndType::LoopFound
             } else {
                 // remove guard from previous tile
-                map.map[map.guard_location.y][map.guard_location.x].tile_type = Tile::Clear;
+                map.map[map.guard_location].tile_type = Tile::Clear;
                 // move to this tile
                 map.guard_location = next_peek_coord;
-                map.map[next_peek_coord.y][next_peek_coord.x].tile_type = Tile::Guard;
+                map.map[next_peek_coord].tile_type = Tile::Guard;
                 // mark visited
-                if !map.map[next_peek_coord.y][next_peek_coord.x].visited {
-                    map.map[next_peek_coord.y][next_peek_coord.x].visited = true;
+                if !map.map[next_peek_coord].visited {
+                    map.map[next_peek_coord].visited = true;
                     map.visited_total += 1;
                 }
                 // mark facing direction for this tile
-                map.map[next_peek_coord.y][next_peek_coord.x]
+                map.map[next_peek_coord]
                     .facing_direciton_when_visited
                     .push(map.guard_facing);
-                return EndType::StepComplete;
+                EndType::StepComplete
             }
         }
         Tile::Obstructed => {
-            map.guard_facing = match map.guard_facing {
-                Direction::North => Direction::East,
-                Direction::East => Direction::South,
-                Direction::South => Direction::West,
-                Direction::West => Direction::North,
-            };
-            if map.map[map.guard_location.y][map.guard_location.x]
+            map.guard_facing = map.guard_facing.turn_right();
+            if map.map[map.guard_location]
                 .facing_direciton_when_visited
                 .contains(&map.guard_facing)
             {
-                return EndType::LoopFound;
+                EndType::LoopFound
             } else {
-                map.map[map.guard_location.y][map.guard_location.x]
+                map.map[map.guard_location]
                     .facing_direciton_when_visited
                     .push(map.guard_facing);
-                return EndType::StepComplete;
+                EndType::StepComplete
             }
         }
         Tile::Guard => {
@@ -214,77 +152,84 @@ fn iterate_guard_once(map: &mut MapData) -> EndType {
     }
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut map = parse(input);
-    //print_map(&map);
-    let mut check: EndType = iterate_guard_once(&mut map);
+/// Whether the guard ends up walking in a loop once `coord` is obstructed.
+fn obstruction_loops(map: &MapData, coord: Coord) -> bool {
+    let mut trial_map = map.clone();
+    trial_map.map[coord].tile_type = Tile::Obstructed;
+    let mut check: EndType = iterate_guard_once(&mut trial_map);
     while check == EndType::StepComplete {
-        check = iterate_guard_once(&mut map);
-    }
-    match check {
-        EndType::LeftMap => {
-            //print_map(&map);
-            return map.visited_total.to_string();
-        }
-        EndType::LoopFound => {
-            //print_map(&map);
-            panic!("we found a loop")
-        }
-        EndType::StepComplete => {
-            //print_map(&map);
-            panic!("shouldn't get here")
-        }
+        check = iterate_guard_once(&mut trial_map);
     }
+    check == EndType::LoopFound
 }
 
-pub fn process_part2(input: &str) -> String {
-    let map = parse(input);
-    //print_map(&map);
-    let mut visit_map = map.clone();
-    let mut check: EndType = iterate_guard_once(&mut visit_map);
-    while check == EndType::StepComplete {
-        check = iterate_guard_once(&mut visit_map);
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+    type Parsed<'a> = &'a str;
+    type Part1 = Result<usize, AocError>;
+    type Part2 = Result<usize, AocError>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
     }
-    match check {
-        EndType::LeftMap => {}
-        EndType::LoopFound => {
-            //print_map(&map);
-            panic!("we found a loop")
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut map = parse(input)?;
+        //print_map(&map);
+        let mut check: EndType = iterate_guard_once(&mut map);
+        while check == EndType::StepComplete {
+            check = iterate_guard_once(&mut map);
         }
-        EndType::StepComplete => {
-            //print_map(&map);
-            panic!("shouldn't get here")
+        //print_map(&map);
+        if check != EndType::LeftMap {
+            return Err(AocError::solver(
+                "the guard walks in a loop and never leaves",
+            ));
         }
+        Ok(map.visited_total)
     }
 
-    let mut loops_created = 0;
-    for y in 0..map.map.len() {
-        for x in 0..map.map[y].len() {
-            if !visit_map.map[y][x].visited {
-                continue;
-            }
-            let mut trial_map = map.clone();
-            match trial_map.map[y][x].tile_type {
-                Tile::Clear => trial_map.map[y][x].tile_type = Tile::Obstructed,
-                Tile::Obstructed => continue,
-                Tile::Guard => continue,
-            }
-            let mut check: EndType = iterate_guard_once(&mut trial_map);
-            while check == EndType::StepComplete {
-                check = iterate_guard_once(&mut trial_map);
-            }
-            match check {
-                EndType::LeftMap => {}
-                EndType::LoopFound => {
-                    loops_created += 1;
-                }
-                EndType::StepComplete => {
-                    panic!("shouldn't get here")
-                }
-            }
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let map = parse(input)?;
+        //print_map(&map);
+        let mut visit_map = map.clone();
+        let mut check: EndType = iterate_guard_once(&mut visit_map);
+        while check == EndType::StepComplete {
+            check = iterate_guard_once(&mut visit_map);
         }
+        if check != EndType::LeftMap {
+            //print_map(&map);
+            return Err(AocError::solver(
+                "the guard walks in a loop and never leaves",
+            ));
+        }
+
+        let candidates: Vec<Coord> = map
+            .map
+            .coords()
+            .filter(|&coord| {
+                visit_map.map[coord].visited && map.map[coord].tile_type == Tile::Clear
+            })
+            .collect();
+        #[cfg(feature = "parallel")]
+        let candidates = candidates.into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let candidates = candidates.into_iter();
+        Ok(candidates
+            .filter(|&coord| obstruction_loops(&map, coord))
+            .count())
     }
-    loops_created.to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day06::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day06::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -294,7 +239,16 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "41");
-        assert_eq!(process_part2(file), "6");
+        assert_eq!(process_part1(file).unwrap(), "41");
+        assert_eq!(process_part2(file).unwrap(), "6");
+        assert_eq!(
+            process_part1("..\n..\n").unwrap_err(),
+            AocError::unsupported("there should be a guard")
+        );
+        assert_eq!(
+            process_part1(".#.\n#^#\n.#.\n").unwrap_err(),
+            AocError::solver("the guard walks in a loop and never leaves")
+        );
+        assert!(process_part2(".^\n.x\n").is_err());
     }
 }
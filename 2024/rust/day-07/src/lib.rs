@@ -1,3 +1,8 @@
+use aoc_core::{Answer, AocError, Solution};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 #[derive(Debug, Clone)]
 struct LineData {
     target: i64,
@@ -17,6 +22,7 @@ struct LineOperator {
 }
 
 impl LineOperator {
+    #[allow(dead_code)]
     fn new(length: usize) -> LineOperator {
         LineOperator {
             state: vec![Operators::Add; length - 1],
@@ -145,14 +151,6 @@ fn solve_line_data(input: LineData) -> i64 {
     }
 }
 
-pub fn process_part1(input: &str) -> String {
-    input
-        .lines()
-        .map(|l| -> i64 { solve_line_data(parse_line(l)) })
-        .sum::<i64>()
-        .to_string()
-}
-
 fn solve_line_data2(input: LineData) -> i64 {
     let mut line_operator: LineOperator = LineOperator::from(&input);
     let mut iterations: usize = 0;
@@ -182,12 +180,43 @@ fn solve_line_data2(input: LineData) -> i64 {
     }
 }
 
-pub fn process_part2(input: &str) -> String {
-    input
-        .lines()
-        .map(|l| -> i64 { solve_line_data2(parse_line(l)) })
-        .sum::<i64>()
-        .to_string()
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 7;
+    type Parsed<'a> = &'a str;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        input
+            .lines()
+            .map(|l| -> i64 { solve_line_data(parse_line(l)) })
+            .sum::<i64>()
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        #[cfg(feature = "parallel")]
+        let lines = input.par_lines();
+        #[cfg(not(feature = "parallel"))]
+        let lines = input.lines();
+        lines
+            .map(|l| -> i64 { solve_line_data2(parse_line(l)) })
+            .sum::<i64>()
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day07::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day07::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -197,7 +226,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "3749");
-        assert_eq!(process_part2(file), "11387");
+        assert_eq!(process_part1(file).unwrap(), "3749");
+        assert_eq!(process_part2(file).unwrap(), "11387");
     }
 }
@@ -1,3 +1,4 @@
+use aoc_core::{Answer, AocError, Solution};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -143,6 +144,7 @@ fn generate_candidates(
     }
 }
 
+#[allow(dead_code)]
 fn print_map(height: i64, width: i64, input: &Vec<Coord>) {
     println!("map:");
     for y in 0..height {
@@ -163,42 +165,64 @@ fn print_map(height: i64, width: i64, input: &Vec<Coord>) {
     }
 }
 
-pub fn process_part1(input: &str) -> String {
-    let data = parse(input);
-    let pairs = generate_coord_pairs(&data);
-    //println!("{pairs:?}, length: {}", pairs.len());
-    let mut total = Vec::new();
-    for pair in pairs {
-        let new_values = generate_candidates(data.width, data.height, pair, true);
-        for value in new_values {
-            if total.contains(&value) {
-                continue;
-            } else {
-                total.push(value);
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let data = parse(input);
+        let pairs = generate_coord_pairs(&data);
+        //println!("{pairs:?}, length: {}", pairs.len());
+        let mut total = Vec::new();
+        for pair in pairs {
+            let new_values = generate_candidates(data.width, data.height, pair, true);
+            for value in new_values {
+                if total.contains(&value) {
+                    continue;
+                } else {
+                    total.push(value);
+                }
             }
         }
+        //print_map(data.height, data.width, &total);
+        total.len()
     }
-    //print_map(data.height, data.width, &total);
-    total.len().to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let data = parse(input);
-    let pairs = generate_coord_pairs(&data);
-    //println!("{pairs:?}, length: {}", pairs.len());
-    let mut total = Vec::new();
-    for pair in pairs {
-        let new_values = generate_candidates(data.width, data.height, pair, false);
-        for value in new_values {
-            if total.contains(&value) {
-                continue;
-            } else {
-                total.push(value);
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let data = parse(input);
+        let pairs = generate_coord_pairs(&data);
+        //println!("{pairs:?}, length: {}", pairs.len());
+        let mut total = Vec::new();
+        for pair in pairs {
+            let new_values = generate_candidates(data.width, data.height, pair, false);
+            for value in new_values {
+                if total.contains(&value) {
+                    continue;
+                } else {
+                    total.push(value);
+                }
             }
         }
+        //print_map(data.height, data.width, &total);
+        total.len()
     }
-    //print_map(data.height, data.width, &total);
-    total.len().to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day08::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day08::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -208,8 +232,8 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "14");
-        assert_eq!(process_part2(file), "34");
+        assert_eq!(process_part1(file).unwrap(), "14");
+        assert_eq!(process_part2(file).unwrap(), "34");
     }
     #[test]
     fn candidate_test1() {
@@ -228,46 +252,46 @@ mod tests {
     #[test]
     fn test_2() {
         let file = include_str!("../test-input-2.txt");
-        assert_eq!(process_part1(file), "2");
+        assert_eq!(process_part1(file).unwrap(), "2");
     }
     #[test]
     fn test_3() {
         let file = include_str!("../test-input-3.txt");
-        assert_eq!(process_part1(file), "2");
+        assert_eq!(process_part1(file).unwrap(), "2");
     }
     #[test]
     fn test_4() {
         let file = include_str!("../test-input-4.txt");
-        assert_eq!(process_part1(file), "2");
+        assert_eq!(process_part1(file).unwrap(), "2");
     }
     #[test]
     fn test_5() {
         let file = include_str!("../test-input-5.txt");
-        assert_eq!(process_part1(file), "2");
+        assert_eq!(process_part1(file).unwrap(), "2");
     }
     #[test]
     fn test_6() {
         let file = include_str!("../test-input-6.txt");
-        assert_eq!(process_part1(file), "1");
+        assert_eq!(process_part1(file).unwrap(), "1");
     }
     #[test]
     fn test_7() {
         let file = include_str!("../test-input-7.txt");
-        assert_eq!(process_part1(file), "1");
+        assert_eq!(process_part1(file).unwrap(), "1");
     }
     #[test]
     fn test_8() {
         let file = include_str!("../test-input-8.txt");
-        assert_eq!(process_part1(file), "1");
+        assert_eq!(process_part1(file).unwrap(), "1");
     }
     #[test]
     fn test_9() {
         let file = include_str!("../test-input-9.txt");
-        assert_eq!(process_part1(file), "1");
+        assert_eq!(process_part1(file).unwrap(), "1");
     }
     #[test]
     fn test_10() {
         let file = include_str!("../test-input-10.txt");
-        assert_eq!(process_part1(file), "1");
+        assert_eq!(process_part1(file).unwrap(), "1");
     }
 }
@@ -1,3 +1,4 @@
+use aoc_core::{Answer, AocError, Solution};
 use std::{fmt::Display, iter::zip};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -214,21 +215,43 @@ fn parse(input: &str) -> Disk {
     Disk { data }
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut disk = parse(input);
-    while !disk.is_blockwise_left_packed() {
-        disk.pack_one_block();
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 9;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut disk = parse(input);
+        while !disk.is_blockwise_left_packed() {
+            disk.pack_one_block();
+        }
+        //println!("{disk}");
+        disk.calculate_checksum()
     }
-    //println!("{disk}");
-    disk.calculate_checksum().to_string()
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut disk = parse(input);
+        //println!("{disk}");
+        disk.pack_by_file();
+        //println!("{disk}");
+        disk.calculate_checksum()
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day09::solve_part1(input)
 }
 
-pub fn process_part2(input: &str) -> String {
-    let mut disk = parse(input);
-    //println!("{disk}");
-    disk.pack_by_file();
-    //println!("{disk}");
-    disk.calculate_checksum().to_string()
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day09::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -238,7 +261,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "1928");
-        assert_eq!(process_part2(file), "2858");
+        assert_eq!(process_part1(file).unwrap(), "1928");
+        assert_eq!(process_part2(file).unwrap(), "2858");
     }
 }
@@ -1,18 +1,25 @@
-use day_11::*;
+use aoc_2024_day_11::*;
 
 fn main() {
     divan::main();
 }
 
+fn input() -> String {
+    aoc_core::input::from_env(env!("CARGO_MANIFEST_DIR"))
+}
+
 #[divan::bench]
-fn part1() {
-    process_part1(divan::black_box(include_str!("../input.txt")));
+fn part1(bencher: divan::Bencher) {
+    let input = input();
+    bencher.bench(|| process_part1(divan::black_box(&input)));
 }
 #[divan::bench]
-fn part2() {
-    process_part2(divan::black_box(include_str!("../input.txt")));
+fn part2(bencher: divan::Bencher) {
+    let input = input();
+    bencher.bench(|| process_part2(divan::black_box(&input)));
 }
 #[divan::bench]
-fn part1_but_memo() {
-    process_part1_but_faster(divan::black_box(include_str!("../input.txt")));
+fn part1_but_memo(bencher: divan::Bencher) {
+    let input = input();
+    bencher.bench(|| process_part1_but_faster(divan::black_box(&input)));
 }
@@ -1,3 +1,4 @@
+use aoc_core::{Answer, AocError, Solution};
 use std::collections::HashMap;
 
 use nom::{
@@ -25,12 +26,12 @@ fn iterate_stones_fast(input: u64) -> StoneGroup {
         return StoneGroup::One(1);
     }
     let stone_str = input.to_string();
-    if stone_str.len() % 2 == 0 {
+    if stone_str.len().is_multiple_of(2) {
         let (first, last) = stone_str.split_at(stone_str.len() / 2);
-        return StoneGroup::Two((
+        StoneGroup::Two((
             first.parse().expect("should be a number"),
             last.parse().expect("should be a number"),
-        ));
+        ))
     } else {
         StoneGroup::One(input * 2024)
     }
@@ -60,18 +61,6 @@ fn parse(input: &str) -> IResult<&str, Vec<u64>> {
     many1(many_till(anychar, complete::u64).map(|(_discard, val)| val))(input)
 }
 
-pub fn process_part1(input: &str) -> String {
-    let (_, mut stones) = parse(input).expect("parse should succeed");
-    //println!("{stones:?}");
-    for _ in 0..25 {
-        stones = iterate_stones(stones);
-        //if i < 6 {
-        //    println!("{stones:?}");
-        //}
-    }
-    stones.len().to_string()
-}
-
 fn determine_trajectory(input: u64, generations: u64, map: &mut HashMap<(u64, u64), u64>) -> u64 {
     if generations == 1 {
         // take current stone, iterate one generation, add to map
@@ -107,16 +96,50 @@ fn determine_trajectory(input: u64, generations: u64, map: &mut HashMap<(u64, u6
     }
 }
 
-pub fn process_part2(input: &str) -> String {
-    let (_, stones) = parse(input).expect("parse should succeed");
-    let mut trajectory_map: HashMap<(u64, u64), u64> = HashMap::new();
-    //println!("{stones:?}");
-    let mut sum: u64 = 0;
-    for stone in stones {
-        sum += determine_trajectory(stone, 75, &mut trajectory_map);
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
     }
-    //println!("items in map: {}", trajectory_map.keys().len());
-    sum.to_string()
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let (_, mut stones) = parse(input).expect("parse should succeed");
+        //println!("{stones:?}");
+        for _ in 0..25 {
+            stones = iterate_stones(stones);
+            //if i < 6 {
+            //    println!("{stones:?}");
+            //}
+        }
+        stones.len()
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let (_, stones) = parse(input).expect("parse should succeed");
+        let mut trajectory_map: HashMap<(u64, u64), u64> = HashMap::new();
+        //println!("{stones:?}");
+        let mut sum: u64 = 0;
+        for stone in stones {
+            sum += determine_trajectory(stone, 75, &mut trajectory_map);
+        }
+        //println!("items in map: {}", trajectory_map.keys().len());
+        sum
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day11::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day11::solve_part2(input)
 }
 
 pub fn process_part1_but_faster(input: &str) -> String {
@@ -137,6 +160,6 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "55312");
+        assert_eq!(process_part1(file).unwrap(), "55312");
     }
 }
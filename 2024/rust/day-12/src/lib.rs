@@ -1,56 +1,20 @@
-use std::{collections::HashMap, iter::zip};
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Coord {
-    x: i64,
-    y: i64,
-}
-
-impl Coord {
-    fn from(x: usize, y: usize) -> Coord {
-        Coord {
-            x: x.try_into().unwrap(),
-            y: y.try_into().unwrap(),
-        }
-    }
-}
+use aoc_core::{Answer, AocError, Solution};
+use aoc_grid::{Coord, Direction, Grid};
+use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
 struct MapData {
-    map: HashMap<Coord, char>,
+    map: Grid<char>,
     letters: HashMap<char, Vec<Coord>>,
-    height: i64,
-    width: i64,
 }
 
 fn parse(input: &str) -> MapData {
-    let mut map: HashMap<Coord, char> = HashMap::new();
+    let map: Grid<char> = Grid::parse(input).expect("the garden should be a rectangle");
     let mut letters: HashMap<char, Vec<Coord>> = HashMap::new();
-    let mut height: usize = 0;
-    let mut width: usize = 0;
-    for (y, line) in input.lines().enumerate() {
-        for (x, ch) in line.chars().enumerate() {
-            map.insert(Coord::from(x, y), ch);
-            match letters.get_mut(&ch) {
-                Some(l) => {
-                    l.push(Coord::from(x, y));
-                }
-                None => {
-                    letters.insert(ch, vec![Coord::from(x, y)]);
-                }
-            }
-            if y == 0 {
-                width += 1;
-            }
-        }
-        height += 1;
-    }
-    MapData {
-        map,
-        letters,
-        height: height.try_into().unwrap(),
-        width: width.try_into().unwrap(),
+    for (coord, ch) in map.iter() {
+        letters.entry(*ch).or_default().push(coord);
     }
+    MapData { map, letters }
 }
 
 fn cardnial_flood(
@@ -60,69 +24,24 @@ fn cardnial_flood(
     flood_map: &mut HashMap<Coord, bool>,
     perimeter_count: &mut usize,
 ) {
-    match map.map.get(&start) {
-        Some(ch) => {
-            if *ch != letter {
-                *perimeter_count += 1;
-                return;
-            }
-        }
-        None => panic!(
-            "should not get here:\nmap_width: {}\nmap_height: {}\nstart: {start:?}\nletter: {letter}\nmap: {map:?}\nflood_map: {flood_map:?}\nperimeter: {perimeter_count}",
-        map.width,
-        map.height
-        ),
+    if map.map[start] != letter {
+        *perimeter_count += 1;
+        return;
     }
-    match flood_map.get(&start) {
-        Some(flood) => {
-            if *flood {
-                return;
-            }
-        }
-        None => {
-            flood_map.insert(start, true);
-            // flood up, flood down, flood right, flood left
-            if start.y > 0 {
-                let new_start = Coord {
-                    x: start.x,
-                    y: start.y - 1,
-                };
-                cardnial_flood(new_start, letter, map, flood_map, perimeter_count);
-            } else {
-                *perimeter_count += 1;
-            }
-            if start.y < map.height - 1 {
-                let new_start = Coord {
-                    x: start.x,
-                    y: start.y + 1,
-                };
-                cardnial_flood(new_start, letter, map, flood_map, perimeter_count);
-            } else {
-                *perimeter_count += 1;
-            }
-            if start.x > 0 {
-                let new_start = Coord {
-                    x: start.x - 1,
-                    y: start.y,
-                };
-                cardnial_flood(new_start, letter, map, flood_map, perimeter_count);
-            } else {
-                *perimeter_count += 1;
-            }
-            if start.x < map.width - 1 {
-                let new_start = Coord {
-                    x: start.x + 1,
-                    y: start.y,
-                };
-                cardnial_flood(new_start, letter, map, flood_map, perimeter_count);
-            } else {
-                *perimeter_count += 1;
-            }
+    if flood_map.contains_key(&start) {
+        return;
+    }
+    flood_map.insert(start, true);
+    // flood in every direction, the edge of the map counts as perimeter
+    for direction in Direction::ALL {
+        match map.map.step(start, direction) {
+            Some(new_start) => cardnial_flood(new_start, letter, map, flood_map, perimeter_count),
+            None => *perimeter_count += 1,
         }
     }
 }
 
-fn prune_list(input: Vec<Coord>, remove_these: &Vec<Coord>) -> Vec<Coord> {
+fn prune_list(input: Vec<Coord>, remove_these: &[Coord]) -> Vec<Coord> {
     let mut new_list = Vec::new();
     for item in input {
         if !remove_these.contains(&item) {
@@ -136,14 +55,14 @@ fn determine_cost_p1(map: &MapData) -> usize {
     let mut sum = 0;
     for ch in map.letters.keys() {
         // get all coords for a letter
-        let mut letter_list = map.letters.get(&ch).unwrap().clone();
+        let mut letter_list = map.letters.get(ch).unwrap().clone();
         //println!("letter: {ch}, locations: {letter_list:?}");
         while !letter_list.is_empty() {
             // split into regions plots with same letter but not touching
             let mut flood_map: HashMap<Coord, bool> = HashMap::new();
             let mut perimeter: usize = 0;
             cardnial_flood(letter_list[0], *ch, map, &mut flood_map, &mut perimeter);
-            let region: Vec<Coord> = flood_map.keys().map(|x| *x).collect();
+            let region: Vec<Coord> = flood_map.keys().copied().collect();
             letter_list = prune_list(letter_list, &region);
             //println!(
             //    "letter: {ch}, region area: {}, preimeter: {perimeter:?}",
@@ -157,71 +76,22 @@ fn determine_cost_p1(map: &MapData) -> usize {
     sum
 }
 
-fn print_region(input: &Vec<Coord>, input_ch: &char, map: &MapData) {
+#[allow(dead_code)]
+fn print_region(input: &[Coord], input_ch: &char, map: &MapData) {
     println!("{input_ch} region");
-    for y in 0..map.height {
-        for x in 0..map.width {
-            if input.contains(&Coord { x, y }) {
-                print!("{input_ch}");
-            } else {
-                print!(".");
-            }
+    for coord in map.map.coords() {
+        if input.contains(&coord) {
+            print!("{input_ch}");
+        } else {
+            print!(".");
+        }
+        if coord.x == map.map.width() - 1 {
+            println!();
         }
-        println!();
     }
 }
 
-fn neighbors(input: &Coord) -> (Coord, Coord, Coord, Coord) {
-    (
-        //up
-        Coord {
-            x: input.x,
-            y: input.y - 1,
-        },
-        // down
-        Coord {
-            x: input.x,
-            y: input.y + 1,
-        },
-        // left
-        Coord {
-            x: input.x - 1,
-            y: input.y,
-        },
-        // right
-        Coord {
-            x: input.x + 1,
-            y: input.y,
-        },
-    )
-}
-
-fn diags(input: &Coord) -> (Coord, Coord, Coord, Coord) {
-    (
-        // up left
-        Coord {
-            x: input.x - 1,
-            y: input.y - 1,
-        },
-        // up right
-        Coord {
-            x: input.x + 1,
-            y: input.y - 1,
-        },
-        // down left
-        Coord {
-            x: input.x - 1,
-            y: input.y + 1,
-        },
-        // down right
-        Coord {
-            x: input.x + 1,
-            y: input.y + 1,
-        },
-    )
-}
-
-fn count_sides(input: &Vec<Coord>) -> usize {
+fn count_sides(input: &[Coord]) -> usize {
     if input.is_empty() {
         panic!("should not be called on an empty region")
     }
@@ -238,11 +108,12 @@ fn count_sides(input: &Vec<Coord>) -> usize {
     //not all in a row or column, time to count corners
     let mut corners = 0;
     for start in input {
-        let (n1, n2, n3, n4) = neighbors(start);
-        let up = input.contains(&n1);
-        let down = input.contains(&n2);
-        let left = input.contains(&n3);
-        let right = input.contains(&n4);
+        let in_region =
+            |dx: isize, dy: isize| start.offset(dx, dy).is_some_and(|c| input.contains(&c));
+        let up = in_region(0, -1);
+        let down = in_region(0, 1);
+        let left = in_region(-1, 0);
+        let right = in_region(1, 0);
         // exterior corners
         //print!("checking: ({},{}); {input:?}\n", start.x, start.y);
         // top right
@@ -266,11 +137,10 @@ fn count_sides(input: &Vec<Coord>) -> usize {
             corners += 1;
         }
         // interior corners
-        let (d1, d2, d3, d4) = diags(start);
-        let upleft = input.contains(&d1);
-        let upright = input.contains(&d2);
-        let downleft = input.contains(&d3);
-        let downright = input.contains(&d4);
+        let upleft = in_region(-1, -1);
+        let upright = in_region(1, -1);
+        let downleft = in_region(-1, 1);
+        let downright = in_region(1, 1);
         // up left
         if up && left && !upleft {
             //print!("diag upleft interior corner, ");
@@ -300,14 +170,14 @@ fn determine_cost_p2(map: &MapData) -> usize {
     let mut sum = 0;
     for ch in map.letters.keys() {
         // get all coords for a letter
-        let mut letter_list = map.letters.get(&ch).unwrap().clone();
+        let mut letter_list = map.letters.get(ch).unwrap().clone();
         //println!("letter: {ch}, locations: {letter_list:?}");
         while !letter_list.is_empty() {
             // split into regions plots with same letter but not touching
             let mut flood_map: HashMap<Coord, bool> = HashMap::new();
             let mut perimeter: usize = 0;
             cardnial_flood(letter_list[0], *ch, map, &mut flood_map, &mut perimeter);
-            let region: Vec<Coord> = flood_map.keys().map(|x| *x).collect();
+            let region: Vec<Coord> = flood_map.keys().copied().collect();
             letter_list = prune_list(letter_list, &region);
             let sides = count_sides(&region);
             //print_region(&region, &ch, map);
@@ -322,14 +192,36 @@ fn determine_cost_p2(map: &MapData) -> usize {
     sum
 }
 
-pub fn process_part1(input: &str) -> String {
-    let data = parse(input);
-    determine_cost_p1(&data).to_string()
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 12;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let data = parse(input);
+        determine_cost_p1(&data)
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let data = parse(input);
+        determine_cost_p2(&data)
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day12::solve_part1(input)
 }
 
-pub fn process_part2(input: &str) -> String {
-    let data = parse(input);
-    determine_cost_p2(&data).to_string()
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day12::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -339,29 +231,29 @@ mod tests {
     #[test]
     fn test_input_negative_1() {
         let file = include_str!("../test-input--1.txt");
-        assert_eq!(process_part1(file), "140");
-        assert_eq!(process_part2(file), "80");
+        assert_eq!(process_part1(file).unwrap(), "140");
+        assert_eq!(process_part2(file).unwrap(), "80");
     }
     #[test]
     fn test_input0() {
         let file = include_str!("../test-input-0.txt");
-        assert_eq!(process_part1(file), "772");
-        assert_eq!(process_part2(file), "436");
+        assert_eq!(process_part1(file).unwrap(), "772");
+        assert_eq!(process_part2(file).unwrap(), "436");
     }
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "1930");
-        assert_eq!(process_part2(file), "1206");
+        assert_eq!(process_part1(file).unwrap(), "1930");
+        assert_eq!(process_part2(file).unwrap(), "1206");
     }
     #[test]
     fn test_input2() {
         let file = include_str!("../test-input-2.txt");
-        assert_eq!(process_part2(file), "236")
+        assert_eq!(process_part2(file).unwrap(), "236")
     }
     #[test]
     fn test_input3() {
         let file = include_str!("../test-input-3.txt");
-        assert_eq!(process_part2(file), "368")
+        assert_eq!(process_part2(file).unwrap(), "368")
     }
 }
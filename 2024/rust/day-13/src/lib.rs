@@ -1,5 +1,5 @@
-use std::net::Incoming;
-
+use aoc_core::{Answer, AocError, Solution};
+use aoc_linalg::LinearSystem;
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -114,99 +114,74 @@ fn get_button_counts(machine: Machine) -> Option<(i64, i64)> {
     //     A   B
     // X: [94, 22  [A] = [8400]
     // Y:  34, 67] [B] = [5400]
-    // *Button*X=*Result*
-    // *Button*^-1*Result* = X
-    // det([[a, b],[c, d]]) = 1/(ad-bc)
-    // inverse([[a, b], [c,d]]) = 1/(ad-bc) [[d, -b], [-c, a]]
-    let a = machine.button_a.x;
-    let b = machine.button_b.x;
-    let c = machine.button_a.y;
-    let d = machine.button_b.y;
-    if (a * d - b * c) == 0 {
-        //println!("machine: {machine:?} has no inverse");
-        return None;
-    }
-    let x = machine.prize.x;
-    let y = machine.prize.y;
-    // A = (dx-by)/(ad-bc)
-    // B = -(cx-ay)/(ad-bc)
-    // for bc < ad:
-    // if by > dx then no positive integer solution
-    // if ay < cx then no positive integer solution
-    if b * c < a * d {
-        if b * y > d * x {
-            //println!("machine: {machine:?} has a negative solution");
-            return None;
-        }
-        if a * y < c * x {
-            //println!("machine: {machine:?} has a negative solution");
-            return None;
-        }
-    }
-    // for bc > ad:
-    // if by < dx then no positive integer solution
-    // if ay > cx then no positive integer solution
-    else {
-        if b * y < d * x {
-            //println!("machine has a negative solution");
-            return None;
-        }
-        if a * y > c * x {
-            //println!("machine has a negative solution");
-            return None;
-        }
-    }
-    let det = a * d - b * c;
-    let a_presses_numerator = (d * x) - (b * y);
-    let a_presses = if a_presses_numerator % det != 0 {
-        //println!("machine has a non-integer solution for A presses");
-        return None;
-    } else {
-        a_presses_numerator / det
-    };
-    let b_presses_numerator = -((c * x) - (a * y));
-    let b_presses = if b_presses_numerator % det != 0 {
-        //println!("machine has a non-integer solution for B presses");
-        return None;
-    } else {
-        b_presses_numerator / det
-    };
-    //println!("machine requires: A:{a_presses}, B:{b_presses}");
-    return Some((a_presses, b_presses));
+    // buttons that move in the same direction leave no unique solution
+    let a = i128::from(machine.button_a.x);
+    let b = i128::from(machine.button_b.x);
+    let c = i128::from(machine.button_a.y);
+    let d = i128::from(machine.button_b.y);
+    let prize = [i128::from(machine.prize.x), i128::from(machine.prize.y)];
+    let presses = LinearSystem::new(&[vec![a, b], vec![c, d]], &prize)
+        .reduce()?
+        .unique_solution()?;
+    let a_presses = presses[0].to_integer().filter(|&n| n >= 0)?;
+    let b_presses = presses[1].to_integer().filter(|&n| n >= 0)?;
+    Some((a_presses.try_into().ok()?, b_presses.try_into().ok()?))
 }
 
-pub fn process_part1(input: &str) -> String {
-    let a_cost = 3;
-    let b_cost = 1;
-    let machines = parse_p1(input);
-    //println!("machines: {}\n{machines:?}", machines.len());
-    let mut cost = 0;
-    for machine in machines {
-        match get_button_counts(machine) {
-            Some((a, b)) => {
-                cost += (a * a_cost) + (b * b_cost);
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+    type Parsed<'a> = &'a str;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let a_cost = 3;
+        let b_cost = 1;
+        let machines = parse_p1(input);
+        //println!("machines: {}\n{machines:?}", machines.len());
+        let mut cost = 0;
+        for machine in machines {
+            match get_button_counts(machine) {
+                Some((a, b)) => {
+                    cost += (a * a_cost) + (b * b_cost);
+                }
+                None => continue,
             }
-            None => continue,
         }
+        cost
     }
-    cost.to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let a_cost = 3;
-    let b_cost = 1;
-    let machines = parse_p2(input);
-    //println!("machines: {}\n{machines:?}", machines.len());
-    let mut cost = 0;
-    for machine in machines {
-        match get_button_counts(machine) {
-            Some((a, b)) => {
-                cost += (a * a_cost) + (b * b_cost);
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let a_cost = 3;
+        let b_cost = 1;
+        let machines = parse_p2(input);
+        //println!("machines: {}\n{machines:?}", machines.len());
+        let mut cost = 0;
+        for machine in machines {
+            match get_button_counts(machine) {
+                Some((a, b)) => {
+                    cost += (a * a_cost) + (b * b_cost);
+                }
+                None => continue,
             }
-            None => continue,
         }
+        cost
     }
-    cost.to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day13::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day13::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -216,6 +191,6 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "480");
+        assert_eq!(process_part1(file).unwrap(), "480");
     }
 }
@@ -0,0 +1,6 @@
+use aoc_2024_day_14::view_part2;
+
+fn main() {
+    let file = aoc_core::input::from_args(env!("CARGO_MANIFEST_DIR"));
+    aoc_core::print_or_exit(view_part2(&file).map(Into::into));
+}
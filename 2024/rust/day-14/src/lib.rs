@@ -1,13 +1,14 @@
+use aoc_core::{Answer, AocError, Solution};
 use std::collections::HashMap;
+#[cfg(feature = "viewer")]
 use std::io::{stdin, stdout, Write};
 
+use aoc_parse::{int, lines, parse_all};
 use nom::{
-    branch::alt,
     bytes::complete::tag,
-    character::complete::{self, anychar, line_ending},
-    multi::{many1, many_till},
+    character::complete::char,
     sequence::{preceded, separated_pair},
-    IResult, Parser,
+    IResult,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -23,15 +24,8 @@ struct RobotData {
 }
 
 fn parse_robot(input: &str) -> IResult<&str, RobotData> {
-    let (input, pos) = preceded(
-        tag("p="),
-        separated_pair(complete::i64, tag(","), complete::i64),
-    )(input)?;
-    let (input, vel) = preceded(
-        tag(" v="),
-        separated_pair(complete::i64, tag(","), complete::i64),
-    )(input)?;
-    let (input, _) = alt((line_ending, tag("")))(input)?;
+    let (input, pos) = preceded(tag("p="), separated_pair(int, char(','), int))(input)?;
+    let (input, vel) = preceded(tag(" v="), separated_pair(int, char(','), int))(input)?;
     Ok((
         input,
         RobotData {
@@ -41,12 +35,11 @@ fn parse_robot(input: &str) -> IResult<&str, RobotData> {
     ))
 }
 
-fn parse(input: &str) -> Vec<RobotData> {
-    let (_, data) = many1(many_till(anychar, parse_robot).map(|(_discard, val)| val))(input)
-        .expect("parse should succeed");
-    data
+fn parse(input: &str) -> Result<Vec<RobotData>, AocError> {
+    Ok(parse_all(input, lines(parse_robot))?)
 }
 
+#[cfg(feature = "viewer")]
 fn print_map(data: &HashMap<Coord, i64>, width: i64, height: i64) {
     let mut asterisks: Vec<(Coord, i64)> = Vec::new();
     println!("current map:");
@@ -54,7 +47,7 @@ fn print_map(data: &HashMap<Coord, i64>, width: i64, height: i64) {
         for x in 0..width {
             match data.get(&Coord { x, y }) {
                 Some(val) => {
-                    if *val > 9 && *val < 0 {
+                    if *val > 9 || *val < 0 {
                         asterisks.push((Coord { x, y }, *val));
                         print!("*");
                     } else {
@@ -74,12 +67,12 @@ fn print_map(data: &HashMap<Coord, i64>, width: i64, height: i64) {
 }
 
 fn iterate_x_times(
-    data: &Vec<RobotData>,
+    data: &[RobotData],
     width: i64,
     height: i64,
     iterations: i64,
 ) -> HashMap<Coord, i64> {
-    let mut new_data = data.clone();
+    let mut new_data = data.to_vec();
     for _ in 0..iterations {
         for robot in new_data.iter_mut() {
             robot.postion.x += robot.velocity.x;
@@ -116,102 +109,118 @@ fn count_quadrants_after_iterations(
     height: i64,
     data: Vec<RobotData>,
     iterations: i64,
-) -> String {
+) -> Result<String, AocError> {
+    if width % 2 == 0 || height % 2 == 0 {
+        // there'd be no middle line to leave out
+        return Err(AocError::unsupported(
+            "the quadrants need an odd width and height",
+        ));
+    }
     let robot_map = iterate_x_times(&data, width, height, iterations);
-    let vertical_divider_inclusive = width % 2 == 0;
-    let horizontal_divider_inclusive = height % 2 == 0;
     let vertical_edge = width / 2;
     let horizontal_edge = height / 2;
     let mut q1_count = 0;
     let mut q2_count = 0;
     let mut q3_count = 0;
     let mut q4_count = 0;
-    if vertical_divider_inclusive {
-        if horizontal_divider_inclusive {
-            // nothing left out
-            todo!();
-        } else {
-            // y has an excluded middle line, but not x
-            todo!();
-        }
-    } else {
-        if horizontal_divider_inclusive {
-            // x has an excluded middle line, but not y
-            todo!();
-        } else {
-            // both x and y have an excluded middle line
-            // count q1
-            for y in 0..horizontal_edge {
-                for x in 0..vertical_edge {
-                    match robot_map.get(&Coord { x, y }) {
-                        Some(val) => {
-                            q1_count += val;
-                        }
-                        None => {
-                            continue;
-                        }
-                    }
+    // both x and y have an excluded middle line
+    // count q1
+    for y in 0..horizontal_edge {
+        for x in 0..vertical_edge {
+            match robot_map.get(&Coord { x, y }) {
+                Some(val) => {
+                    q1_count += val;
+                }
+                None => {
+                    continue;
                 }
             }
-            // count q2
-            for y in 0..horizontal_edge {
-                for x in (vertical_edge + 1)..width {
-                    match robot_map.get(&Coord { x, y }) {
-                        Some(val) => {
-                            q2_count += val;
-                        }
-                        None => {
-                            continue;
-                        }
-                    }
+        }
+    }
+    // count q2
+    for y in 0..horizontal_edge {
+        for x in (vertical_edge + 1)..width {
+            match robot_map.get(&Coord { x, y }) {
+                Some(val) => {
+                    q2_count += val;
+                }
+                None => {
+                    continue;
                 }
             }
-            // count q3
-            for y in (horizontal_edge + 1)..height {
-                for x in 0..vertical_edge {
-                    match robot_map.get(&Coord { x, y }) {
-                        Some(val) => {
-                            q3_count += val;
-                        }
-                        None => {
-                            continue;
-                        }
-                    }
+        }
+    }
+    // count q3
+    for y in (horizontal_edge + 1)..height {
+        for x in 0..vertical_edge {
+            match robot_map.get(&Coord { x, y }) {
+                Some(val) => {
+                    q3_count += val;
+                }
+                None => {
+                    continue;
                 }
             }
-            // count q4
-            for y in (horizontal_edge + 1)..height {
-                for x in (vertical_edge + 1)..width {
-                    match robot_map.get(&Coord { x, y }) {
-                        Some(val) => {
-                            q4_count += val;
-                        }
-                        None => {
-                            continue;
-                        }
-                    }
+        }
+    }
+    // count q4
+    for y in (horizontal_edge + 1)..height {
+        for x in (vertical_edge + 1)..width {
+            match robot_map.get(&Coord { x, y }) {
+                Some(val) => {
+                    q4_count += val;
+                }
+                None => {
+                    continue;
                 }
             }
         }
     }
     //print_map(robot_map, width, height);
     //println!("q1: {q1_count}, q2: {q2_count}, q3: {q3_count}, q4: {q4_count}");
-    (q1_count * q2_count * q3_count * q4_count).to_string()
+    Ok((q1_count * q2_count * q3_count * q4_count).to_string())
 }
 
-pub fn process_part1(input: &str) -> String {
-    let data = parse(input);
-    //println!("{data:?}");
-    count_quadrants_after_iterations(101, 103, data, 100)
+/// The time in `0..size` at which the robots are bunched up the tightest
+/// along one axis. Each axis repeats every `size` seconds on its own.
+fn tightest_time(data: &[RobotData], axis: fn(&Coord) -> i64, size: i64) -> i64 {
+    (0..size)
+        .min_by_key(|&seconds| {
+            let positions: Vec<i64> = data
+                .iter()
+                .map(|robot| {
+                    (axis(&robot.postion) + axis(&robot.velocity) * seconds).rem_euclid(size)
+                })
+                .collect();
+            let count = positions.len() as i64;
+            let sum: i64 = positions.iter().sum();
+            let sum_of_squares: i64 = positions.iter().map(|p| p * p).sum();
+            // the variance times count², which orders the same way
+            count * sum_of_squares - sum * sum
+        })
+        .unwrap_or(0)
 }
 
+/// The first time the robots draw the tree, which is when they're bunched up
+/// along both axes at once. The per-axis times are combined with the Chinese
+/// remainder theorem, so the width and height need to be coprime.
+fn tree_time(data: &[RobotData], width: i64, height: i64) -> Result<i64, AocError> {
+    let x_time = tightest_time(data, |coord| coord.x, width);
+    let y_time = tightest_time(data, |coord| coord.y, height);
+    (0..height)
+        .map(|cycles| x_time + width * cycles)
+        .find(|time| (time - y_time).rem_euclid(height) == 0)
+        .ok_or_else(|| AocError::unsupported("the width and height must be coprime"))
+}
+
+#[cfg(feature = "viewer")]
 fn iterate_x_times_stack_check(
-    data: &Vec<RobotData>,
+    data: &[RobotData],
     width: i64,
     height: i64,
     iterations: i64,
 ) -> (Vec<RobotData>, bool) {
-    let mut new_data = data.clone();
+    let mut new_data = data.to_vec();
     for _ in 0..iterations {
         for robot in new_data.iter_mut() {
             robot.postion.x += robot.velocity.x;
@@ -247,14 +256,51 @@ fn iterate_x_times_stack_check(
     (new_data, stack_check)
 }
 
-pub fn process_part2(input: &str) -> String {
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+    type Parsed<'a> = &'a str;
+    type Part1 = Result<String, AocError>;
+    type Part2 = Result<i64, AocError>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let data = parse(input)?;
+        //println!("{data:?}");
+        count_quadrants_after_iterations(101, 103, data, 100)
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let data = parse(input)?;
+        tree_time(&data, 101, 103)
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day14::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day14::solve_part2(input)
+}
+
+/// Steps through the frames where no two robots share a tile, printing each
+/// and asking whether it's the tree. Returns the time of the accepted frame.
+#[cfg(feature = "viewer")]
+pub fn view_part2(input: &str) -> Result<i64, AocError> {
     let width = 101;
     let height = 103;
-    let data = parse(input);
+    let data = parse(input)?;
     let mut iterations = 0;
-    let (mut new_map, mut stacks) = iterate_x_times_stack_check(&data, width, height, 0);
+    let (mut new_map, _) = iterate_x_times_stack_check(&data, width, height, 0);
     loop {
-        (new_map, stacks) = iterate_x_times_stack_check(&new_map, width, height, 1);
+        let (next_map, stacks) = iterate_x_times_stack_check(&new_map, width, height, 1);
+        new_map = next_map;
         iterations += 1;
         if stacks {
             let r_map = iterate_x_times(&new_map, width, height, 0);
@@ -267,7 +313,7 @@ pub fn process_part2(input: &str) -> String {
                 .read_line(&mut s)
                 .expect("Did not enter a correct string");
             if s.trim() == "y" || s.trim() == "yes" || s.trim() == "YES" || s.trim() == "Y" {
-                return iterations.to_string();
+                return Ok(iterations);
             }
         } //else {
           // if iterations % 1000 == 0 {
@@ -277,8 +323,6 @@ pub fn process_part2(input: &str) -> String {
           // }
           //}
     }
-    //println!("{data:?}");
-    "works".to_string()
 }
 
 #[cfg(test)]
@@ -288,7 +332,27 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        let data = parse(file);
-        assert_eq!(count_quadrants_after_iterations(11, 7, data, 100), "12");
+        let data = parse(file).unwrap();
+        assert_eq!(
+            count_quadrants_after_iterations(11, 7, data.clone(), 100).unwrap(),
+            "12"
+        );
+        assert!(count_quadrants_after_iterations(12, 7, data, 100).is_err());
+    }
+
+    #[test]
+    fn test_tree_time() {
+        // every robot is at 5,3 after 30 seconds, and the velocities differ
+        // enough that they never all line up on either axis otherwise
+        let data: Vec<RobotData> = (1..=6i64)
+            .map(|v| RobotData {
+                postion: Coord {
+                    x: (5 - v * 30).rem_euclid(11),
+                    y: (3 - v * 30).rem_euclid(7),
+                },
+                velocity: Coord { x: v, y: v },
+            })
+            .collect();
+        assert_eq!(tree_time(&data, 11, 7), Ok(30));
     }
 }
@@ -1,3 +1,6 @@
+use aoc_core::{Answer, AocError, Solution};
+use aoc_grid::{Coord, Direction, Grid};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
     Nothing,
@@ -8,47 +11,9 @@ enum Tile {
     BoxHalfRight,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Coord {
-    x: usize,
-    y: usize,
-}
-
-impl Coord {
-    fn add(self, other: Direction) -> Coord {
-        let (x, y) = match other {
-            Direction::Up => (self.x, self.y - 1),
-            Direction::Down => (self.x, self.y + 1),
-            Direction::Left => (self.x - 1, self.y),
-            Direction::Right => (self.x + 1, self.y),
-        };
-        Coord { x, y }
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl Direction {
-    fn add(self, other: Coord) -> Coord {
-        let (x, y) = match self {
-            Direction::Up => (other.x, other.y - 1),
-            Direction::Down => (other.x, other.y + 1),
-            Direction::Left => (other.x - 1, other.y),
-            Direction::Right => (other.x + 1, other.y),
-        };
-        Coord { x, y }
-    }
-}
-
 #[derive(Debug, Clone)]
 struct MapData {
-    map: Vec<Vec<Tile>>,
+    map: Grid<Tile>,
     robot_pos: Coord,
     move_list: Vec<Direction>,
 }
@@ -60,70 +25,59 @@ fn parse(input: &str) -> MapData {
         .next()
         .expect("there should be a list of directions");
 
-    let mut tile_map: Vec<Vec<Tile>> = Vec::new();
+    let width = map.lines().next().expect("the map is not empty").len();
+    let mut tiles: Vec<Tile> = Vec::new();
     let mut robot_pos: Coord = Coord { x: 0, y: 0 };
     for (y, line) in map.lines().enumerate() {
-        let mut tile_line: Vec<Tile> = Vec::new();
         for (x, ch) in line.chars().enumerate() {
             let tile_type: Tile = match ch {
                 '#' => Tile::Wall,
                 'O' => Tile::Box,
                 '.' => Tile::Nothing,
                 '@' => {
-                    robot_pos = Coord {
-                        x: x.try_into().unwrap(),
-                        y: y.try_into().unwrap(),
-                    };
+                    robot_pos = Coord { x, y };
                     Tile::Robot
                 }
                 unknown => {
                     panic!("unknown tile type: {unknown}");
                 }
             };
-            tile_line.push(tile_type);
+            tiles.push(tile_type);
         }
-        tile_map.push(tile_line);
     }
     let mut move_list: Vec<Direction> = Vec::new();
     for ch in directions.chars() {
-        let dir: Direction = match ch {
-            '^' => Direction::Up,
-            'v' => Direction::Down,
-            '<' => Direction::Left,
-            '>' => Direction::Right,
-            '\n' => continue,
-            unknown => {
-                panic!("unknown direction type: {unknown}");
-            }
-        };
+        if ch == '\n' {
+            continue;
+        }
+        let dir =
+            Direction::from_arrow(ch).unwrap_or_else(|| panic!("unknown direction type: {ch}"));
         move_list.push(dir);
     }
     if robot_pos == (Coord { x: 0, y: 0 }) {
         panic!("could not find robot")
     }
     MapData {
-        map: tile_map,
+        map: Grid::from_cells(width, tiles),
         robot_pos,
         move_list,
     }
 }
 
 fn try_move_small_box(location: &Coord, direction: &Direction, data: &mut MapData) -> bool {
-    let new_location = location.add(*direction);
-    match data.map[new_location.y][new_location.x] {
+    let new_location = location.step(*direction).unwrap();
+    match data.map[new_location] {
         Tile::Nothing => {
-            data.map[new_location.y][new_location.x] = Tile::Box;
-            return true;
-        }
-        Tile::Wall => {
-            return false;
+            data.map[new_location] = Tile::Box;
+            true
         }
+        Tile::Wall => false,
         Tile::Box => {
             if try_move_small_box(&new_location, direction, data) {
-                data.map[new_location.y][new_location.x] = Tile::Box;
-                return true;
+                data.map[new_location] = Tile::Box;
+                true
             } else {
-                return false;
+                false
             }
         }
         Tile::Robot => {
@@ -148,26 +102,26 @@ fn run_all_small_movements(data: MapData) -> MapData {
         move_list: Vec::new(),
     };
     for direction in data.move_list {
-        let Coord { x: try_x, y: try_y } = new_data.robot_pos.add(direction);
-        match new_data.map[try_y][try_x] {
+        let try_pos = new_data.robot_pos.step(direction).unwrap();
+        match new_data.map[try_pos] {
             Tile::Nothing => {
-                new_data.map[new_data.robot_pos.y][new_data.robot_pos.x] = Tile::Nothing;
-                new_data.map[try_y][try_x] = Tile::Robot;
-                new_data.robot_pos = Coord { x: try_x, y: try_y };
+                new_data.map[new_data.robot_pos] = Tile::Nothing;
+                new_data.map[try_pos] = Tile::Robot;
+                new_data.robot_pos = try_pos;
             }
             Tile::Wall => {
                 continue;
             }
             Tile::Box => {
-                if try_move_small_box(&Coord { x: try_x, y: try_y }, &direction, &mut new_data) {
-                    new_data.map[new_data.robot_pos.y][new_data.robot_pos.x] = Tile::Nothing;
-                    new_data.map[try_y][try_x] = Tile::Robot;
-                    new_data.robot_pos = Coord { x: try_x, y: try_y };
+                if try_move_small_box(&try_pos, &direction, &mut new_data) {
+                    new_data.map[new_data.robot_pos] = Tile::Nothing;
+                    new_data.map[try_pos] = Tile::Robot;
+                    new_data.robot_pos = try_pos;
                 } else {
                     continue;
                 }
             }
-            Tile::Robot => panic!("robot ran into itself at ({try_x},{try_y})"),
+            Tile::Robot => panic!("robot ran into itself at ({},{})", try_pos.x, try_pos.y),
             Tile::BoxHalfLeft => {
                 panic!("cannot move double sized boxes here");
             }
@@ -179,9 +133,10 @@ fn run_all_small_movements(data: MapData) -> MapData {
     new_data
 }
 
+#[allow(dead_code)]
 fn print_map(data: &MapData) {
     println!("current map:");
-    for tile_row in &data.map {
+    for tile_row in data.map.rows() {
         for tile in tile_row {
             match tile {
                 Tile::Nothing => {
@@ -212,7 +167,7 @@ fn calculate_total(data: &MapData) -> usize {
     let mut sum = 0;
     let mut big_detected = false;
     let mut small_detected = false;
-    for (y, tile_row) in data.map.iter().enumerate() {
+    for (y, tile_row) in data.map.rows().enumerate() {
         for (x, tile) in tile_row.iter().enumerate() {
             match tile {
                 Tile::Box => {
@@ -238,13 +193,6 @@ fn calculate_total(data: &MapData) -> usize {
     sum
 }
 
-pub fn process_part1(input: &str) -> String {
-    let data = parse(input);
-    let new_data = run_all_small_movements(data);
-    //print_map(&new_data);
-    calculate_total(&new_data).to_string()
-}
-
 fn big_parse(input: &str) -> MapData {
     let mut input_iter = input.split("\n\n");
     let map = input_iter.next().expect("there should be a map");
@@ -252,20 +200,17 @@ fn big_parse(input: &str) -> MapData {
         .next()
         .expect("there should be a list of directions");
 
-    let mut tile_map: Vec<Vec<Tile>> = Vec::new();
+    let width = 2 * map.lines().next().expect("the map is not empty").len();
+    let mut tiles: Vec<Tile> = Vec::new();
     let mut robot_pos: Coord = Coord { x: 0, y: 0 };
     for (y, line) in map.lines().enumerate() {
-        let mut tile_line: Vec<Tile> = Vec::new();
         for (x, ch) in line.chars().enumerate() {
             let left_tile_type: Tile = match ch {
                 '#' => Tile::Wall,
                 'O' => Tile::BoxHalfLeft,
                 '.' => Tile::Nothing,
                 '@' => {
-                    robot_pos = Coord {
-                        x: (x * 2).try_into().unwrap(),
-                        y: y.try_into().unwrap(),
-                    };
+                    robot_pos = Coord { x: x * 2, y };
                     Tile::Robot
                 }
                 unknown => {
@@ -281,30 +226,24 @@ fn big_parse(input: &str) -> MapData {
                     panic!("unknown tile type: {unknown}");
                 }
             };
-            tile_line.push(left_tile_type);
-            tile_line.push(right_tile_type);
+            tiles.push(left_tile_type);
+            tiles.push(right_tile_type);
         }
-        tile_map.push(tile_line);
     }
     let mut move_list: Vec<Direction> = Vec::new();
     for ch in directions.chars() {
-        let dir: Direction = match ch {
-            '^' => Direction::Up,
-            'v' => Direction::Down,
-            '<' => Direction::Left,
-            '>' => Direction::Right,
-            '\n' => continue,
-            unknown => {
-                panic!("unknown direction type: {unknown}");
-            }
-        };
+        if ch == '\n' {
+            continue;
+        }
+        let dir =
+            Direction::from_arrow(ch).unwrap_or_else(|| panic!("unknown direction type: {ch}"));
         move_list.push(dir);
     }
     if robot_pos == (Coord { x: 0, y: 0 }) {
         panic!("could not find robot")
     }
     MapData {
-        map: tile_map,
+        map: Grid::from_cells(width, tiles),
         robot_pos,
         move_list,
     }
@@ -318,65 +257,50 @@ fn large_box_moveable(
 ) -> bool {
     //validate locations
     //println!("location_left: {location_left:?}, location_right: {location_right:?}, direction: {direction:?}");
-    if *location_right != location_left.add(Direction::Right) {
+    if *location_right != location_left.step(Direction::East).unwrap() {
         panic!(
             "box is not a full box somehow??? left: {location_left:?}, right: {location_right:?}, direction: {direction:?}"
         );
     }
-    if data.map[location_left.y][location_left.x] != Tile::BoxHalfLeft {
+    if data.map[*location_left] != Tile::BoxHalfLeft {
         panic!(
             "left side is not a left half box: left: {location_left:?}, right: {location_right:?}, direction: {direction:?}"
         )
     }
-    if data.map[location_right.y][location_right.x] != Tile::BoxHalfRight {
+    if data.map[*location_right] != Tile::BoxHalfRight {
         panic!(
             "right side is not a right half box: left: {location_left:?}, right: {location_right:?}, direction: {direction:?}"
         )
     }
-    let new_location_left = location_left.add(*direction);
-    let new_location_right = location_right.add(*direction);
-    if *direction == Direction::Up || *direction == Direction::Down {
-        match (
-            data.map[new_location_left.y][new_location_left.x],
-            data.map[new_location_right.y][new_location_right.x],
-        ) {
+    let new_location_left = location_left.step(*direction).unwrap();
+    let new_location_right = location_right.step(*direction).unwrap();
+    if direction.is_vertical() {
+        match (data.map[new_location_left], data.map[new_location_right]) {
             (Tile::Box, _) => {
                 panic!("cannot move small boxes");
             }
             (_, Tile::Box) => {
                 panic!("cannot move small boxes");
             }
-            (Tile::Wall, _) => {
-                return false;
-            }
-            (_, Tile::Wall) => {
-                return false;
-            }
+            (Tile::Wall, _) => false,
+            (_, Tile::Wall) => false,
             (Tile::Robot, _) => {
                 panic!("ran into robot left");
             }
             (_, Tile::Robot) => {
                 panic!("ran into robot right");
             }
-            (Tile::Nothing, Tile::Nothing) => {
-                return true;
-            }
+            (Tile::Nothing, Tile::Nothing) => true,
 
-            (Tile::Nothing, Tile::BoxHalfLeft) => {
-                if large_box_moveable(
-                    &new_location_right,
-                    &Coord {
-                        x: new_location_right.x + 1,
-                        y: new_location_right.y,
-                    },
-                    direction,
-                    data,
-                ) {
-                    return true;
-                } else {
-                    return false;
-                }
-            }
+            (Tile::Nothing, Tile::BoxHalfLeft) => large_box_moveable(
+                &new_location_right,
+                &Coord {
+                    x: new_location_right.x + 1,
+                    y: new_location_right.y,
+                },
+                direction,
+                data,
+            ),
             (Tile::Nothing, Tile::BoxHalfRight) => {
                 panic!("right box side with no left is impossible");
             }
@@ -387,30 +311,20 @@ fn large_box_moveable(
                 panic!("two left box sides is impossible");
             }
             (Tile::BoxHalfLeft, Tile::BoxHalfRight) => {
-                if large_box_moveable(&new_location_left, &new_location_right, direction, data) {
-                    return true;
-                } else {
-                    return false;
-                }
-            }
-            (Tile::BoxHalfRight, Tile::Nothing) => {
-                if large_box_moveable(
-                    &Coord {
-                        x: new_location_left.x - 1,
-                        y: new_location_left.y,
-                    },
-                    &new_location_left,
-                    direction,
-                    data,
-                ) {
-                    return true;
-                } else {
-                    return false;
-                }
-            }
+                large_box_moveable(&new_location_left, &new_location_right, direction, data)
+            }
+            (Tile::BoxHalfRight, Tile::Nothing) => large_box_moveable(
+                &Coord {
+                    x: new_location_left.x - 1,
+                    y: new_location_left.y,
+                },
+                &new_location_left,
+                direction,
+                data,
+            ),
             (Tile::BoxHalfRight, Tile::BoxHalfLeft) => {
                 //two box situation
-                if large_box_moveable(
+                large_box_moveable(
                     &Coord {
                         x: new_location_left.x - 1,
                         y: new_location_left.y,
@@ -426,11 +340,7 @@ fn large_box_moveable(
                     },
                     direction,
                     data,
-                ) {
-                    return true;
-                } else {
-                    return false;
-                }
+                )
             }
             (Tile::BoxHalfRight, Tile::BoxHalfRight) => {
                 panic!("two right sides is impossible");
@@ -438,15 +348,11 @@ fn large_box_moveable(
         }
     } else {
         //box is moving right or left
-        if *direction == Direction::Right {
+        if *direction == Direction::East {
             // right
-            match data.map[new_location_right.y][new_location_right.x] {
-                Tile::Nothing => {
-                    return true;
-                }
-                Tile::Wall => {
-                    return false;
-                }
+            match data.map[new_location_right] {
+                Tile::Nothing => true,
+                Tile::Wall => false,
                 Tile::Box => {
                     panic!("cannot move small boxes here");
                 }
@@ -455,7 +361,7 @@ fn large_box_moveable(
                 }
                 Tile::BoxHalfLeft => {
                     // recursively call move_large_box
-                    return large_box_moveable(
+                    large_box_moveable(
                         &new_location_right,
                         &Coord {
                             x: new_location_right.x + 1,
@@ -463,7 +369,7 @@ fn large_box_moveable(
                         },
                         direction,
                         data,
-                    );
+                    )
                 }
                 Tile::BoxHalfRight => {
                     panic!("ran into a right half with no left while moving right");
@@ -471,13 +377,9 @@ fn large_box_moveable(
             }
         } else {
             // left
-            match data.map[new_location_left.y][new_location_left.x] {
-                Tile::Nothing => {
-                    return true;
-                }
-                Tile::Wall => {
-                    return false;
-                }
+            match data.map[new_location_left] {
+                Tile::Nothing => true,
+                Tile::Wall => false,
                 Tile::Box => {
                     panic!("cannot move small boxes here");
                 }
@@ -489,7 +391,7 @@ fn large_box_moveable(
                 }
                 Tile::BoxHalfRight => {
                     // recursively call move_large_box
-                    return large_box_moveable(
+                    large_box_moveable(
                         &Coord {
                             x: new_location_left.x - 1,
                             y: new_location_left.y,
@@ -497,7 +399,7 @@ fn large_box_moveable(
                         &new_location_left,
                         direction,
                         data,
-                    );
+                    )
                 }
             }
         }
@@ -511,28 +413,25 @@ fn move_large_box(
     data: &mut MapData,
 ) {
     //validate locations
-    if *location_right != location_left.add(Direction::Right) {
+    if *location_right != location_left.step(Direction::East).unwrap() {
         panic!(
             "box is not a full box somehow??? left: {location_left:?}, right: {location_right:?}, direction: {direction:?}"
         );
     }
-    if data.map[location_left.y][location_left.x] != Tile::BoxHalfLeft {
+    if data.map[*location_left] != Tile::BoxHalfLeft {
         panic!(
             "left side is not a left half box: left: {location_left:?}, right: {location_right:?}, direction: {direction:?}"
         )
     }
-    if data.map[location_right.y][location_right.x] != Tile::BoxHalfRight {
+    if data.map[*location_right] != Tile::BoxHalfRight {
         panic!(
         "right side is not a right half box: left: {location_left:?}, right: {location_right:?}, direction: {direction:?}"
     )
     }
-    let new_location_left = location_left.add(*direction);
-    let new_location_right = location_right.add(*direction);
-    if *direction == Direction::Up || *direction == Direction::Down {
-        match (
-            data.map[new_location_left.y][new_location_left.x],
-            data.map[new_location_right.y][new_location_right.x],
-        ) {
+    let new_location_left = location_left.step(*direction).unwrap();
+    let new_location_right = location_right.step(*direction).unwrap();
+    if direction.is_vertical() {
+        match (data.map[new_location_left], data.map[new_location_right]) {
             (Tile::Box, _) => {
                 panic!("cannot move small boxes");
             }
@@ -552,10 +451,10 @@ fn move_large_box(
                 panic!("ran into robot right");
             }
             (Tile::Nothing, Tile::Nothing) => {
-                data.map[location_left.y][location_left.x] = Tile::Nothing;
-                data.map[location_right.y][location_right.x] = Tile::Nothing;
-                data.map[new_location_left.y][new_location_left.x] = Tile::BoxHalfLeft;
-                data.map[new_location_right.y][new_location_right.x] = Tile::BoxHalfRight;
+                data.map[*location_left] = Tile::Nothing;
+                data.map[*location_right] = Tile::Nothing;
+                data.map[new_location_left] = Tile::BoxHalfLeft;
+                data.map[new_location_right] = Tile::BoxHalfRight;
             }
             (Tile::Nothing, Tile::BoxHalfLeft) => {
                 move_large_box(
@@ -567,10 +466,10 @@ fn move_large_box(
                     direction,
                     data,
                 );
-                data.map[location_left.y][location_left.x] = Tile::Nothing;
-                data.map[location_right.y][location_right.x] = Tile::Nothing;
-                data.map[new_location_left.y][new_location_left.x] = Tile::BoxHalfLeft;
-                data.map[new_location_right.y][new_location_right.x] = Tile::BoxHalfRight;
+                data.map[*location_left] = Tile::Nothing;
+                data.map[*location_right] = Tile::Nothing;
+                data.map[new_location_left] = Tile::BoxHalfLeft;
+                data.map[new_location_right] = Tile::BoxHalfRight;
             }
             (Tile::Nothing, Tile::BoxHalfRight) => {
                 panic!("right box side with no left is impossible");
@@ -583,10 +482,10 @@ fn move_large_box(
             }
             (Tile::BoxHalfLeft, Tile::BoxHalfRight) => {
                 move_large_box(&new_location_left, &new_location_right, direction, data);
-                data.map[location_left.y][location_left.x] = Tile::Nothing;
-                data.map[location_right.y][location_right.x] = Tile::Nothing;
-                data.map[new_location_left.y][new_location_left.x] = Tile::BoxHalfLeft;
-                data.map[new_location_right.y][new_location_right.x] = Tile::BoxHalfRight;
+                data.map[*location_left] = Tile::Nothing;
+                data.map[*location_right] = Tile::Nothing;
+                data.map[new_location_left] = Tile::BoxHalfLeft;
+                data.map[new_location_right] = Tile::BoxHalfRight;
             }
             (Tile::BoxHalfRight, Tile::Nothing) => {
                 move_large_box(
@@ -598,10 +497,10 @@ fn move_large_box(
                     direction,
                     data,
                 );
-                data.map[location_left.y][location_left.x] = Tile::Nothing;
-                data.map[location_right.y][location_right.x] = Tile::Nothing;
-                data.map[new_location_left.y][new_location_left.x] = Tile::BoxHalfLeft;
-                data.map[new_location_right.y][new_location_right.x] = Tile::BoxHalfRight;
+                data.map[*location_left] = Tile::Nothing;
+                data.map[*location_right] = Tile::Nothing;
+                data.map[new_location_left] = Tile::BoxHalfLeft;
+                data.map[new_location_right] = Tile::BoxHalfRight;
             }
             (Tile::BoxHalfRight, Tile::BoxHalfLeft) => {
                 move_large_box(
@@ -622,10 +521,10 @@ fn move_large_box(
                     direction,
                     data,
                 );
-                data.map[location_left.y][location_left.x] = Tile::Nothing;
-                data.map[location_right.y][location_right.x] = Tile::Nothing;
-                data.map[new_location_left.y][new_location_left.x] = Tile::BoxHalfLeft;
-                data.map[new_location_right.y][new_location_right.x] = Tile::BoxHalfRight;
+                data.map[*location_left] = Tile::Nothing;
+                data.map[*location_right] = Tile::Nothing;
+                data.map[new_location_left] = Tile::BoxHalfLeft;
+                data.map[new_location_right] = Tile::BoxHalfRight;
             }
             (Tile::BoxHalfRight, Tile::BoxHalfRight) => {
                 panic!("two right sides is impossible");
@@ -633,15 +532,15 @@ fn move_large_box(
         }
     } else {
         //box is moving right or left
-        if *direction == Direction::Right {
+        if *direction == Direction::East {
             // right
-            match data.map[new_location_right.y][new_location_right.x] {
+            match data.map[new_location_right] {
                 Tile::Nothing => {
                     //successful move
-                    data.map[location_left.y][location_left.x] = Tile::Nothing;
-                    data.map[location_right.y][location_right.x] = Tile::Nothing;
-                    data.map[new_location_left.y][new_location_left.x] = Tile::BoxHalfLeft;
-                    data.map[new_location_right.y][new_location_right.x] = Tile::BoxHalfRight;
+                    data.map[*location_left] = Tile::Nothing;
+                    data.map[*location_right] = Tile::Nothing;
+                    data.map[new_location_left] = Tile::BoxHalfLeft;
+                    data.map[new_location_right] = Tile::BoxHalfRight;
                 }
                 Tile::Wall => {
                     panic!("cannot move into wall");
@@ -663,10 +562,10 @@ fn move_large_box(
                         direction,
                         data,
                     );
-                    data.map[location_left.y][location_left.x] = Tile::Nothing;
-                    data.map[location_right.y][location_right.x] = Tile::Nothing;
-                    data.map[new_location_left.y][new_location_left.x] = Tile::BoxHalfLeft;
-                    data.map[new_location_right.y][new_location_right.x] = Tile::BoxHalfRight;
+                    data.map[*location_left] = Tile::Nothing;
+                    data.map[*location_right] = Tile::Nothing;
+                    data.map[new_location_left] = Tile::BoxHalfLeft;
+                    data.map[new_location_right] = Tile::BoxHalfRight;
                 }
                 Tile::BoxHalfRight => {
                     panic!("ran into a right half with no left while moving right");
@@ -674,12 +573,12 @@ fn move_large_box(
             }
         } else {
             // left
-            match data.map[new_location_left.y][new_location_left.x] {
+            match data.map[new_location_left] {
                 Tile::Nothing => {
                     //successful move
-                    data.map[location_left.y][location_left.x] = Tile::Nothing;
-                    data.map[new_location_left.y][new_location_left.x] = Tile::BoxHalfLeft;
-                    data.map[new_location_right.y][new_location_right.x] = Tile::BoxHalfRight;
+                    data.map[*location_left] = Tile::Nothing;
+                    data.map[new_location_left] = Tile::BoxHalfLeft;
+                    data.map[new_location_right] = Tile::BoxHalfRight;
                 }
                 Tile::Wall => {
                     panic!("cannot move into wall");
@@ -704,9 +603,9 @@ fn move_large_box(
                         direction,
                         data,
                     );
-                    data.map[location_right.y][location_right.x] = Tile::Nothing;
-                    data.map[new_location_left.y][new_location_left.x] = Tile::BoxHalfLeft;
-                    data.map[new_location_right.y][new_location_right.x] = Tile::BoxHalfRight;
+                    data.map[*location_right] = Tile::Nothing;
+                    data.map[new_location_left] = Tile::BoxHalfLeft;
+                    data.map[new_location_right] = Tile::BoxHalfRight;
                 }
             }
         }
@@ -720,12 +619,12 @@ fn run_all_big_movements(data: MapData) -> MapData {
         move_list: Vec::new(),
     };
     for direction in data.move_list {
-        let Coord { x: try_x, y: try_y } = new_data.robot_pos.add(direction);
-        match new_data.map[try_y][try_x] {
+        let try_pos = new_data.robot_pos.step(direction).unwrap();
+        match new_data.map[try_pos] {
             Tile::Nothing => {
-                new_data.map[new_data.robot_pos.y][new_data.robot_pos.x] = Tile::Nothing;
-                new_data.map[try_y][try_x] = Tile::Robot;
-                new_data.robot_pos = Coord { x: try_x, y: try_y };
+                new_data.map[new_data.robot_pos] = Tile::Nothing;
+                new_data.map[try_pos] = Tile::Robot;
+                new_data.robot_pos = try_pos;
             }
             Tile::Wall => {
                 continue;
@@ -733,27 +632,27 @@ fn run_all_big_movements(data: MapData) -> MapData {
             Tile::Box => {
                 panic!("should not have small boxes in this function");
             }
-            Tile::Robot => panic!("robot ran into itself at ({try_x},{try_y})"),
+            Tile::Robot => panic!("robot ran into itself at ({},{})", try_pos.x, try_pos.y),
             Tile::BoxHalfLeft => {
-                let left_side = Coord { x: try_x, y: try_y };
-                let right_side = left_side.add(Direction::Right);
+                let left_side = try_pos;
+                let right_side = left_side.step(Direction::East).unwrap();
                 if large_box_moveable(&left_side, &right_side, &direction, &new_data) {
                     move_large_box(&left_side, &right_side, &direction, &mut new_data);
-                    new_data.map[new_data.robot_pos.y][new_data.robot_pos.x] = Tile::Nothing;
-                    new_data.map[try_y][try_x] = Tile::Robot;
-                    new_data.robot_pos = Coord { x: try_x, y: try_y };
+                    new_data.map[new_data.robot_pos] = Tile::Nothing;
+                    new_data.map[try_pos] = Tile::Robot;
+                    new_data.robot_pos = try_pos;
                 } else {
                     continue;
                 }
             }
             Tile::BoxHalfRight => {
-                let right_side = Coord { x: try_x, y: try_y };
-                let left_side = right_side.add(Direction::Left);
+                let right_side = try_pos;
+                let left_side = right_side.step(Direction::West).unwrap();
                 if large_box_moveable(&left_side, &right_side, &direction, &new_data) {
                     move_large_box(&left_side, &right_side, &direction, &mut new_data);
-                    new_data.map[new_data.robot_pos.y][new_data.robot_pos.x] = Tile::Nothing;
-                    new_data.map[try_y][try_x] = Tile::Robot;
-                    new_data.robot_pos = Coord { x: try_x, y: try_y };
+                    new_data.map[new_data.robot_pos] = Tile::Nothing;
+                    new_data.map[try_pos] = Tile::Robot;
+                    new_data.robot_pos = try_pos;
                 } else {
                     continue;
                 }
@@ -764,11 +663,40 @@ fn run_all_big_movements(data: MapData) -> MapData {
     new_data
 }
 
-pub fn process_part2(input: &str) -> String {
-    let data = big_parse(input);
-    //print_map(&data);
-    let new_data = run_all_big_movements(data);
-    calculate_total(&new_data).to_string()
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let data = parse(input);
+        let new_data = run_all_small_movements(data);
+        //print_map(&new_data);
+        calculate_total(&new_data)
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let data = big_parse(input);
+        //print_map(&data);
+        let new_data = run_all_big_movements(data);
+        calculate_total(&new_data)
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day15::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day15::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -778,12 +706,12 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "2028");
+        assert_eq!(process_part1(file).unwrap(), "2028");
     }
     #[test]
     fn test_input2() {
         let file = include_str!("../test-input-2.txt");
-        assert_eq!(process_part1(file), "10092");
-        assert_eq!(process_part2(file), "9021")
+        assert_eq!(process_part1(file).unwrap(), "10092");
+        assert_eq!(process_part2(file).unwrap(), "9021")
     }
 }
@@ -1,6 +0,0 @@
-use day_17::process_part2_slow;
-
-fn main() {
-    let file = include_str!("../../input.txt");
-    println!("{}", process_part2_slow(file));
-}
@@ -0,0 +1,6 @@
+use aoc_2024_day_17::trace;
+
+fn main() {
+    let file = aoc_core::input::from_args(env!("CARGO_MANIFEST_DIR"));
+    aoc_core::print_or_exit(trace(&file).map(Into::into));
+}
@@ -0,0 +1,122 @@
+//! Finding the register A that makes a program print itself.
+//!
+//! This works for any program that is one loop ending in `jnz 0`, shifting
+//! `a` right by 3 once per pass and printing once per pass. The pass that
+//! prints output `i` then only sees `a >> 3i`, so the last output depends on
+//! the top 3 bits of A alone, the one before it on the top 6, and so on. A is
+//! built up 3 bits at a time from the top, checking the tail of the output at
+//! each step, and backing up when no 3 bits fit.
+
+use aoc_core::AocError;
+
+use crate::vm::{Instruction, Machine, Opcode, Program};
+
+fn count(instructions: &[Instruction], wanted: impl Fn(&Instruction) -> bool) -> usize {
+    instructions.iter().filter(|&i| wanted(i)).count()
+}
+
+/// Checks the program has the one loop shape the search relies on.
+fn check_shape(program: &Program) -> Result<(), AocError> {
+    let instructions = program.instructions()?;
+    let last = instructions.last();
+    if last.map(|i| (i.opcode, i.operand)) != Some((Opcode::Jnz, 0))
+        || count(&instructions, |i| i.opcode == Opcode::Jnz) != 1
+    {
+        return Err(AocError::unsupported(
+            "the program must be a single loop ending in jnz 0",
+        ));
+    }
+    if count(&instructions, |i| i.opcode == Opcode::Adv) != 1
+        || count(&instructions, |i| (i.opcode, i.operand) == (Opcode::Adv, 3)) != 1
+    {
+        return Err(AocError::unsupported(
+            "the loop must shift a right by 3 exactly once",
+        ));
+    }
+    if count(&instructions, |i| i.opcode == Opcode::Out) != 1 {
+        return Err(AocError::unsupported("the loop must output exactly once"));
+    }
+    if program.code().len() * 3 > 64 {
+        return Err(AocError::unsupported(
+            "the program is too long for a 64 bit register A",
+        ));
+    }
+    Ok(())
+}
+
+fn search(
+    program: &Program,
+    b: u64,
+    c: u64,
+    high: u64,
+    left: usize,
+) -> Result<Option<u64>, AocError> {
+    if left == 0 {
+        return Ok(Some(high));
+    }
+    let wanted = &program.code()[left - 1..];
+    for bits in 0..8 {
+        let a = (high << 3) | bits;
+        let mut machine = Machine::new(a, b, c);
+        machine.run(program)?;
+        if machine.output == wanted {
+            if let Some(found) = search(program, b, c, a, left - 1)? {
+                return Ok(Some(found));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// The smallest A that makes `program` output itself, with B and C starting
+/// at `b` and `c`. Every A the search can reach has the same number of 3 bit
+/// groups and they're tried smallest first from the top, so the first one
+/// found is the smallest.
+pub fn quine_a(program: &Program, b: u64, c: u64) -> Result<u64, AocError> {
+    check_shape(program)?;
+    search(program, b, c, 0, program.code().len())?
+        .ok_or_else(|| AocError::solver("no value of A outputs the program"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outputs_itself(code: &[u8]) -> u64 {
+        let program = Program::new(code.to_vec()).unwrap();
+        let a = quine_a(&program, 0, 0).unwrap();
+        let mut machine = Machine::new(a, 0, 0);
+        machine.run(&program).unwrap();
+        assert_eq!(machine.output, code);
+        a
+    }
+
+    #[test]
+    fn test_quine_a() {
+        let example = [0, 3, 5, 4, 3, 0];
+        assert_eq!(outputs_itself(&example), 117440);
+        let program = Program::new(example.to_vec()).unwrap();
+        let smaller = (0..117440).find(|&a| {
+            let mut machine = Machine::new(a, 0, 0);
+            machine.run(&program).unwrap();
+            machine.output == example
+        });
+        assert_eq!(smaller, None);
+
+        // the same shape as the puzzle input, with other constants and order
+        assert_eq!(
+            outputs_itself(&[2, 4, 1, 2, 7, 5, 4, 5, 0, 3, 1, 7, 5, 5, 3, 0]),
+            190384615275535
+        );
+        assert_eq!(
+            outputs_itself(&[2, 4, 1, 3, 7, 5, 0, 3, 1, 5, 4, 4, 5, 5, 3, 0]),
+            236539226447469
+        );
+
+        let shifts_by_one = Program::new(vec![0, 1, 5, 4, 3, 0]).unwrap();
+        assert!(matches!(
+            quine_a(&shifts_by_one, 0, 0),
+            Err(AocError::Unsupported(_))
+        ));
+    }
+}
@@ -0,0 +1,331 @@
+//! The 3-bit computer: a program of 3-bit values read as opcode/operand
+//! pairs, three registers and an output tape.
+
+use std::fmt;
+
+use aoc_core::AocError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    const ALL: [Opcode; 8] = [
+        Opcode::Adv,
+        Opcode::Bxl,
+        Opcode::Bst,
+        Opcode::Jnz,
+        Opcode::Bxc,
+        Opcode::Out,
+        Opcode::Bdv,
+        Opcode::Cdv,
+    ];
+
+    /// Whether the operand names a register (4-6) rather than being a number.
+    fn takes_combo(self) -> bool {
+        matches!(
+            self,
+            Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv
+        )
+    }
+
+    fn mnemonic(self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+}
+
+/// One opcode and its operand, checked so it can always be executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operand: u8,
+}
+
+impl Instruction {
+    pub fn decode(opcode: u8, operand: u8) -> Result<Instruction, AocError> {
+        let opcode = *Opcode::ALL
+            .get(usize::from(opcode))
+            .ok_or_else(|| AocError::unsupported(format!("invalid opcode {opcode}")))?;
+        if operand > 7 || (opcode.takes_combo() && operand == 7) {
+            return Err(AocError::unsupported(format!(
+                "invalid operand {operand} for {}",
+                opcode.mnemonic()
+            )));
+        }
+        Ok(Instruction { opcode, operand })
+    }
+
+    /// How the combo operand reads in pseudo-code.
+    fn combo_name(self) -> String {
+        match self.operand {
+            4 => "a".to_string(),
+            5 => "b".to_string(),
+            6 => "c".to_string(),
+            literal => literal.to_string(),
+        }
+    }
+
+    /// What the instruction does, written out, e.g. `c = a >> 5`.
+    pub fn pseudo_code(self) -> String {
+        let combo = self.combo_name();
+        let operand = self.operand;
+        match self.opcode {
+            Opcode::Adv => format!("a = a >> {combo}"),
+            Opcode::Bxl => format!("b = b ^ {operand}"),
+            Opcode::Bst => format!("b = {combo} % 8"),
+            Opcode::Jnz => format!("if a != 0 goto {operand}"),
+            Opcode::Bxc => "b = b ^ c".to_string(),
+            Opcode::Out => format!("out {combo} % 8"),
+            Opcode::Bdv => format!("b = a >> {combo}"),
+            Opcode::Cdv => format!("c = a >> {combo}"),
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operand = if self.opcode.takes_combo() {
+            self.combo_name()
+        } else {
+            self.operand.to_string()
+        };
+        f.pad(&format!("{} {operand}", self.opcode.mnemonic()))
+    }
+}
+
+/// The raw program, which is also what a quine has to output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    code: Vec<u8>,
+}
+
+impl Program {
+    pub fn new(code: Vec<u8>) -> Result<Program, AocError> {
+        if let Some(value) = code.iter().find(|&&value| value > 7) {
+            return Err(AocError::unsupported(format!(
+                "{value} doesn't fit in 3 bits"
+            )));
+        }
+        if !code.len().is_multiple_of(2) {
+            return Err(AocError::unsupported(
+                "the program must have an even number of values",
+            ));
+        }
+        Ok(Program { code })
+    }
+
+    pub fn code(&self) -> &[u8] {
+        &self.code
+    }
+
+    /// The instruction starting at `ip`, if that's still inside the program.
+    pub fn fetch(&self, ip: usize) -> Option<Result<Instruction, AocError>> {
+        match self.code.get(ip..ip + 2)? {
+            &[opcode, operand] => Some(Instruction::decode(opcode, operand)),
+            _ => None,
+        }
+    }
+
+    /// The instructions at even addresses, which is where they are unless a
+    /// jump lands on an odd one.
+    pub fn instructions(&self) -> Result<Vec<Instruction>, AocError> {
+        self.code
+            .chunks(2)
+            .map(|pair| Instruction::decode(pair[0], pair[1]))
+            .collect()
+    }
+
+    /// One line per instruction: its address, the instruction, and what it
+    /// does.
+    pub fn disassemble(&self) -> Result<String, AocError> {
+        Ok(self
+            .instructions()?
+            .iter()
+            .enumerate()
+            .map(|(i, instruction)| {
+                format!(
+                    "{:>3}  {instruction:<6} {}",
+                    i * 2,
+                    instruction.pseudo_code()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Machine {
+    pub ip: usize,
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+    pub output: Vec<u8>,
+}
+
+impl Machine {
+    pub fn new(a: u64, b: u64, c: u64) -> Machine {
+        Machine {
+            ip: 0,
+            a,
+            b,
+            c,
+            output: vec![],
+        }
+    }
+
+    fn combo(&self, operand: u8) -> u64 {
+        match operand {
+            4 => self.a,
+            5 => self.b,
+            6 => self.c,
+            literal => u64::from(literal),
+        }
+    }
+
+    /// `a` shifted right by a combo operand, for adv, bdv and cdv.
+    fn divide(&self, operand: u8) -> u64 {
+        u32::try_from(self.combo(operand))
+            .ok()
+            .and_then(|shift| self.a.checked_shr(shift))
+            .unwrap_or(0)
+    }
+
+    pub fn execute(&mut self, instruction: Instruction) {
+        let operand = instruction.operand;
+        self.ip += 2;
+        match instruction.opcode {
+            Opcode::Adv => self.a = self.divide(operand),
+            Opcode::Bxl => self.b ^= u64::from(operand),
+            Opcode::Bst => self.b = self.combo(operand) % 8,
+            Opcode::Jnz => {
+                if self.a != 0 {
+                    self.ip = usize::from(operand);
+                }
+            }
+            Opcode::Bxc => self.b ^= self.c,
+            Opcode::Out => self.output.push((self.combo(operand) % 8) as u8),
+            Opcode::Bdv => self.b = self.divide(operand),
+            Opcode::Cdv => self.c = self.divide(operand),
+        }
+    }
+
+    /// Runs one instruction, or returns `false` once the machine has halted.
+    pub fn step(&mut self, program: &Program) -> Result<bool, AocError> {
+        match program.fetch(self.ip) {
+            Some(instruction) => {
+                self.execute(instruction?);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    pub fn run(&mut self, program: &Program) -> Result<(), AocError> {
+        self.trace(program, |_, _| {})
+    }
+
+    /// Runs until the machine halts, showing `tracer` the machine and the
+    /// instruction about to run before each step.
+    pub fn trace(
+        &mut self,
+        program: &Program,
+        mut tracer: impl FnMut(&Machine, Instruction),
+    ) -> Result<(), AocError> {
+        while let Some(instruction) = program.fetch(self.ip) {
+            let instruction = instruction?;
+            tracer(self, instruction);
+            self.execute(instruction);
+        }
+        Ok(())
+    }
+
+    /// The output as the puzzle wants it, comma separated.
+    pub fn output_text(&self) -> String {
+        self.output
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(mut machine: Machine, code: &[u8]) -> Machine {
+        machine.run(&Program::new(code.to_vec()).unwrap()).unwrap();
+        machine
+    }
+
+    #[test]
+    fn test_machine() {
+        assert_eq!(run(Machine::new(0, 0, 9), &[2, 6]).b, 1);
+        assert_eq!(
+            run(Machine::new(10, 0, 0), &[5, 0, 5, 1, 5, 4]).output,
+            [0, 1, 2]
+        );
+        let machine = run(Machine::new(2024, 0, 0), &[0, 1, 5, 4, 3, 0]);
+        assert_eq!(machine.output_text(), "4,2,5,6,7,7,7,7,3,1,0");
+        assert_eq!(machine.a, 0);
+        assert_eq!(run(Machine::new(0, 29, 0), &[1, 7]).b, 26);
+        assert_eq!(run(Machine::new(0, 2024, 43690), &[4, 0]).b, 44354);
+
+        let bad = Program::new(vec![5, 7]).unwrap();
+        assert!(Machine::new(0, 0, 0).run(&bad).is_err());
+        assert!(Program::new(vec![0, 8]).is_err());
+    }
+
+    #[test]
+    fn test_disassemble_and_trace() {
+        let program = Program::new(vec![2, 4, 1, 1, 7, 5, 0, 3, 4, 3, 1, 6, 5, 5, 3, 0]).unwrap();
+        assert_eq!(
+            program.disassemble().unwrap(),
+            "  0  bst a  b = a % 8
+  2  bxl 1  b = b ^ 1
+  4  cdv b  c = a >> b
+  6  adv 3  a = a >> 3
+  8  bxc 3  b = b ^ c
+ 10  bxl 6  b = b ^ 6
+ 12  out b  out b % 8
+ 14  jnz 0  if a != 0 goto 0"
+        );
+
+        let mut steps = vec![];
+        let mut machine = Machine::new(2024, 0, 0);
+        let countdown = Program::new(vec![0, 3, 5, 4, 3, 0]).unwrap();
+        machine
+            .trace(&countdown, |machine, instruction| {
+                steps.push((machine.ip, machine.a, instruction.to_string()))
+            })
+            .unwrap();
+        assert_eq!(steps.len(), 12);
+        assert_eq!(
+            steps[..3],
+            [
+                (0, 2024, "adv 3".to_string()),
+                (2, 253, "out a".to_string()),
+                (4, 253, "jnz 0".to_string())
+            ]
+        );
+        assert_eq!(machine.output_text(), "5,7,3,0");
+    }
+}
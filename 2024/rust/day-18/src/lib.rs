@@ -1,11 +1,8 @@
+use aoc_core::{Answer, AocError, Solution};
+use aoc_grid::{Coord, Grid};
+use aoc_search::astar;
 use std::collections::HashMap;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Coord {
-    x: usize,
-    y: usize,
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Space {
     Safe,
@@ -13,11 +10,11 @@ enum Space {
 }
 
 fn corrupt_squares(
-    incoming: &Vec<Coord>,
+    incoming: &[Coord],
     incoming_limit: usize,
     width_max: usize,
     height_max: usize,
-) -> (Vec<Vec<Space>>, HashMap<Coord, Space>) {
+) -> (Grid<Space>, HashMap<Coord, Space>) {
     if incoming_limit > incoming.len() {
         panic!("not enough bytes incoming");
     }
@@ -25,17 +22,9 @@ fn corrupt_squares(
     for i in 0..incoming_limit {
         bytes.insert(incoming[i], Space::Corrupted);
     }
-    let mut res: Vec<Vec<Space>> = Vec::with_capacity(height_max + 1);
-    for y in 0..=height_max {
-        let mut row: Vec<Space> = Vec::with_capacity(width_max + 1);
-        for x in 0..=width_max {
-            let square_type = match bytes.get(&Coord { x, y }) {
-                Some(x) => *x,
-                None => Space::Safe,
-            };
-            row.push(square_type);
-        }
-        res.push(row);
+    let mut res = Grid::new(width_max + 1, height_max + 1, Space::Safe);
+    for (coord, space) in &bytes {
+        res[*coord] = *space;
     }
     (res, bytes)
 }
@@ -59,11 +48,12 @@ fn parse(input: &str) -> Vec<Coord> {
     res
 }
 
-fn print_map(vec_map: &Vec<Vec<Space>>) {
+#[allow(dead_code)]
+fn print_map(vec_map: &Grid<Space>) {
     println!("current map:");
-    for y in 0..vec_map.len() {
-        for x in 0..vec_map[0].len() {
-            match vec_map[y][x] {
+    for row in vec_map.rows() {
+        for space in row {
+            match space {
                 Space::Safe => {
                     print!(".")
                 }
@@ -76,92 +66,70 @@ fn print_map(vec_map: &Vec<Vec<Space>>) {
     }
 }
 
-fn flood_with_breadcrumbs(
-    vec_map: &Vec<Vec<Space>>,
-    current: &Coord,
-    cost: usize,
-    flood_map: Option<HashMap<Coord, usize>>,
-) -> HashMap<Coord, usize> {
-    let mut flood_map = match flood_map {
-        Some(m) => m,
-        None => HashMap::new(),
-    };
-    match vec_map[current.y][current.x] {
-        Space::Safe => {
-            //keep going
-        }
-        Space::Corrupted => return flood_map,
-    };
-    match flood_map.get(current) {
-        Some(val) => {
-            if cost < *val {
-                flood_map.insert(*current, cost);
-            } else {
-                // another route was cheaper
-                return flood_map;
-            }
-        }
-        None => {
-            flood_map.insert(*current, cost);
-        }
-    }
-    // try up
-    if current.y > 0 {
-        let up = Coord {
-            x: current.x,
-            y: current.y - 1,
-        };
-        flood_map = flood_with_breadcrumbs(vec_map, &up, cost + 1, Some(flood_map));
-    }
-    // try down
-    if current.y < vec_map.len() - 1 {
-        let down = Coord {
-            x: current.x,
-            y: current.y + 1,
-        };
-        flood_map = flood_with_breadcrumbs(vec_map, &down, cost + 1, Some(flood_map));
+/// The fewest steps from `start` to `end` through safe squares, if there is
+/// a way through at all.
+fn steps_to_exit(vec_map: &Grid<Space>, start: Coord, end: Coord) -> Option<usize> {
+    if vec_map[start] == Space::Corrupted {
+        return None;
     }
-    // try left
-    if current.x > 0 {
-        let left = Coord {
-            x: current.x - 1,
-            y: current.y,
-        };
-        flood_map = flood_with_breadcrumbs(vec_map, &left, cost + 1, Some(flood_map));
+    let (steps, _) = astar(
+        start,
+        |&current: &Coord| {
+            vec_map
+                .neighbours4(current)
+                .filter(|next| vec_map[*next] == Space::Safe)
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        },
+        |current| current.manhattan(end),
+        |current| *current == end,
+    )?;
+    Some(steps)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 18;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
     }
-    // try right
-    if current.x < vec_map[0].len() - 1 {
-        let right = Coord {
-            x: current.x + 1,
-            y: current.y,
-        };
-        flood_map = flood_with_breadcrumbs(vec_map, &right, cost + 1, Some(flood_map));
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let coords = parse(input);
+        let (vecs, _) = corrupt_squares(&coords, 1024, 70, 70);
+        //print_map(&vecs);
+        steps_to_exit(&vecs, Coord::new(0, 0), Coord::new(70, 70)).unwrap()
     }
 
-    flood_map
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let coords = parse(input);
+        // once the exit is cut off it stays cut off, so look for the first
+        // byte count that blocks it
+        let fall_counts: Vec<usize> = (1024..coords.len()).collect();
+        let reachable = fall_counts.partition_point(|&fall_count| {
+            let (vecs, _) = corrupt_squares(&coords, fall_count, 70, 70);
+            steps_to_exit(&vecs, Coord::new(0, 0), Coord::new(70, 70)).is_some()
+        });
+        if let Some(last_reachable) = reachable.checked_sub(1) {
+            let blocker = coords[fall_counts[last_reachable]];
+            return format!("{},{}", blocker.x, blocker.y);
+        }
+        "none found".to_string()
+    }
 }
 
-pub fn process_part1(input: &str) -> String {
-    let coords = parse(input);
-    let (vecs, _) = corrupt_squares(&coords, 1024, 70, 70);
-    //print_map(&vecs);
-    let flood_map = flood_with_breadcrumbs(&vecs, &Coord { x: 0, y: 0 }, 0, None);
-    flood_map.get(&Coord { x: 70, y: 70 }).unwrap().to_string()
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day18::solve_part1(input)
 }
 
-pub fn process_part2(input: &str) -> String {
-    let coords = parse(input);
-    for fall_count in (1024..coords.len()).rev() {
-        let (vecs, _) = corrupt_squares(&coords, fall_count, 70, 70);
-        let flood_map = flood_with_breadcrumbs(&vecs, &Coord { x: 0, y: 0 }, 0, None);
-        match flood_map.get(&Coord { x: 70, y: 70 }) {
-            Some(_) => return format!("{},{}", coords[fall_count].x, coords[fall_count].y),
-            None => {
-                continue;
-            }
-        }
-    }
-    "none found".to_string()
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day18::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -174,10 +142,9 @@ mod tests {
         let coords = parse(file);
         let (vecs, _) = corrupt_squares(&coords, 12, 6, 6);
         //print_map(&vecs);
-        let flood_map = flood_with_breadcrumbs(&vecs, &Coord { x: 0, y: 0 }, 0, None);
         assert_eq!(
-            flood_map.get(&Coord { x: 6, y: 6 }).unwrap().to_string(),
-            "22"
+            steps_to_exit(&vecs, Coord::new(0, 0), Coord::new(6, 6)),
+            Some(22)
         );
     }
     #[test]
@@ -186,8 +153,7 @@ mod tests {
         let coords = parse(file);
         for fall_count in (1024..coords.len()).rev() {
             let (vecs, _) = corrupt_squares(&coords, fall_count, 70, 70);
-            let flood_map = flood_with_breadcrumbs(&vecs, &Coord { x: 0, y: 0 }, 0, None);
-            match flood_map.get(&Coord { x: 70, y: 70 }) {
+            match steps_to_exit(&vecs, Coord::new(0, 0), Coord::new(70, 70)) {
                 Some(_) => {
                     assert_eq!(
                         format!("{},{}", coords[fall_count - 1].x, coords[fall_count - 1].y),
@@ -1,5 +1,9 @@
+use aoc_core::{Answer, AocError, Solution};
 use std::collections::HashMap;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 use nom::{
     branch::alt,
     bytes::complete::tag,
@@ -74,6 +78,7 @@ fn parse(input: &str) -> (Vec<Vec<Color>>, Vec<Vec<Color>>) {
     (vec_stripes, vec_requests)
 }
 
+#[allow(dead_code)]
 fn create_map(stripes: &Vec<Vec<Color>>) -> HashMap<(Color, usize), Vec<Vec<Color>>> {
     let mut map: HashMap<(Color, usize), Vec<Vec<Color>>> = HashMap::new();
     for stripe_group in stripes {
@@ -95,6 +100,7 @@ fn create_map(stripes: &Vec<Vec<Color>>) -> HashMap<(Color, usize), Vec<Vec<Colo
     map
 }
 
+#[allow(dead_code)]
 fn fit(req: &[Color], test: &[Color], req_indx: usize) -> bool {
     //println!("checking if {test:?} fits into {req:?} starting at {req_indx}");
     if req_indx + test.len() > req.len() {
@@ -115,6 +121,7 @@ fn fit(req: &[Color], test: &[Color], req_indx: usize) -> bool {
 }
 
 // not fast
+#[allow(dead_code)]
 fn check_build(
     req: &Vec<Color>,
     map: &HashMap<(Color, usize), Vec<Vec<Color>>>,
@@ -170,33 +177,21 @@ fn is_possible<'a>(
     if let Some(v) = map.get(&(request, stripes)) {
         return *v;
     }
-    let res = stripes
-        .iter()
-        .map(|stripe| {
-            if request.starts_with(stripe) {
-                let new_request = &request[stripe.len()..];
-                if new_request.is_empty() {
-                    return true;
-                }
-                is_possible(new_request, stripes, map)
-            } else {
-                false
+    let res = stripes.iter().any(|stripe| {
+        if request.starts_with(stripe) {
+            let new_request = &request[stripe.len()..];
+            if new_request.is_empty() {
+                return true;
             }
-        })
-        .any(|x| x);
+            is_possible(new_request, stripes, map)
+        } else {
+            false
+        }
+    });
     map.insert((request, stripes), res);
     res
 }
 
-pub fn process_part1(input: &str) -> String {
-    let (stripes, requests) = parse(input);
-    let count: usize = requests
-        .iter()
-        .filter(|req| is_possible(req, &stripes, &mut HashMap::new()))
-        .count();
-    count.to_string()
-}
-
 fn count_possible<'a>(
     request: &'a [Color],
     stripes: &'a [Vec<Color>],
@@ -223,13 +218,48 @@ fn count_possible<'a>(
     res
 }
 
-pub fn process_part2(input: &str) -> String {
-    let (stripes, requests) = parse(input);
-    let count: usize = requests
-        .iter()
-        .map(|req| count_possible(req, &stripes, &mut HashMap::new()))
-        .sum();
-    count.to_string()
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 19;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let (stripes, requests) = parse(input);
+        #[cfg(feature = "parallel")]
+        let requests = requests.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let requests = requests.iter();
+        requests
+            .filter(|req| is_possible(req, &stripes, &mut HashMap::new()))
+            .count()
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let (stripes, requests) = parse(input);
+        #[cfg(feature = "parallel")]
+        let requests = requests.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let requests = requests.iter();
+        requests
+            .map(|req| count_possible(req, &stripes, &mut HashMap::new()))
+            .sum()
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day19::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day19::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -239,7 +269,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "6");
-        assert_eq!(process_part2(file), "16");
+        assert_eq!(process_part1(file).unwrap(), "6");
+        assert_eq!(process_part2(file).unwrap(), "16");
     }
 }
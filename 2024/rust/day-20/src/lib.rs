@@ -1,4 +1,7 @@
-use std::collections::{HashMap, VecDeque};
+use aoc_core::{Answer, AocError, Solution};
+use aoc_grid::{Coord, Grid};
+use aoc_search::{bfs, Paths};
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Tile {
@@ -8,42 +11,37 @@ enum Tile {
     Wall,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Coord {
-    x: usize,
-    y: usize,
-}
+impl TryFrom<char> for Tile {
+    type Error = char;
 
-fn parse(input: &str) -> (Coord, Coord, Vec<Vec<Tile>>) {
-    let mut map: Vec<Vec<Tile>> = Vec::new();
-    let mut start: Coord = Coord { x: 0, y: 0 };
-    let mut end: Coord = Coord { x: 0, y: 0 };
-    for (y, line) in input.lines().enumerate() {
-        let mut map_line: Vec<Tile> = Vec::new();
-        for (x, ch) in line.chars().enumerate() {
-            let tile = match ch {
-                'S' => {
-                    start = Coord { x, y };
-                    Tile::Start
-                }
-                'E' => {
-                    end = Coord { x, y };
-                    Tile::End
-                }
-                '.' => Tile::Track,
-                '#' => Tile::Wall,
-                unknown => panic!("unknown character in parse: {unknown}"),
-            };
-            map_line.push(tile);
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            'S' => Ok(Tile::Start),
+            'E' => Ok(Tile::End),
+            '.' => Ok(Tile::Track),
+            '#' => Ok(Tile::Wall),
+            unknown => Err(unknown),
         }
-        map.push(map_line);
     }
+}
+
+fn parse(input: &str) -> (Coord, Coord, Grid<Tile>) {
+    let map: Grid<Tile> = input
+        .parse()
+        .unwrap_or_else(|e| panic!("unknown character in parse: {e}"));
+    let start = map
+        .position(|tile| *tile == Tile::Start)
+        .expect("there should be a start");
+    let end = map
+        .position(|tile| *tile == Tile::End)
+        .expect("there should be an end");
     (start, end, map)
 }
 
-fn print_map(input: &[Vec<Tile>]) {
+#[allow(dead_code)]
+fn print_map(input: &Grid<Tile>) {
     println!("current map:");
-    for y in input.iter() {
+    for y in input.rows() {
         for x in y.iter() {
             match x {
                 Tile::Start => {
@@ -64,276 +62,24 @@ fn print_map(input: &[Vec<Tile>]) {
     }
 }
 
-fn flood_fill_with_count(
-    map: &[Vec<Tile>],
-    position_queue: &mut VecDeque<(Coord, usize)>,
-    flood_map: &mut HashMap<Coord, usize>,
-) {
-    let mut position_cost_opt = position_queue.pop_front();
-    while position_cost_opt.is_some() {
-        let (position, current_cost) = position_cost_opt.unwrap();
-        if map[position.y][position.x] == Tile::Wall {
-            // cannot move here
-
-            position_cost_opt = position_queue.pop_front();
-            continue;
-        }
-        match flood_map.get_mut(&position) {
-            Some(old_cost) => {
-                if current_cost < *old_cost {
-                    // println!(
-                    //     "getting to ({},{}) is cheaper: {current_cost}",
-                    //     position.x, position.y
-                    // );
-                    *old_cost = current_cost;
-                } else {
-                    // already got here cheaper
-                    position_cost_opt = position_queue.pop_front();
-                    continue;
-                }
-            }
-            None => {
-                flood_map.insert(position, current_cost);
-            }
-        }
-        // try up
-        if position.y > 0 {
-            let new_position = Coord {
-                x: position.x,
-                y: position.y - 1,
-            };
-            // println!(
-            //     "adding ({},{}) to queue to be checked",
-            //     position.x, position.y
-            // );
-            position_queue.push_back((new_position, current_cost + 1));
-        }
-        // try down
-        if position.y < map.len() - 1 {
-            let new_position = Coord {
-                x: position.x,
-                y: position.y + 1,
-            };
-            // println!(
-            //     "adding ({},{}) to queue to be checked",
-            //     position.x, position.y
-            // );
-            position_queue.push_back((new_position, current_cost + 1));
-        }
-        // try left
-        if position.x > 0 {
-            let new_position = Coord {
-                x: position.x - 1,
-                y: position.y,
-            };
-            // println!(
-            //     "adding ({},{}) to queue to be checked",
-            //     position.x, position.y
-            // );
-            position_queue.push_back((new_position, current_cost + 1));
-        }
-        // try right
-        if position.x < map.len() - 1 {
-            let new_position = Coord {
-                x: position.x + 1,
-                y: position.y,
-            };
-            // println!(
-            //     "adding ({},{}) to queue to be checked",
-            //     position.x, position.y
-            // );
-            position_queue.push_back((new_position, current_cost + 1));
-        }
-        //println!("getting next location to check");
-        position_cost_opt = position_queue.pop_front();
-    }
-}
-
-fn reverse_flood(
-    map: &HashMap<Coord, usize>,
-    position_queue: &mut VecDeque<(Coord, usize)>,
-    track: &mut Vec<Coord>,
-) {
-    let mut position_cost_opt = position_queue.pop_front();
-    while position_cost_opt.is_some() {
-        let (position, current_cost) = position_cost_opt.unwrap();
-        match map.get(&position) {
-            Some(cost) => {
-                if current_cost == *cost {
-                    // correct
-                    if !track.contains(&position) {
-                        track.push(position);
-                    }
-                    if *cost == 0 {
-                        // we're done
-                        position_cost_opt = position_queue.pop_front();
-                        continue;
-                    }
-                } else {
-                    // wrong, stop
-                    position_cost_opt = position_queue.pop_front();
-                    continue;
-                }
-            }
-            None => {
-                position_cost_opt = position_queue.pop_front();
-                continue;
-            }
-        }
-        // try up
-        if position.y > 0 {
-            let new_position = Coord {
-                x: position.x,
-                y: position.y - 1,
-            };
-            position_queue.push_back((new_position, current_cost - 1));
-        }
-        // try down
-        if position.y < map.len() - 1 {
-            let new_position = Coord {
-                x: position.x,
-                y: position.y + 1,
-            };
-            position_queue.push_back((new_position, current_cost - 1));
-        }
-        // try left
-        if position.x > 0 {
-            let new_position = Coord {
-                x: position.x - 1,
-                y: position.y,
-            };
-            position_queue.push_back((new_position, current_cost - 1));
-        }
-        // try right
-        if position.x < map.len() - 1 {
-            let new_position = Coord {
-                x: position.x + 1,
-                y: position.y,
-            };
-            position_queue.push_back((new_position, current_cost - 1));
-        }
-        position_cost_opt = position_queue.pop_front();
-    }
-}
-
-fn generate_list_of_adjacent_walls(map: &[Vec<Tile>], middle_point: Coord) -> Vec<Coord> {
-    let mut tile_list: Vec<Coord> = Vec::with_capacity(8);
-    if middle_point.y > 0 && map[middle_point.y - 1][middle_point.x] == Tile::Wall {
-        tile_list.push(Coord {
-            x: middle_point.x,
-            y: middle_point.y - 1,
-        });
-    }
-    // try down
-    if middle_point.y < map.len() - 1 && map[middle_point.y + 1][middle_point.x] == Tile::Wall {
-        tile_list.push(Coord {
-            x: middle_point.x,
-            y: middle_point.y + 1,
-        });
-    }
-    // try left
-    if middle_point.x > 0 && map[middle_point.y][middle_point.x - 1] == Tile::Wall {
-        tile_list.push(Coord {
-            x: middle_point.x - 1,
-            y: middle_point.y,
-        });
-    }
-    // try right
-    if middle_point.x < map.len() - 1 && map[middle_point.y][middle_point.x + 1] == Tile::Wall {
-        tile_list.push(Coord {
-            x: middle_point.x + 1,
-            y: middle_point.y,
-        });
-    }
-    tile_list
+/// How far every bit of track is from `from` without cheating.
+fn race_costs(map: &Grid<Tile>, from: Coord) -> Paths<Coord, usize> {
+    bfs([from], |&position: &Coord| {
+        map.neighbours4(position)
+            .filter(|next| map[*next] != Tile::Wall)
+            .collect::<Vec<_>>()
+    })
 }
 
-pub fn process_part1(input: &str) -> String {
-    let (start, end, map) = parse(input);
-    //print_map(&map);
-    let mut flood_map = HashMap::new();
-    let mut queue = VecDeque::new();
-    queue.push_back((start, 0));
-    flood_fill_with_count(&map, &mut queue, &mut flood_map);
-    let end_cost = match flood_map.get(&end) {
-        Some(cost) => *cost,
-        None => panic!("end could not be reached by floodfill"),
-    };
-    //println!("floodfill done");
-    let mut normal_track: Vec<Coord> = Vec::new();
-    let mut rev_queue = VecDeque::new();
-    rev_queue.push_back((end, end_cost));
-    reverse_flood(&flood_map, &mut rev_queue, &mut normal_track);
-    //println!("track found");
-    let mut wall_list: Vec<Coord> = Vec::new();
-    for tile in normal_track {
-        for adj in generate_list_of_adjacent_walls(&map, tile).iter() {
-            if !wall_list.contains(adj) {
-                wall_list.push(*adj);
-            }
-        }
-    }
-    //println!("walls found");
-    let mut cheat_list: Vec<(Coord, usize)> = Vec::new();
-    for wall in wall_list {
-        let mut new_map = map.clone();
-        new_map[wall.y][wall.x] = Tile::Track;
-        let mut new_flood_map = HashMap::new();
-        let mut new_queue = VecDeque::new();
-        new_queue.push_back((start, 0));
-        flood_fill_with_count(&new_map, &mut new_queue, &mut new_flood_map);
-        //println!("wall ({},{}) processed", wall.x, wall.y);
-        let new_end_cost = match new_flood_map.get(&end) {
-            Some(cost) => *cost,
-            None => panic!("end could not be reached by floodfill"),
-        };
-        if new_end_cost < end_cost {
-            cheat_list.push((wall, new_end_cost));
-        }
-    }
-    //println!("all cheats found");
-    let mut best_cheat: usize = 0;
-    let mut cheat_sort: HashMap<usize, usize> = HashMap::new();
-    for cheat in cheat_list {
-        let _wall_position = cheat.0;
-        let save = end_cost - cheat.1;
-        if save > best_cheat {
-            best_cheat = save;
-        }
-        match cheat_sort.get_mut(&save) {
-            Some(count) => {
-                *count += 1;
-            }
-            None => {
-                cheat_sort.insert(save, 1);
-            }
-        }
-    }
-    //println!("all cheats counted");
-    let mut output_string = String::new();
-    let mut at_least_100: usize = 0;
-    for i in 1..=best_cheat {
-        if let Some(count) = cheat_sort.get(&i) {
-            if i >= 100 {
-                at_least_100 += *count;
-            }
-            if *count == 1 {
-                output_string =
-                    format!("{output_string}\nThere is one cheat that saves {i} picoseconds.");
-            } else {
-                output_string =
-                    format!("{output_string}\nThere are {count} cheats that save {i} picoseconds.");
-            }
-        }
-    }
-    output_string = format!("{output_string}\n{at_least_100}");
-    //println!("{output_string}");
-    output_string.to_string()
+fn generate_list_of_adjacent_walls(map: &Grid<Tile>, middle_point: Coord) -> Vec<Coord> {
+    map.neighbours4(middle_point)
+        .filter(|adjacent| map[*adjacent] == Tile::Wall)
+        .collect()
 }
 
 fn generate_phase_list(
-    map: &[Vec<Tile>],
-    track: &[Coord],
+    map: &Grid<Tile>,
+    track: &HashSet<Coord>,
     position: &Coord,
     length: usize,
 ) -> Vec<(Coord, usize)> {
@@ -354,23 +100,18 @@ fn generate_phase_list(
     // ..#####..
     // ...###...
     // ....#....
-    for (y, row) in map.iter().enumerate() {
-        for (x, tile) in row.iter().enumerate() {
-            if *tile != Tile::Wall && track.contains(&Coord { x, y }) {
+    let reach = length as isize;
+    for dy in -reach..=reach {
+        let across = reach - dy.abs();
+        for dx in -across..=across {
+            let Some(coord) = map.offset(*position, dx, dy) else {
+                continue;
+            };
+            if map[coord] != Tile::Wall && track.contains(&coord) {
                 //valid endpoint
-                let y_diff = if y > position.y {
-                    y - position.y
-                } else {
-                    position.y - y
-                };
-                let x_diff = if x > position.x {
-                    x - position.x
-                } else {
-                    position.x - x
-                };
-                let distance = y_diff + x_diff;
-                if distance <= length && distance > 1 {
-                    output.push((Coord { x, y }, distance));
+                let distance = coord.manhattan(*position);
+                if distance > 1 {
+                    output.push((coord, distance));
                 }
             }
         }
@@ -379,98 +120,191 @@ fn generate_phase_list(
     output
 }
 
-pub fn process_part2(input: &str) -> String {
-    let (start, end, map) = parse(input);
-    //print_map(&map);
-    let mut flood_map = HashMap::new();
-    let mut normal_queue = VecDeque::new();
-    normal_queue.push_back((start, 0));
-    flood_fill_with_count(&map, &mut normal_queue, &mut flood_map);
-    let end_cost = match flood_map.get(&end) {
-        Some(cost) => *cost,
-        None => panic!("end could not be reached by floodfill"),
-    };
-    //println!("non-cheat cost determined");
-    let mut normal_track: Vec<Coord> = Vec::new();
-    let mut rev_queue = VecDeque::new();
-    rev_queue.push_back((end, end_cost));
-    reverse_flood(&flood_map, &mut rev_queue, &mut normal_track);
-    //println!("track found, {} tiles", normal_track.len());
-    let mut cheat_total_map: HashMap<(Coord, Coord), (usize, usize)> = HashMap::new();
-    for start in normal_track.iter() {
-        let end_cheat_cost_list = generate_phase_list(&map, &normal_track, start, 20);
-        for (end, cheat_cost) in end_cheat_cost_list {
-            let current_cost = *flood_map
-                .get(start)
-                .expect("this should be in the flood map");
-            let new_cost = cheat_cost + current_cost;
-            let old_cost = *flood_map
-                .get(&end)
-                .expect("this should be in the flood map");
-            if old_cost < new_cost {
-                //not a save, go next
-                continue;
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 20;
+    type Parsed<'a> = &'a str;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let (start, end, map) = parse(input);
+        //print_map(&map);
+        let from_start = race_costs(&map, start);
+        let end_cost = from_start
+            .cost(&end)
+            .expect("end could not be reached by floodfill");
+        let from_end = race_costs(&map, end);
+        let normal_track = from_start.on_cheapest_paths([end]);
+        //println!("track found");
+        let mut wall_list: HashSet<Coord> = HashSet::new();
+        for tile in normal_track {
+            wall_list.extend(generate_list_of_adjacent_walls(&map, tile));
+        }
+        //println!("walls found");
+        let mut cheat_list: Vec<(Coord, usize)> = Vec::new();
+        for wall in wall_list {
+            // with only this wall gone the quickest route through it goes
+            // from one side of it to another
+            let sides: Vec<Coord> = map
+                .neighbours4(wall)
+                .filter(|side| map[*side] != Tile::Wall)
+                .collect();
+            let new_end_cost = sides
+                .iter()
+                .flat_map(|before| {
+                    sides.iter().filter_map(|after| {
+                        Some(from_start.cost(before)? + 2 + from_end.cost(after)?)
+                    })
+                })
+                .min();
+            if let Some(new_end_cost) = new_end_cost.filter(|cost| *cost < end_cost) {
+                cheat_list.push((wall, new_end_cost));
             }
-            let save = old_cost - new_cost;
-            let total_cost = end_cost - save;
-            match cheat_total_map.get_mut(&(*start, end)) {
-                Some((old_cheat_cost, old_savings)) => {
-                    if *old_cheat_cost < total_cost {
-                        continue;
-                    } else {
-                        *old_cheat_cost = total_cost;
-                        *old_savings = save;
-                    }
+        }
+        //println!("all cheats found");
+        let mut best_cheat: usize = 0;
+        let mut cheat_sort: HashMap<usize, usize> = HashMap::new();
+        for cheat in cheat_list {
+            let _wall_position = cheat.0;
+            let save = end_cost - cheat.1;
+            if save > best_cheat {
+                best_cheat = save;
+            }
+            match cheat_sort.get_mut(&save) {
+                Some(count) => {
+                    *count += 1;
                 }
                 None => {
-                    cheat_total_map.insert((*start, end), (total_cost, save));
+                    cheat_sort.insert(save, 1);
                 }
             }
         }
+        //println!("all cheats counted");
+        let mut output_string = String::new();
+        let mut at_least_100: usize = 0;
+        for i in 1..=best_cheat {
+            if let Some(count) = cheat_sort.get(&i) {
+                if i >= 100 {
+                    at_least_100 += *count;
+                }
+                if *count == 1 {
+                    output_string =
+                        format!("{output_string}\nThere is one cheat that saves {i} picoseconds.");
+                } else {
+                    output_string = format!(
+                        "{output_string}\nThere are {count} cheats that save {i} picoseconds."
+                    );
+                }
+            }
+        }
+        output_string = format!("{output_string}\n{at_least_100}");
+        //println!("{output_string}");
+        output_string
     }
-    // println!(
-    //     "total cheat list generated: {} entries",
-    //     cheat_total_map.len()
-    // );
-    let mut cheat_savings: HashMap<usize, usize> = HashMap::new();
-    // k.0 is the start
-    // k.1 is the end
-    // v.0 is the total cost
-    // v.1 are the savings
-    let mut best_cheat: usize = 0;
-    for ((_, _), (_, savings)) in cheat_total_map.iter() {
-        if *savings > best_cheat {
-            best_cheat = *savings;
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let (start, end, map) = parse(input);
+        //print_map(&map);
+        let from_start = race_costs(&map, start);
+        let end_cost = from_start
+            .cost(&end)
+            .expect("end could not be reached by floodfill");
+        //println!("non-cheat cost determined");
+        let normal_track = from_start.on_cheapest_paths([end]);
+        let flood_map = from_start.costs();
+        //println!("track found, {} tiles", normal_track.len());
+        let mut cheat_total_map: HashMap<(Coord, Coord), (usize, usize)> = HashMap::new();
+        for start in normal_track.iter() {
+            let end_cheat_cost_list = generate_phase_list(&map, &normal_track, start, 20);
+            for (end, cheat_cost) in end_cheat_cost_list {
+                let current_cost = *flood_map
+                    .get(start)
+                    .expect("this should be in the flood map");
+                let new_cost = cheat_cost + current_cost;
+                let old_cost = *flood_map
+                    .get(&end)
+                    .expect("this should be in the flood map");
+                if old_cost < new_cost {
+                    //not a save, go next
+                    continue;
+                }
+                let save = old_cost - new_cost;
+                let total_cost = end_cost - save;
+                match cheat_total_map.get_mut(&(*start, end)) {
+                    Some((old_cheat_cost, old_savings)) => {
+                        if *old_cheat_cost < total_cost {
+                            continue;
+                        } else {
+                            *old_cheat_cost = total_cost;
+                            *old_savings = save;
+                        }
+                    }
+                    None => {
+                        cheat_total_map.insert((*start, end), (total_cost, save));
+                    }
+                }
+            }
         }
-        match cheat_savings.get_mut(savings) {
-            Some(count) => {
-                *count += 1;
+        // println!(
+        //     "total cheat list generated: {} entries",
+        //     cheat_total_map.len()
+        // );
+        let mut cheat_savings: HashMap<usize, usize> = HashMap::new();
+        // k.0 is the start
+        // k.1 is the end
+        // v.0 is the total cost
+        // v.1 are the savings
+        let mut best_cheat: usize = 0;
+        for ((_, _), (_, savings)) in cheat_total_map.iter() {
+            if *savings > best_cheat {
+                best_cheat = *savings;
             }
-            None => {
-                cheat_savings.insert(*savings, 1);
+            match cheat_savings.get_mut(savings) {
+                Some(count) => {
+                    *count += 1;
+                }
+                None => {
+                    cheat_savings.insert(*savings, 1);
+                }
             }
         }
-    }
-    //println!("all cheats counted");
-    let mut output_string = String::new();
-    let mut at_least_100: usize = 0;
-    for i in 50..=best_cheat {
-        if let Some(count) = cheat_savings.get(&i) {
-            if i >= 100 {
-                at_least_100 += *count;
-            }
-            if *count == 1 {
-                output_string =
-                    format!("{output_string}\nThere is one cheat that saves {i} picoseconds.");
-            } else {
-                output_string =
-                    format!("{output_string}\nThere are {count} cheats that save {i} picoseconds.");
+        //println!("all cheats counted");
+        let mut output_string = String::new();
+        let mut at_least_100: usize = 0;
+        for i in 50..=best_cheat {
+            if let Some(count) = cheat_savings.get(&i) {
+                if i >= 100 {
+                    at_least_100 += *count;
+                }
+                if *count == 1 {
+                    output_string =
+                        format!("{output_string}\nThere is one cheat that saves {i} picoseconds.");
+                } else {
+                    output_string = format!(
+                        "{output_string}\nThere are {count} cheats that save {i} picoseconds."
+                    );
+                }
             }
         }
+        output_string = format!("{output_string}\n{at_least_100}");
+        //println!("{output_string}");
+        output_string
     }
-    output_string = format!("{output_string}\n{at_least_100}");
-    //println!("{output_string}");
-    output_string.to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day20::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day20::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -481,7 +315,7 @@ mod tests {
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
         assert_eq!(
-            process_part1(file),
+            process_part1(file).unwrap(),
             "
 There are 14 cheats that save 2 picoseconds.
 There are 14 cheats that save 4 picoseconds.
@@ -497,7 +331,7 @@ There is one cheat that saves 64 picoseconds.
 0"
         );
         assert_eq!(
-            process_part2(file),
+            process_part2(file).unwrap(),
             "
 There are 32 cheats that save 50 picoseconds.
 There are 31 cheats that save 52 picoseconds.
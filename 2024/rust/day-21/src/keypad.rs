@@ -0,0 +1,300 @@
+//! Keypads of any layout, and chains of robots each typing on the next
+//! keypad down with their arm steered from the keypad above.
+
+use std::collections::{HashMap, VecDeque};
+
+use aoc_core::AocError;
+
+/// The door's keypad.
+pub const NUMERIC: &str = "789\n456\n123\n 0A";
+/// The keypad that steers a robot's arm.
+pub const DIRECTIONAL: &str = " ^A\n<v>";
+
+/// The keys a directional keypad needs: one per direction, and `A` to press.
+const MOVES: [(char, (i32, i32)); 4] =
+    [('^', (0, -1)), ('v', (0, 1)), ('<', (-1, 0)), ('>', (1, 0))];
+
+/// A grid of keys, where spaces are gaps the arm must never be over. Every
+/// arm starts on `A`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keypad {
+    keys: HashMap<char, (i32, i32)>,
+    at: HashMap<(i32, i32), char>,
+}
+
+impl Keypad {
+    pub fn parse(layout: &str) -> Result<Keypad, AocError> {
+        let mut keypad = Keypad {
+            keys: HashMap::new(),
+            at: HashMap::new(),
+        };
+        for (y, row) in layout.lines().enumerate() {
+            for (x, key) in row.chars().enumerate() {
+                if key == ' ' {
+                    continue;
+                }
+                if keypad.keys.insert(key, (x as i32, y as i32)).is_some() {
+                    return Err(AocError::parse(
+                        y + 1,
+                        x + 1,
+                        format!("{key} is on the keypad twice"),
+                    ));
+                }
+                keypad.at.insert((x as i32, y as i32), key);
+            }
+        }
+        if !keypad.keys.contains_key(&'A') {
+            return Err(AocError::unsupported(
+                "a keypad needs an A key for the arm to start on",
+            ));
+        }
+        Ok(keypad)
+    }
+
+    pub fn numeric() -> Keypad {
+        Keypad::parse(NUMERIC).expect("the numeric keypad is valid")
+    }
+
+    pub fn directional() -> Keypad {
+        Keypad::parse(DIRECTIONAL).expect("the directional keypad is valid")
+    }
+
+    pub fn has_key(&self, key: char) -> bool {
+        self.keys.contains_key(&key)
+    }
+
+    /// Every shortest way for the arm to get from `from` to `to` over the
+    /// keys, as the directions to press, followed by the `A` that presses
+    /// `to`.
+    pub fn routes(&self, from: char, to: char) -> Vec<String> {
+        let (Some(&start), Some(&end)) = (self.keys.get(&from), self.keys.get(&to)) else {
+            return vec![];
+        };
+        // distances back from the end, so each route only takes steps that
+        // get closer to it
+        let mut distance = HashMap::from([(end, 0)]);
+        let mut queue = VecDeque::from([end]);
+        while let Some(position) = queue.pop_front() {
+            for (_, (dx, dy)) in MOVES {
+                let next = (position.0 + dx, position.1 + dy);
+                if self.at.contains_key(&next) && !distance.contains_key(&next) {
+                    distance.insert(next, distance[&position] + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        let mut routes = vec![];
+        if distance.contains_key(&start) {
+            self.extend_routes(start, &distance, &mut String::new(), &mut routes);
+        }
+        routes
+    }
+
+    fn extend_routes(
+        &self,
+        position: (i32, i32),
+        distance: &HashMap<(i32, i32), u32>,
+        route: &mut String,
+        routes: &mut Vec<String>,
+    ) {
+        if distance[&position] == 0 {
+            routes.push(format!("{route}A"));
+            return;
+        }
+        for (direction, (dx, dy)) in MOVES {
+            let next = (position.0 + dx, position.1 + dy);
+            if distance.get(&next) == Some(&(distance[&position] - 1)) {
+                route.push(direction);
+                self.extend_routes(next, distance, route, routes);
+                route.pop();
+            }
+        }
+    }
+
+    /// Where pressing `direction` moves the arm from `key`, if it's still
+    /// over a key.
+    fn step(&self, key: char, direction: char) -> Option<char> {
+        let (_, (dx, dy)) = MOVES.iter().find(|(d, _)| *d == direction)?;
+        let (x, y) = self.keys[&key];
+        self.at.get(&(x + dx, y + dy)).copied()
+    }
+}
+
+/// A door keypad typed on by a robot, whose arm is steered from a keypad
+/// that's typed on by the next robot, and so on up to the keypad a person
+/// presses.
+#[derive(Debug, Clone)]
+pub struct Chain {
+    /// the door's keypad first, the person's last
+    keypads: Vec<Keypad>,
+}
+
+impl Chain {
+    /// The door's keypad, then `robots` robots pressing copies of `remote`
+    /// before the person presses one.
+    pub fn new(door: Keypad, remote: Keypad, robots: usize) -> Result<Chain, AocError> {
+        if let Some((missing, _)) = MOVES
+            .iter()
+            .find(|(direction, _)| !remote.has_key(*direction))
+        {
+            return Err(AocError::unsupported(format!(
+                "the remote keypad has no {missing} key to steer with"
+            )));
+        }
+        let mut keypads = vec![door];
+        keypads.extend(std::iter::repeat_n(remote, robots + 1));
+        Ok(Chain { keypads })
+    }
+
+    pub fn door(&self) -> &Keypad {
+        &self.keypads[0]
+    }
+
+    /// The fewest presses the person needs to make for `code` to be typed on
+    /// the door, or `None` if some key can't be reached.
+    pub fn min_presses(&self, code: &str) -> Option<u64> {
+        let mut costs = Costs::new(self);
+        costs.keys(0, code)
+    }
+
+    /// One sequence the person could press to type `code` in
+    /// [`Chain::min_presses`] presses, if it isn't longer than `limit`. Chains
+    /// of more than a few robots quickly make it too long to write out.
+    pub fn press_sequence(&self, code: &str, limit: u64) -> Option<String> {
+        let mut costs = Costs::new(self);
+        if costs.keys(0, code)? > limit {
+            return None;
+        }
+        let mut presses = String::new();
+        costs.write(0, code, &mut presses);
+        Some(presses)
+    }
+
+    /// What the door receives when the person presses `presses`, or `None`
+    /// if an arm is ever steered off its keypad.
+    pub fn replay(&self, presses: &str) -> Option<String> {
+        let mut typed: String = presses.to_string();
+        for keypad in self.keypads.iter().rev().skip(1) {
+            let mut arm = 'A';
+            let mut output = String::new();
+            for press in typed.chars() {
+                if press == 'A' {
+                    output.push(arm);
+                } else {
+                    arm = keypad.step(arm, press)?;
+                }
+            }
+            typed = output;
+        }
+        Some(typed)
+    }
+}
+
+/// The cheapest way, counted in the person's presses, to move each keypad's
+/// arm between two keys and press the second, worked out as it's needed.
+struct Costs<'a> {
+    chain: &'a Chain,
+    cheapest: HashMap<(usize, char, char), Option<(u64, String)>>,
+}
+
+impl<'a> Costs<'a> {
+    fn new(chain: &'a Chain) -> Costs<'a> {
+        Costs {
+            chain,
+            cheapest: HashMap::new(),
+        }
+    }
+
+    /// The cheapest route on `level`'s keypad from `from` to pressing `to`,
+    /// and what it costs.
+    fn press(&mut self, level: usize, from: char, to: char) -> Option<(u64, String)> {
+        if level + 1 == self.chain.keypads.len() {
+            return self.chain.keypads[level]
+                .has_key(to)
+                .then(|| (1, to.to_string()));
+        }
+        if let Some(cheapest) = self.cheapest.get(&(level, from, to)) {
+            return cheapest.clone();
+        }
+        let cheapest = self.chain.keypads[level]
+            .routes(from, to)
+            .into_iter()
+            .filter_map(|route| Some((self.keys(level + 1, &route)?, route)))
+            .min_by_key(|(cost, _)| *cost);
+        self.cheapest.insert((level, from, to), cheapest.clone());
+        cheapest
+    }
+
+    /// What it costs to type `keys` on `level`'s keypad, starting from `A`.
+    fn keys(&mut self, level: usize, keys: &str) -> Option<u64> {
+        let mut arm = 'A';
+        let mut total = 0;
+        for key in keys.chars() {
+            total += self.press(level, arm, key)?.0;
+            arm = key;
+        }
+        Some(total)
+    }
+
+    /// Appends the person's presses for typing `keys` on `level`'s keypad.
+    fn write(&mut self, level: usize, keys: &str, presses: &mut String) {
+        if level + 1 == self.chain.keypads.len() {
+            presses.push_str(keys);
+            return;
+        }
+        let mut arm = 'A';
+        for key in keys.chars() {
+            let (_, route) = self.press(level, arm, key).expect("every key was costed");
+            self.write(level + 1, &route, presses);
+            arm = key;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_routes() {
+        let numeric = Keypad::numeric();
+        let mut routes = numeric.routes('A', '7');
+        routes.sort();
+        // anything starting left would pass over the gap
+        assert!(routes.iter().all(|route| !route.starts_with("<<")));
+        assert_eq!(routes.len(), 9);
+        assert_eq!(numeric.routes('5', '5'), ["A"]);
+        assert_eq!(Keypad::directional().routes('<', 'A'), [">^>A", ">>^A"]);
+
+        assert!(Keypad::parse("12\n3").is_err());
+        assert!(Keypad::parse("AA").is_err());
+    }
+
+    #[test]
+    fn test_chain() {
+        let chain = Chain::new(Keypad::numeric(), Keypad::directional(), 2).unwrap();
+        assert_eq!(chain.min_presses("029A"), Some(68));
+        let presses = chain.press_sequence("029A", 100).unwrap();
+        assert_eq!(presses.len(), 68);
+        assert_eq!(chain.replay(&presses).as_deref(), Some("029A"));
+        assert_eq!(chain.press_sequence("029A", 67), None);
+        assert_eq!(chain.min_presses("02B"), None);
+
+        // no robots between: the person types on the door's remote directly
+        let direct = Chain::new(Keypad::numeric(), Keypad::directional(), 0).unwrap();
+        assert_eq!(
+            direct.min_presses("029A"),
+            Some("<A^A>^^AvvvA".len() as u64)
+        );
+
+        // a remote with the directions in a line, and a door with a hole
+        let remote = Keypad::parse("<^Av>").unwrap();
+        let door = Keypad::parse("12\n A").unwrap();
+        let chain = Chain::new(door, remote, 3).unwrap();
+        let presses = chain.press_sequence("12A", 10_000).unwrap();
+        assert_eq!(chain.replay(&presses).as_deref(), Some("12A"));
+        assert_eq!(chain.min_presses("12A"), Some(presses.len() as u64));
+
+        assert!(Chain::new(Keypad::numeric(), Keypad::numeric(), 2).is_err());
+    }
+}
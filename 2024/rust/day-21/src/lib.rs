@@ -1,1100 +1,179 @@
-use std::{collections::HashMap, usize};
-
-use itertools::Itertools;
-use nom::{bytes::complete::tag, character::complete, sequence::terminated};
-
-// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-// enum Dir {
-//     U,
-//     D,
-//     L,
-//     R,
-// }
-
-// +---+---+---+
-// | 7 | 8 | 9 |
-// +---+---+---+
-// | 4 | 5 | 6 |
-// +---+---+---+
-// | 1 | 2 | 3 |
-// +---+---+---+
-//     | 0 | A |
-//     +---+---+
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum NPad {
-    Seven,
-    Eight,
-    Nine,
-    Four,
-    Five,
-    Six,
-    One,
-    Two,
-    Three,
-    Zero,
-    A,
-    Invalid,
+use aoc_core::{Answer, AocError, Solution};
+
+pub mod keypad;
+
+use keypad::{Chain, Keypad};
+
+/// Presses on the person's keypad, summed over every code, past which
+/// [`sequences`] stops writing them out.
+const SEQUENCE_LIMIT: u64 = 100_000;
+
+/// The door and remote keypads and the codes to type. The input is the codes
+/// one per line, optionally after `door:` and `remote:` sections giving
+/// other layouts, each a grid of keys with spaces for the gaps:
+///
+/// ```text
+/// door:
+/// 12
+///  A
+///
+/// 12A
+/// ```
+#[derive(Debug, Clone)]
+pub struct Doors {
+    door: Keypad,
+    remote: Keypad,
+    codes: Vec<String>,
 }
 
-//     +---+---+
-//     | ^ | A |
-// +---+---+---+
-// | < | v | > |
-// +---+---+---+
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum DPad {
-    U,
-    A,
-    L,
-    D,
-    R,
-    Invalid,
-}
-
-type Seq = Vec<DPad>;
-
-struct Coord {
-    x: usize,
-    y: usize,
-}
-
-const NMAP: [[NPad; 3]; 4] = [
-    [NPad::Seven, NPad::Eight, NPad::Nine],
-    [NPad::Four, NPad::Five, NPad::Six],
-    [NPad::One, NPad::Two, NPad::Three],
-    [NPad::Invalid, NPad::Zero, NPad::A],
-];
-
-const DMAP: [[DPad; 3]; 2] = [
-    [DPad::Invalid, DPad::U, DPad::A],
-    [DPad::L, DPad::D, DPad::R],
-];
-
-fn validate_dpad_path(start: DPad, end: DPad, inputs: &Vec<DPad>) -> bool {
-    let mut up = false;
-    let mut down = false;
-    let mut left = false;
-    let mut right = false;
-    let mut last_dir: Option<DPad> = None;
-    let mut current_coord = match start {
-        DPad::U => Coord { x: 1, y: 0 },
-        DPad::A => Coord { x: 2, y: 0 },
-        DPad::L => Coord { x: 0, y: 1 },
-        DPad::D => Coord { x: 1, y: 1 },
-        DPad::R => Coord { x: 2, y: 1 },
-        DPad::Invalid => todo!(),
-    };
-    for input in inputs {
-        match (last_dir, input) {
-            (None, _) => {}
-            (_, DPad::Invalid) => panic!("should never get here"),
-            (Some(DPad::Invalid), _) => panic!("should never get here"),
-            (_, DPad::A) => panic!("should never get here"),
-            (Some(DPad::A), _) => panic!("should never get here"),
-            (Some(DPad::U), DPad::U) => {}
-            (Some(DPad::L), DPad::L) => {}
-            (Some(DPad::D), DPad::D) => {}
-            (Some(DPad::R), DPad::R) => {}
-            (Some(DPad::U), DPad::D) => {
-                return false;
-            }
-            (Some(DPad::D), DPad::U) => {
-                return false;
-            }
-            (Some(DPad::L), DPad::R) => {
-                return false;
-            }
-            (Some(DPad::R), DPad::L) => {
-                return false;
-            }
-            (Some(DPad::U), DPad::L) => {
-                if left {
-                    return false;
-                } else {
-                    left = true;
-                    up = true
-                }
-            }
-            (Some(DPad::U), DPad::R) => {
-                if right {
-                    return false;
-                } else {
-                    up = true;
-                    right = true;
-                }
-            }
-            (Some(DPad::L), DPad::U) => {
-                if up {
-                    return false;
-                } else {
-                    left = true;
-                    up = true
-                }
-            }
-            (Some(DPad::L), DPad::D) => {
-                if down {
-                    return false;
-                } else {
-                    left = true;
-                    down = true;
-                }
-            }
-            (Some(DPad::D), DPad::L) => {
-                if left {
-                    return false;
-                } else {
-                    down = true;
-                    left = true;
-                }
-            }
-            (Some(DPad::D), DPad::R) => {
-                if right {
-                    return false;
-                } else {
-                    down = true;
-                    right = true
-                }
-            }
-            (Some(DPad::R), DPad::U) => {
-                if up {
-                    return false;
-                } else {
-                    up = true;
-                    right = true;
-                }
-            }
-            (Some(DPad::R), DPad::D) => {
-                if down {
-                    return false;
-                } else {
-                    down = true;
-                    right = true;
+impl Doors {
+    pub fn parse(input: &str) -> Result<Doors, AocError> {
+        let mut door = Keypad::numeric();
+        let mut remote = Keypad::directional();
+        let mut codes = vec![];
+        let mut lines = input.lines().enumerate().peekable();
+        while let Some((number, line)) = lines.next() {
+            let keypad = match line.trim_end() {
+                "" => continue,
+                "door:" => &mut door,
+                "remote:" => &mut remote,
+                code => {
+                    codes.push((number, code.to_string()));
+                    continue;
                 }
-            }
-        }
-        last_dir = Some(*input);
-        match input {
-            DPad::U => current_coord.y -= 1,
-            DPad::A => {}
-            DPad::L => current_coord.x -= 1,
-            DPad::D => current_coord.y += 1,
-            DPad::R => current_coord.x += 1,
-            DPad::Invalid => panic!("should not get here"),
-        }
-        if DMAP[current_coord.y][current_coord.x] == DPad::Invalid {
-            //println!("got to an invalid square");
-            return false;
-        }
-    }
-    if DMAP[current_coord.y][current_coord.x] == end {
-        true
-    } else {
-        panic!("pathfinding is wrong");
+            };
+            let mut layout = vec![];
+            while let Some((_, row)) = lines.next_if(|(_, row)| !row.trim().is_empty()) {
+                layout.push(row);
+            }
+            *keypad = Keypad::parse(&layout.join("\n")).map_err(|e| match e {
+                AocError::Parse {
+                    line,
+                    column,
+                    message,
+                } => AocError::parse(number + 1 + line, column, message),
+                e => e,
+            })?;
+        }
+        for (number, code) in &codes {
+            if let Some(column) = code.chars().position(|key| !door.has_key(key)) {
+                return Err(AocError::parse(
+                    number + 1,
+                    column + 1,
+                    format!("\"{code}\" has a key that isn't on the door's keypad"),
+                ));
+            }
+        }
+        Ok(Doors {
+            door,
+            remote,
+            codes: codes.into_iter().map(|(_, code)| code).collect(),
+        })
+    }
+
+    pub fn chain(&self, robots: usize) -> Result<Chain, AocError> {
+        Chain::new(self.door.clone(), self.remote.clone(), robots)
+    }
+
+    /// The sum over the codes of the presses each needs times the number in
+    /// it.
+    pub fn complexity(&self, robots: usize) -> Result<u64, AocError> {
+        let chain = self.chain(robots)?;
+        let mut total = 0;
+        for code in &self.codes {
+            let presses = chain
+                .min_presses(code)
+                .ok_or_else(|| AocError::solver(format!("there's no way to type \"{code}\"")))?;
+            total += presses * numeric_part(code);
+        }
+        Ok(total)
     }
 }
 
-fn dpad_list_from_dpad(
-    start: DPad,
-    end: DPad,
-    map: &mut HashMap<(DPad, DPad), Vec<Seq>>,
-) -> Vec<Seq> {
-    if let Some(res) = map.get(&(start, end)) {
-        return res.clone();
-    }
-    let mut seq = match (start, end) {
-        (DPad::U, DPad::U) => vec![],
-        (DPad::U, DPad::A) => vec![DPad::R],
-        (DPad::U, DPad::L) => vec![DPad::D, DPad::L],
-        (DPad::U, DPad::D) => vec![DPad::D],
-        (DPad::U, DPad::R) => vec![DPad::D, DPad::R],
-        (DPad::A, DPad::U) => vec![DPad::L],
-        (DPad::A, DPad::A) => vec![],
-        (DPad::A, DPad::L) => vec![DPad::D, DPad::L, DPad::L],
-        (DPad::A, DPad::D) => vec![DPad::D, DPad::L],
-        (DPad::A, DPad::R) => vec![DPad::D],
-        (DPad::L, DPad::U) => vec![DPad::R, DPad::U],
-        (DPad::L, DPad::A) => vec![DPad::R, DPad::R, DPad::U],
-        (DPad::L, DPad::L) => vec![],
-        (DPad::L, DPad::D) => vec![DPad::R],
-        (DPad::L, DPad::R) => vec![DPad::R, DPad::R],
-        (DPad::D, DPad::U) => vec![DPad::U],
-        (DPad::D, DPad::A) => vec![DPad::U, DPad::R],
-        (DPad::D, DPad::L) => vec![DPad::L],
-        (DPad::D, DPad::D) => vec![],
-        (DPad::D, DPad::R) => vec![DPad::R],
-        (DPad::R, DPad::U) => vec![DPad::U, DPad::L],
-        (DPad::R, DPad::A) => vec![DPad::U],
-        (DPad::R, DPad::L) => vec![DPad::L, DPad::L],
-        (DPad::R, DPad::D) => vec![DPad::L],
-        (DPad::R, DPad::R) => vec![],
-        _ => panic!("dpad_list_from_dpad found invalid state"),
-    };
-    if seq.len() > 1 {
-        let size: usize = seq.len();
-        //println!("seq: {seq:?}");
-        let mut res: Vec<Seq> = seq
-            .into_iter()
-            .permutations(size)
-            .unique()
-            .filter(|x| validate_dpad_path(start, end, x))
-            .collect();
-        //println!("res: {res:?}");
-        for s in res.iter_mut() {
-            s.push(DPad::A);
-        }
-        map.insert((start, end), res.clone());
-        res
-    } else {
-        if !validate_dpad_path(start, end, &seq) {
-            panic!("invalid route")
-        }
-        seq.push(DPad::A);
-        map.insert((start, end), vec![seq.clone()]);
-        vec![seq]
-    }
+/// The digits of the code read as one number, ignoring any other keys.
+fn numeric_part(code: &str) -> u64 {
+    code.chars()
+        .filter_map(|key| key.to_digit(10))
+        .fold(0, |number, digit| number * 10 + u64::from(digit))
 }
 
-fn validate_npad_path(start: NPad, end: NPad, inputs: &Vec<DPad>) -> bool {
-    let mut current_coord = match start {
-        NPad::Seven => Coord { x: 0, y: 0 },
-        NPad::Eight => Coord { x: 1, y: 0 },
-        NPad::Nine => Coord { x: 2, y: 0 },
-        NPad::Four => Coord { x: 0, y: 1 },
-        NPad::Five => Coord { x: 1, y: 1 },
-        NPad::Six => Coord { x: 2, y: 1 },
-        NPad::One => Coord { x: 0, y: 2 },
-        NPad::Two => Coord { x: 1, y: 2 },
-        NPad::Three => Coord { x: 2, y: 2 },
-        NPad::Zero => Coord { x: 1, y: 3 },
-        NPad::A => Coord { x: 2, y: 3 },
-        NPad::Invalid => Coord { x: 0, y: 3 },
-    };
-    for input in inputs {
-        match input {
-            DPad::U => current_coord.y -= 1,
-            DPad::A => {}
-            DPad::L => current_coord.x -= 1,
-            DPad::D => current_coord.y += 1,
-            DPad::R => current_coord.x += 1,
-            DPad::Invalid => todo!(),
-        }
-        if NMAP[current_coord.y][current_coord.x] == NPad::Invalid {
-            return false;
-        }
-    }
-    NMAP[current_coord.y][current_coord.x] == end
-}
+pub struct Day21;
 
-fn dpad_list_from_npad(
-    start: NPad,
-    end: NPad,
-    map: &mut HashMap<(NPad, NPad), Vec<Seq>>,
-) -> Vec<Seq> {
-    if let Some(res) = map.get(&(start, end)) {
-        return res.clone();
-    }
-    let mut seq = match (start, end) {
-        (NPad::Seven, NPad::Seven) => vec![],
-        (NPad::Seven, NPad::Eight) => vec![DPad::R],
-        (NPad::Seven, NPad::Nine) => vec![DPad::R, DPad::R],
-        (NPad::Seven, NPad::Four) => vec![DPad::D],
-        (NPad::Seven, NPad::Five) => vec![DPad::D, DPad::R],
-        (NPad::Seven, NPad::Six) => vec![DPad::D, DPad::R, DPad::R],
-        (NPad::Seven, NPad::One) => vec![DPad::D, DPad::D],
-        (NPad::Seven, NPad::Two) => vec![DPad::D, DPad::D, DPad::R],
-        (NPad::Seven, NPad::Three) => vec![DPad::D, DPad::D, DPad::R, DPad::R],
-        (NPad::Seven, NPad::Zero) => vec![DPad::R, DPad::D, DPad::D, DPad::D],
-        (NPad::Seven, NPad::A) => vec![DPad::R, DPad::R, DPad::D, DPad::D, DPad::D],
-        (NPad::Eight, NPad::Seven) => vec![DPad::L],
-        (NPad::Eight, NPad::Eight) => vec![],
-        (NPad::Eight, NPad::Nine) => vec![DPad::R],
-        (NPad::Eight, NPad::Four) => vec![DPad::D, DPad::L],
-        (NPad::Eight, NPad::Five) => vec![DPad::D],
-        (NPad::Eight, NPad::Six) => vec![DPad::D, DPad::R],
-        (NPad::Eight, NPad::One) => vec![DPad::D, DPad::D, DPad::L],
-        (NPad::Eight, NPad::Two) => vec![DPad::D, DPad::D],
-        (NPad::Eight, NPad::Three) => vec![DPad::D, DPad::D, DPad::R],
-        (NPad::Eight, NPad::Zero) => vec![DPad::D, DPad::D, DPad::D],
-        (NPad::Eight, NPad::A) => vec![DPad::D, DPad::D, DPad::D, DPad::R],
-        (NPad::Nine, NPad::Seven) => vec![DPad::L, DPad::L],
-        (NPad::Nine, NPad::Eight) => vec![DPad::L],
-        (NPad::Nine, NPad::Nine) => vec![],
-        (NPad::Nine, NPad::Four) => vec![DPad::D, DPad::L, DPad::L],
-        (NPad::Nine, NPad::Five) => vec![DPad::D, DPad::L],
-        (NPad::Nine, NPad::Six) => vec![DPad::D],
-        (NPad::Nine, NPad::One) => vec![DPad::D, DPad::D, DPad::L, DPad::L],
-        (NPad::Nine, NPad::Two) => vec![DPad::D, DPad::D, DPad::L],
-        (NPad::Nine, NPad::Three) => vec![DPad::D, DPad::D],
-        (NPad::Nine, NPad::Zero) => vec![DPad::D, DPad::D, DPad::D, DPad::L],
-        (NPad::Nine, NPad::A) => vec![DPad::D, DPad::D, DPad::D],
-        (NPad::Four, NPad::Seven) => vec![DPad::U],
-        (NPad::Four, NPad::Eight) => vec![DPad::U, DPad::R],
-        (NPad::Four, NPad::Nine) => vec![DPad::U, DPad::R, DPad::R],
-        (NPad::Four, NPad::Four) => vec![],
-        (NPad::Four, NPad::Five) => vec![DPad::R],
-        (NPad::Four, NPad::Six) => vec![DPad::R, DPad::R],
-        (NPad::Four, NPad::One) => vec![DPad::D],
-        (NPad::Four, NPad::Two) => vec![DPad::D, DPad::R],
-        (NPad::Four, NPad::Three) => vec![DPad::D, DPad::R, DPad::R],
-        (NPad::Four, NPad::Zero) => vec![DPad::R, DPad::D, DPad::D],
-        (NPad::Four, NPad::A) => vec![DPad::R, DPad::R, DPad::D, DPad::D],
-        (NPad::Five, NPad::Seven) => vec![DPad::U, DPad::L],
-        (NPad::Five, NPad::Eight) => vec![DPad::U],
-        (NPad::Five, NPad::Nine) => vec![DPad::U, DPad::R],
-        (NPad::Five, NPad::Four) => vec![DPad::L],
-        (NPad::Five, NPad::Five) => vec![],
-        (NPad::Five, NPad::Six) => vec![DPad::R],
-        (NPad::Five, NPad::One) => vec![DPad::D, DPad::L],
-        (NPad::Five, NPad::Two) => vec![DPad::D],
-        (NPad::Five, NPad::Three) => vec![DPad::D, DPad::R],
-        (NPad::Five, NPad::Zero) => vec![DPad::D, DPad::D],
-        (NPad::Five, NPad::A) => vec![DPad::D, DPad::D, DPad::R],
-        (NPad::Six, NPad::Seven) => vec![DPad::U, DPad::L, DPad::L],
-        (NPad::Six, NPad::Eight) => vec![DPad::L, DPad::U],
-        (NPad::Six, NPad::Nine) => vec![DPad::U],
-        (NPad::Six, NPad::Four) => vec![DPad::L, DPad::L],
-        (NPad::Six, NPad::Five) => vec![DPad::L],
-        (NPad::Six, NPad::Six) => vec![],
-        (NPad::Six, NPad::One) => vec![DPad::D, DPad::L, DPad::L],
-        (NPad::Six, NPad::Two) => vec![DPad::D, DPad::L],
-        (NPad::Six, NPad::Three) => vec![DPad::D],
-        (NPad::Six, NPad::Zero) => vec![DPad::D, DPad::D, DPad::L],
-        (NPad::Six, NPad::A) => vec![DPad::D, DPad::D],
-        (NPad::One, NPad::Seven) => vec![DPad::U, DPad::U],
-        (NPad::One, NPad::Eight) => vec![DPad::U, DPad::U, DPad::R],
-        (NPad::One, NPad::Nine) => vec![DPad::U, DPad::U, DPad::R, DPad::R],
-        (NPad::One, NPad::Four) => vec![DPad::U],
-        (NPad::One, NPad::Five) => vec![DPad::U, DPad::R],
-        (NPad::One, NPad::Six) => vec![DPad::U, DPad::R, DPad::R],
-        (NPad::One, NPad::One) => vec![],
-        (NPad::One, NPad::Two) => vec![DPad::R],
-        (NPad::One, NPad::Three) => vec![DPad::R, DPad::R],
-        (NPad::One, NPad::Zero) => vec![DPad::R, DPad::D],
-        (NPad::One, NPad::A) => vec![DPad::R, DPad::R, DPad::D],
-        (NPad::Two, NPad::Seven) => vec![DPad::U, DPad::U, DPad::L],
-        (NPad::Two, NPad::Eight) => vec![DPad::U, DPad::U],
-        (NPad::Two, NPad::Nine) => vec![DPad::R, DPad::U, DPad::U],
-        (NPad::Two, NPad::Four) => vec![DPad::U, DPad::L],
-        (NPad::Two, NPad::Five) => vec![DPad::U],
-        (NPad::Two, NPad::Six) => vec![DPad::U, DPad::R],
-        (NPad::Two, NPad::One) => vec![DPad::L],
-        (NPad::Two, NPad::Two) => vec![],
-        (NPad::Two, NPad::Three) => vec![DPad::R],
-        (NPad::Two, NPad::Zero) => vec![DPad::D],
-        (NPad::Two, NPad::A) => vec![DPad::D, DPad::R],
-        (NPad::Three, NPad::Seven) => vec![DPad::U, DPad::U, DPad::L, DPad::L],
-        (NPad::Three, NPad::Eight) => vec![DPad::U, DPad::U, DPad::L],
-        (NPad::Three, NPad::Nine) => vec![DPad::U, DPad::U],
-        (NPad::Three, NPad::Four) => vec![DPad::U, DPad::L, DPad::L],
-        (NPad::Three, NPad::Five) => vec![DPad::U, DPad::L],
-        (NPad::Three, NPad::Six) => vec![DPad::U],
-        (NPad::Three, NPad::One) => vec![DPad::L, DPad::L],
-        (NPad::Three, NPad::Two) => vec![DPad::L],
-        (NPad::Three, NPad::Three) => vec![],
-        (NPad::Three, NPad::Zero) => vec![DPad::D, DPad::L],
-        (NPad::Three, NPad::A) => vec![DPad::D],
-        (NPad::Zero, NPad::Seven) => vec![DPad::U, DPad::U, DPad::U, DPad::L],
-        (NPad::Zero, NPad::Eight) => vec![DPad::U, DPad::U, DPad::U],
-        (NPad::Zero, NPad::Nine) => vec![DPad::U, DPad::U, DPad::U, DPad::R],
-        (NPad::Zero, NPad::Four) => vec![DPad::U, DPad::U, DPad::L],
-        (NPad::Zero, NPad::Five) => vec![DPad::U, DPad::U],
-        (NPad::Zero, NPad::Six) => vec![DPad::U, DPad::U, DPad::R],
-        (NPad::Zero, NPad::One) => vec![DPad::U, DPad::L],
-        (NPad::Zero, NPad::Two) => vec![DPad::U],
-        (NPad::Zero, NPad::Three) => vec![DPad::U, DPad::R],
-        (NPad::Zero, NPad::Zero) => vec![],
-        (NPad::Zero, NPad::A) => vec![DPad::R],
-        (NPad::A, NPad::Seven) => vec![DPad::U, DPad::U, DPad::U, DPad::L, DPad::L],
-        (NPad::A, NPad::Eight) => vec![DPad::U, DPad::U, DPad::U, DPad::L],
-        (NPad::A, NPad::Nine) => vec![DPad::U, DPad::U, DPad::U],
-        (NPad::A, NPad::Four) => vec![DPad::U, DPad::U, DPad::L, DPad::L],
-        (NPad::A, NPad::Five) => vec![DPad::U, DPad::U, DPad::L],
-        (NPad::A, NPad::Six) => vec![DPad::U, DPad::U],
-        (NPad::A, NPad::One) => vec![DPad::U, DPad::L, DPad::L],
-        (NPad::A, NPad::Two) => vec![DPad::U, DPad::L],
-        (NPad::A, NPad::Three) => vec![DPad::U],
-        (NPad::A, NPad::Zero) => vec![DPad::L],
-        (NPad::A, NPad::A) => vec![],
-        _ => panic!("dpad_list_from_npad found invalid state"),
-    };
-    if seq.len() > 1 {
-        let size: usize = seq.len();
-        //println!("seq: {seq:?}");
-        let mut res: Vec<Seq> = seq
-            .into_iter()
-            .permutations(size)
-            .unique()
-            .filter(|x| validate_npad_path(start, end, x))
-            .collect();
-        //println!("res: {res:?}");
-        for s in res.iter_mut() {
-            s.push(DPad::A);
-        }
-        map.insert((start, end), res.clone());
-        res
-    } else {
-        if !validate_npad_path(start, end, &seq) {
-            panic!("invalid route")
-        }
-        seq.push(DPad::A);
-        map.insert((start, end), vec![seq.clone()]);
-        vec![seq]
-    }
-}
+impl Solution for Day21 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 21;
+    type Parsed<'a> = Result<Doors, AocError>;
+    type Part1 = Result<u64, AocError>;
+    type Part2 = Result<u64, AocError>;
 
-fn parse(input: &str) -> Vec<(u32, Vec<NPad>)> {
-    let mut res: Vec<(u32, Vec<NPad>)> = Vec::new();
-    for line in input.lines() {
-        let (_, number) = terminated(complete::u32::<&str, nom::error::Error<_>>, tag("A"))(line)
-            .expect("there should be a number");
-        let mut code: Vec<NPad> = Vec::new();
-        for ch in line.chars() {
-            let key = match ch {
-                '0' => NPad::Zero,
-                '1' => NPad::One,
-                '2' => NPad::Two,
-                '3' => NPad::Three,
-                '4' => NPad::Four,
-                '5' => NPad::Five,
-                '6' => NPad::Six,
-                '7' => NPad::Seven,
-                '8' => NPad::Eight,
-                '9' => NPad::Nine,
-                'A' => NPad::A,
-                unknown => panic!("unknown character in parse: {unknown}"),
-            };
-            code.push(key);
-        }
-        res.push((number, code));
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Doors::parse(input)
     }
-    res
-}
 
-pub fn process_part1(input: &str) -> String {
-    let codes = parse(input);
-    let mut nmap: HashMap<(NPad, NPad), Vec<Seq>> = HashMap::new();
-    let mut dmap: HashMap<(DPad, DPad), Vec<Seq>> = HashMap::new();
-    //println!("{codes:?}");
-    let mut total: usize = 0;
-    for (val, code) in codes {
-        //innermost robot
-        //println!("code: {}: {code:?}", code.len());
-        // start on A go to first key
-        let mut robot1_task: Vec<Seq> = dpad_list_from_npad(NPad::A, code[0], &mut nmap);
-        for pair in code.windows(2) {
-            let start = pair[0];
-            let end = pair[1];
-            let mut new_task_list: Vec<Seq> = Vec::new();
-            let new_step_list = dpad_list_from_npad(start, end, &mut nmap);
-            for old_steps in robot1_task.iter() {
-                //println!("robot 1: {new_step_list:?}");
-                for new_steps in new_step_list.iter() {
-                    let mut result_steps = old_steps.clone();
-                    result_steps.extend(new_steps);
-                    new_task_list.push(result_steps);
-                }
-            }
-            robot1_task = new_task_list;
-        }
-        // println!("robot1: {}: {robot1_task:?}", robot1_task.len());
-        let mut shortest1 = usize::MAX;
-        for seq1 in robot1_task.iter() {
-            //println!("{}: {:?}", seq1.len(), seq1);
-            if seq1.len() < shortest1 {
-                shortest1 = seq1.len();
-            }
-        }
-        // println!("robot1 shortest: {shortest1}");
-        let mut robot2_total: Vec<Seq> = Vec::new();
-        let mut robot2_task;
-        for seq1 in robot1_task.iter() {
-            // start on A go to first key
-            //println!("start: A, end: {:?}", seq1[0]);
-            robot2_task = dpad_list_from_dpad(DPad::A, seq1[0], &mut dmap);
-            //println!("robot 2: {robot2_task:?}");
-            for pair in seq1.windows(2) {
-                let start = pair[0];
-                let end = pair[1];
-                //println!("robot2_task so far: {robot2_task:?}");
-                //println!("start: {start:?}, end: {end:?}");
-                let mut new_task_list: Vec<Seq> = Vec::new();
-                let new_step_list = dpad_list_from_dpad(start, end, &mut dmap);
-                //println!("robot 2: {new_step_list:?}");
-                for old_steps in robot2_task.iter() {
-                    for new_steps in new_step_list.iter() {
-                        let mut result_steps = old_steps.clone();
-                        result_steps.extend(new_steps);
-                        new_task_list.push(result_steps);
-                    }
-                }
-                robot2_task = new_task_list;
-            }
-            robot2_total.extend(robot2_task);
-        }
-        let mut shortest2 = usize::MAX;
-        let mut sizes: HashMap<usize, usize> = HashMap::new();
-        for seq2 in robot2_total.iter() {
-            sizes.entry(seq2.len()).and_modify(|e| *e += 1).or_insert(1);
-            //println!("{}: {:?}", seq2.len(), seq2);
-            if seq2.len() < shortest2 {
-                shortest2 = seq2.len();
-            }
-        }
-        // for (k, v) in sizes.into_iter().sorted_by(|(k, _), (k2, _)| k.cmp(k2)) {
-        //     println!("r2 length: {k}, count: {v}");
-        // }
-        robot2_total = robot2_total
-            .into_iter()
-            .filter(|seq| seq.len() == shortest2)
-            .collect();
-        // println!("robot2 shortest: {shortest2}");
-        // println!("robot2: {}: {robot2_task:?}", robot2_task.len());
-        let mut robot3_total: Vec<Seq> = Vec::new();
-        let mut robot3_task;
-        for seq1 in robot2_total.iter() {
-            // start on A go to first key
-            //println!("start: A, end: {:?}", seq1[0]);
-            robot3_task = dpad_list_from_dpad(DPad::A, seq1[0], &mut dmap);
-            //println!("robot 3: {robot3_task:?}");
-            for pair in seq1.windows(2) {
-                let start = pair[0];
-                let end = pair[1];
-                //println!("robot3_task so far: {robot3_task:?}");
-                //println!("start: {start:?}, end: {end:?}");
-                let mut new_task_list: Vec<Seq> = Vec::new();
-                let new_step_list = dpad_list_from_dpad(start, end, &mut dmap);
-                //println!("robot 3: {new_step_list:?}");
-                for old_steps in robot3_task.iter() {
-                    for new_steps in new_step_list.iter() {
-                        let mut result_steps = old_steps.clone();
-                        result_steps.extend(new_steps);
-                        new_task_list.push(result_steps);
-                    }
-                }
-                robot3_task = new_task_list;
-            }
-            robot3_total.extend(robot3_task);
-        }
-        let mut shortest3 = usize::MAX;
-        //print!("lengths: ");
-        let mut sizes: HashMap<usize, usize> = HashMap::new();
-        for seq3 in robot3_total.iter() {
-            sizes.entry(seq3.len()).and_modify(|e| *e += 1).or_insert(1);
-            //print!("{}, ", seq3.len());
-            if seq3.len() < shortest3 {
-                shortest3 = seq3.len();
-            }
-        }
-        // for (k, v) in sizes.into_iter().sorted_by(|(k, _), (k2, _)| k.cmp(k2)) {
-        //     println!("r3 length: {k}, count: {v}");
-        // }
-        // println!();
-        // println!("robot3 shortest: {shortest3}");
-        // println!(
-        //     "robot3 routes: {}:\nroute0: {}: {:?}",
-        //     robot3_total.len(),
-        //     robot3_total[0].len(),
-        //     robot3_total[0]
-        // );
-        //println!("complexity: {}*{}", shortest3, val);
-        total += val as usize * shortest3;
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        input?.complexity(2)
     }
-    total.to_string()
-}
 
-fn expand_by_dpad(input: Vec<Seq>, map: &mut HashMap<(DPad, DPad), Vec<Seq>>) -> Vec<Seq> {
-    let mut robot_next_total: Vec<Seq> = Vec::new();
-    let mut robot_next_task;
-    for seq1 in input.iter() {
-        // start on A go to first key
-        //println!("start: A, end: {:?}", seq1[0]);
-        robot_next_task = dpad_list_from_dpad(DPad::A, seq1[0], map);
-        //println!("robot 2: {robot_next_task:?}");
-        for pair in seq1.windows(2) {
-            let start = pair[0];
-            let end = pair[1];
-            //println!("robot_next_task so far: {robot_next_task:?}");
-            //println!("start: {start:?}, end: {end:?}");
-            let mut new_task_list: Vec<Seq> = Vec::new();
-            let new_step_list = dpad_list_from_dpad(start, end, map);
-            //println!("robot next: {new_step_list:?}");
-            for old_steps in robot_next_task.iter() {
-                for new_steps in new_step_list.iter() {
-                    let mut result_steps = old_steps.clone();
-                    result_steps.extend(new_steps);
-                    new_task_list.push(result_steps);
-                }
-            }
-            robot_next_task = new_task_list;
-        }
-        robot_next_total.extend(robot_next_task);
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        input?.complexity(25)
     }
-    let mut shortest_next = usize::MAX;
-    //let mut sizes: HashMap<usize, usize> = HashMap::new();
-    for seq2 in robot_next_total.iter() {
-        //sizes.entry(seq2.len()).and_modify(|e| *e += 1).or_insert(1);
-        //println!("{}: {:?}", seq2.len(), seq2);
-        if seq2.len() < shortest_next {
-            shortest_next = seq2.len();
-        }
-    }
-    // for (k, v) in sizes.into_iter().sorted_by(|(k, _), (k2, _)| k.cmp(k2)) {
-    //     println!("r2 length: {k}, count: {v}");
-    // }
-    robot_next_total = robot_next_total
-        .into_iter()
-        .filter(|seq| seq.len() == shortest_next)
-        .collect();
-    robot_next_total
 }
 
-fn calc_cost(
-    start: DPad,
-    end: DPad,
-    depth: usize,
-    cost_map: &mut HashMap<(DPad, DPad, usize), usize>,
-    dpad_map: &mut HashMap<(DPad, DPad), Vec<Seq>>,
-) -> usize {
-    if let Some(val) = cost_map.get(&(start, end, depth)) {
-        return *val;
-    } else {
-        // the innermost robot is calcuated via npad so we stop at the 1st robot
-        if depth == 1 {
-            let res = dpad_list_from_dpad(start, end, dpad_map);
-            let mut shortest = usize::MAX;
-            for seq in res {
-                shortest = shortest.min(seq.len());
-            }
-            cost_map.insert((start, end, depth), shortest);
-            shortest
-        } else {
-            let sequence_list: Vec<Seq> = dpad_list_from_dpad(start, end, dpad_map);
-            let mut best_cost = usize::MAX;
-            for seq in sequence_list {
-                // push an a onto the front
-                let mut aug_seq = vec![DPad::A];
-                aug_seq.extend(seq);
-                let mut cost = 0;
-                for i in 0..aug_seq.len() - 1 {
-                    let new_start = aug_seq[i];
-                    let new_end = aug_seq[i + 1];
-                    cost += calc_cost(new_start, new_end, depth - 1, cost_map, dpad_map);
-                }
-                best_cost = best_cost.min(cost);
-            }
-            //println!("new best cost for {start:?}, {end:?}, depth: {depth}: {best_cost}");
-            cost_map.insert((start, end, depth), best_cost);
-            best_cost
-        }
-    }
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day21::solve_part1(input)
 }
 
-pub fn process_part2(input: &str) -> String {
-    let mut nmap: HashMap<(NPad, NPad), Vec<Seq>> = HashMap::new();
-    let mut dmap: HashMap<(DPad, DPad), Vec<Seq>> = HashMap::new();
-    let codes = parse(input);
-    let mut map: HashMap<(DPad, DPad, usize), usize> = HashMap::new();
-    //println!("{codes:?}");
-    let mut total: usize = 0;
-    for (val, code) in codes {
-        // start on A go to first key
-        //println!("code: {code:?}");
-        let mut keypad_robot_task: Vec<Seq> = dpad_list_from_npad(NPad::A, code[0], &mut nmap);
-        for pair in code.windows(2) {
-            let start = pair[0];
-            let end = pair[1];
-            let mut new_task_list: Vec<Seq> = Vec::new();
-            let new_step_list = dpad_list_from_npad(start, end, &mut nmap);
-            for old_steps in keypad_robot_task.iter() {
-                //println!("robot 1: {new_step_list:?}");
-                for new_steps in new_step_list.iter() {
-                    let mut result_steps = old_steps.clone();
-                    result_steps.extend(new_steps);
-                    new_task_list.push(result_steps);
-                }
-            }
-            keypad_robot_task = new_task_list;
-        }
-        //println!("keypad_robot_task: {keypad_robot_task:?}");
-        let mut cheapest_seq = usize::MAX;
-        for seq in keypad_robot_task {
-            let mut seq_cost = calc_cost(DPad::A, seq[0], 25, &mut map, &mut dmap);
-            //println!("cost to press seq[0]:{:?}:{seq_cost}", seq[0]);
-            for pair in seq.windows(2) {
-                let start = pair[0];
-                let end = pair[1];
-                seq_cost += calc_cost(start, end, 25, &mut map, &mut dmap);
-                // println!(
-                //     "total cost so far to press seq[{}]:{:?}:{seq_cost}",
-                //     i + 1,
-                //     end
-                // );
-            }
-            cheapest_seq = cheapest_seq.min(seq_cost);
-        }
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day21::solve_part2(input)
+}
 
-        //println!("complexity: {}*{}", cheapest_seq, val);
-        total += val as usize * cheapest_seq;
-    }
-    total.to_string()
+/// What the person presses to type each code with part 1's two robots, one
+/// code per line.
+pub fn sequences(input: &str) -> Result<String, AocError> {
+    let doors = Doors::parse(input)?;
+    let chain = doors.chain(2)?;
+    let mut lines = vec![];
+    for code in &doors.codes {
+        let presses = chain.press_sequence(code, SEQUENCE_LIMIT).ok_or_else(|| {
+            AocError::unsupported(format!("\"{code}\" takes too many presses to write out"))
+        })?;
+        lines.push(format!("{code}: {presses}"));
+    }
+    Ok(lines.join("\n"))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    fn find_npad_endpoint(start: NPad, inputs: Vec<DPad>) -> NPad {
-        let mut current_coord = match start {
-            NPad::Seven => Coord { x: 0, y: 0 },
-            NPad::Eight => Coord { x: 1, y: 0 },
-            NPad::Nine => Coord { x: 2, y: 0 },
-            NPad::Four => Coord { x: 0, y: 1 },
-            NPad::Five => Coord { x: 1, y: 1 },
-            NPad::Six => Coord { x: 2, y: 1 },
-            NPad::One => Coord { x: 0, y: 2 },
-            NPad::Two => Coord { x: 1, y: 2 },
-            NPad::Three => Coord { x: 2, y: 2 },
-            NPad::Zero => Coord { x: 1, y: 3 },
-            NPad::A => Coord { x: 2, y: 3 },
-            NPad::Invalid => Coord { x: 0, y: 3 },
-        };
-        for input in inputs {
-            match input {
-                DPad::U => current_coord.y -= 1,
-                DPad::A => {}
-                DPad::L => current_coord.x -= 1,
-                DPad::D => current_coord.y += 1,
-                DPad::R => current_coord.x += 1,
-                DPad::Invalid => todo!(),
-            }
-            assert_ne!(NMAP[current_coord.y][current_coord.x], NPad::Invalid)
-        }
-        NMAP[current_coord.y][current_coord.x]
+    #[test]
+    fn test_input() {
+        let file = include_str!("../test-input-1.txt");
+        assert_eq!(process_part1(file).unwrap(), "126384");
+        assert_eq!(process_part2(file).unwrap(), "154115708116294");
     }
 
-    fn find_dpad_endpoint(start: DPad, inputs: Vec<DPad>) -> DPad {
-        let mut current_coord = match start {
-            DPad::U => Coord { x: 1, y: 0 },
-            DPad::A => Coord { x: 2, y: 0 },
-            DPad::L => Coord { x: 0, y: 1 },
-            DPad::D => Coord { x: 1, y: 1 },
-            DPad::R => Coord { x: 2, y: 1 },
-            DPad::Invalid => todo!(),
-        };
-        for input in inputs {
-            match input {
-                DPad::U => current_coord.y -= 1,
-                DPad::A => {}
-                DPad::L => current_coord.x -= 1,
-                DPad::D => current_coord.y += 1,
-                DPad::R => current_coord.x += 1,
-                DPad::Invalid => todo!(),
-            }
-            assert_ne!(DMAP[current_coord.y][current_coord.x], DPad::Invalid)
+    #[test]
+    fn test_custom_layouts() {
+        let input = "door:\n12\n A\n\nremote:\n<^Av>\n\n12A\n21A\n";
+        let doors = Doors::parse(input).unwrap();
+        let sequences = sequences(input).unwrap();
+        assert_eq!(sequences.lines().count(), 2);
+        for (code, line) in doors.codes.iter().zip(sequences.lines()) {
+            let (_, presses) = line.split_once(": ").unwrap();
+            assert_eq!(
+                doors.chain(2).unwrap().replay(presses).as_deref(),
+                Some(code.as_str())
+            );
         }
-        DMAP[current_coord.y][current_coord.x]
-    }
 
-    fn validate_npad_route_count(start: NPad, end: NPad, seq_count: usize) -> bool {
-        println!("recieved {seq_count} sequences");
-        seq_count
-            == match (start, end) {
-                (NPad::Invalid, _) => panic!("shouldn't get here"),
-                (_, NPad::Invalid) => panic!("shouldn't get here"),
-                (NPad::Seven, NPad::Seven) => 1,
-                (NPad::Seven, NPad::Eight) => 1,
-                (NPad::Seven, NPad::Nine) => 1,
-                (NPad::Seven, NPad::Four) => 1,
-                (NPad::Seven, NPad::Five) => 2,
-                (NPad::Seven, NPad::Six) => 3,
-                (NPad::Seven, NPad::One) => 1,
-                (NPad::Seven, NPad::Two) => 3,
-                (NPad::Seven, NPad::Three) => 6,
-                (NPad::Seven, NPad::Zero) => 3,
-                (NPad::Seven, NPad::A) => 9,
-                (NPad::Eight, NPad::Seven) => 1,
-                (NPad::Eight, NPad::Eight) => 1,
-                (NPad::Eight, NPad::Nine) => 1,
-                (NPad::Eight, NPad::Four) => 2,
-                (NPad::Eight, NPad::Five) => 1,
-                (NPad::Eight, NPad::Six) => 2,
-                (NPad::Eight, NPad::One) => 3,
-                (NPad::Eight, NPad::Two) => 1,
-                (NPad::Eight, NPad::Three) => 3,
-                (NPad::Eight, NPad::Zero) => 1,
-                (NPad::Eight, NPad::A) => 4,
-                (NPad::Nine, NPad::Seven) => 1,
-                (NPad::Nine, NPad::Eight) => 1,
-                (NPad::Nine, NPad::Nine) => 1,
-                (NPad::Nine, NPad::Four) => 3,
-                (NPad::Nine, NPad::Five) => 2,
-                (NPad::Nine, NPad::Six) => 1,
-                (NPad::Nine, NPad::One) => 6,
-                (NPad::Nine, NPad::Two) => 3,
-                (NPad::Nine, NPad::Three) => 1,
-                (NPad::Nine, NPad::Zero) => 4,
-                (NPad::Nine, NPad::A) => 1,
-                (NPad::Four, NPad::Seven) => 1,
-                (NPad::Four, NPad::Eight) => 2,
-                (NPad::Four, NPad::Nine) => 3,
-                (NPad::Four, NPad::Four) => 1,
-                (NPad::Four, NPad::Five) => 1,
-                (NPad::Four, NPad::Six) => 1,
-                (NPad::Four, NPad::One) => 1,
-                (NPad::Four, NPad::Two) => 2,
-                (NPad::Four, NPad::Three) => 3,
-                (NPad::Four, NPad::Zero) => 2,
-                (NPad::Four, NPad::A) => 5,
-                (NPad::Five, NPad::Seven) => 2,
-                (NPad::Five, NPad::Eight) => 1,
-                (NPad::Five, NPad::Nine) => 2,
-                (NPad::Five, NPad::Four) => 1,
-                (NPad::Five, NPad::Five) => 1,
-                (NPad::Five, NPad::Six) => 1,
-                (NPad::Five, NPad::One) => 2,
-                (NPad::Five, NPad::Two) => 1,
-                (NPad::Five, NPad::Three) => 2,
-                (NPad::Five, NPad::Zero) => 1,
-                (NPad::Five, NPad::A) => 3,
-                (NPad::Six, NPad::Seven) => 3,
-                (NPad::Six, NPad::Eight) => 2,
-                (NPad::Six, NPad::Nine) => 1,
-                (NPad::Six, NPad::Four) => 1,
-                (NPad::Six, NPad::Five) => 1,
-                (NPad::Six, NPad::Six) => 1,
-                (NPad::Six, NPad::One) => 3,
-                (NPad::Six, NPad::Two) => 2,
-                (NPad::Six, NPad::Three) => 1,
-                (NPad::Six, NPad::Zero) => 3,
-                (NPad::Six, NPad::A) => 1,
-                (NPad::One, NPad::Seven) => 1,
-                (NPad::One, NPad::Eight) => 3,
-                (NPad::One, NPad::Nine) => 6,
-                (NPad::One, NPad::Four) => 1,
-                (NPad::One, NPad::Five) => 2,
-                (NPad::One, NPad::Six) => 3,
-                (NPad::One, NPad::One) => 1,
-                (NPad::One, NPad::Two) => 1,
-                (NPad::One, NPad::Three) => 1,
-                (NPad::One, NPad::Zero) => 1,
-                (NPad::One, NPad::A) => 2,
-                (NPad::Two, NPad::Seven) => 3,
-                (NPad::Two, NPad::Eight) => 1,
-                (NPad::Two, NPad::Nine) => 3,
-                (NPad::Two, NPad::Four) => 2,
-                (NPad::Two, NPad::Five) => 1,
-                (NPad::Two, NPad::Six) => 2,
-                (NPad::Two, NPad::One) => 1,
-                (NPad::Two, NPad::Two) => 1,
-                (NPad::Two, NPad::Three) => 1,
-                (NPad::Two, NPad::Zero) => 1,
-                (NPad::Two, NPad::A) => 2,
-                (NPad::Three, NPad::Seven) => 6,
-                (NPad::Three, NPad::Eight) => 3,
-                (NPad::Three, NPad::Nine) => 1,
-                (NPad::Three, NPad::Four) => 3,
-                (NPad::Three, NPad::Five) => 2,
-                (NPad::Three, NPad::Six) => 1,
-                (NPad::Three, NPad::One) => 1,
-                (NPad::Three, NPad::Two) => 1,
-                (NPad::Three, NPad::Three) => 1,
-                (NPad::Three, NPad::Zero) => 2,
-                (NPad::Three, NPad::A) => 1,
-                (NPad::Zero, NPad::Seven) => 3,
-                (NPad::Zero, NPad::Eight) => 1,
-                (NPad::Zero, NPad::Nine) => 4,
-                (NPad::Zero, NPad::Four) => 2,
-                (NPad::Zero, NPad::Five) => 1,
-                (NPad::Zero, NPad::Six) => 3,
-                (NPad::Zero, NPad::One) => 1,
-                (NPad::Zero, NPad::Two) => 1,
-                (NPad::Zero, NPad::Three) => 2,
-                (NPad::Zero, NPad::Zero) => 1,
-                (NPad::Zero, NPad::A) => 1,
-                (NPad::A, NPad::Seven) => 9,
-                (NPad::A, NPad::Eight) => 4,
-                (NPad::A, NPad::Nine) => 1,
-                (NPad::A, NPad::Four) => 5,
-                (NPad::A, NPad::Five) => 3,
-                (NPad::A, NPad::Six) => 1,
-                (NPad::A, NPad::One) => 2,
-                (NPad::A, NPad::Two) => 2,
-                (NPad::A, NPad::Three) => 1,
-                (NPad::A, NPad::Zero) => 1,
-                (NPad::A, NPad::A) => 1,
-            }
-    }
-
-    fn validate_dpad_route_count(start: DPad, end: DPad, seq_count: usize) -> bool {
-        println!("recieved {seq_count} sequences");
-        seq_count
-            == match (start, end) {
-                (DPad::Invalid, _) => panic!("shouldn't get here"),
-                (_, DPad::Invalid) => panic!("shouldn't get here"),
-                (DPad::U, DPad::U) => 1,
-                (DPad::U, DPad::A) => 1,
-                (DPad::U, DPad::L) => 1,
-                (DPad::U, DPad::D) => 1,
-                (DPad::U, DPad::R) => 2,
-                (DPad::A, DPad::U) => 1,
-                (DPad::A, DPad::A) => 1,
-                (DPad::A, DPad::L) => 1,
-                (DPad::A, DPad::D) => 2,
-                (DPad::A, DPad::R) => 1,
-                (DPad::L, DPad::U) => 1,
-                (DPad::L, DPad::A) => 1,
-                (DPad::L, DPad::L) => 1,
-                (DPad::L, DPad::D) => 1,
-                (DPad::L, DPad::R) => 1,
-                (DPad::D, DPad::U) => 1,
-                (DPad::D, DPad::A) => 2,
-                (DPad::D, DPad::L) => 1,
-                (DPad::D, DPad::D) => 1,
-                (DPad::D, DPad::R) => 1,
-                (DPad::R, DPad::U) => 2,
-                (DPad::R, DPad::A) => 1,
-                (DPad::R, DPad::L) => 1,
-                (DPad::R, DPad::D) => 1,
-                (DPad::R, DPad::R) => 1,
-            }
-    }
-
-    // #[test]
-    // fn test_expansion_rate() {
-    //     let robot1_task = vec![vec![DPad::L, DPad::A]];
-    //     let mut res = expand_by_dpad(robot1_task);
-    //     for i in 0..25 {
-    //         println!("depth: {i}: {}", res[0].len());
-    //         res = expand_by_dpad(res);
-    //     }
-    //     assert_eq!(res[0].len(), 1);
-    // }
-
-    #[test]
-    fn test_dpad() {
         assert_eq!(
-            dpad_list_from_dpad(DPad::A, DPad::R, &mut HashMap::new()),
-            vec![vec![DPad::D, DPad::A]]
+            Doors::parse("029A\n02BA\n").unwrap_err(),
+            AocError::parse(2, 3, "\"02BA\" has a key that isn't on the door's keypad")
         );
-
-        let robot1_task = vec![
-            vec![
-                DPad::L,
-                DPad::A,
-                DPad::U,
-                DPad::A,
-                DPad::R,
-                DPad::U,
-                DPad::U,
-                DPad::A,
-                DPad::D,
-                DPad::D,
-                DPad::D,
-                DPad::A,
-            ],
-            vec![
-                DPad::L,
-                DPad::A,
-                DPad::U,
-                DPad::A,
-                DPad::U,
-                DPad::R,
-                DPad::U,
-                DPad::A,
-                DPad::D,
-                DPad::D,
-                DPad::D,
-                DPad::A,
-            ],
-        ];
-        let res = expand_by_dpad(robot1_task, &mut HashMap::new());
-
         assert_eq!(
-            res.contains(&vec![
-                DPad::D,
-                DPad::L,
-                DPad::L,
-                DPad::A,
-                DPad::R,
-                DPad::R,
-                DPad::U,
-                DPad::A,
-                DPad::L,
-                DPad::A,
-                DPad::R,
-                DPad::A,
-                DPad::D,
-                DPad::A,
-                DPad::L,
-                DPad::U,
-                DPad::A,
-                DPad::A,
-                DPad::R,
-                DPad::A,
-                DPad::L,
-                DPad::D,
-                DPad::A,
-                DPad::A,
-                DPad::A,
-                DPad::R,
-                DPad::U,
-                DPad::A
-            ]),
-            true
-        )
-    }
-
-    #[test]
-    fn test_keypad() {
-        let npad_list = [
-            NPad::Nine,
-            NPad::Eight,
-            NPad::Seven,
-            NPad::Six,
-            NPad::Five,
-            NPad::Four,
-            NPad::Three,
-            NPad::Two,
-            NPad::One,
-            NPad::Zero,
-            NPad::A,
-        ];
-        let dpad_list = [DPad::U, DPad::D, DPad::L, DPad::R, DPad::A];
-        for start in npad_list {
-            for end in npad_list {
-                println!("starting {start:?}, ending {end:?}");
-                let sequence_list = dpad_list_from_npad(start, end, &mut HashMap::new());
-                assert_eq!(
-                    validate_npad_route_count(start, end, sequence_list.len()),
-                    true
-                );
-                for instruction_list in sequence_list {
-                    println!("start: {start:?}, end: {end:?} checking {instruction_list:?}");
-                    assert_eq!(find_npad_endpoint(start, instruction_list), end)
-                }
-            }
-        }
-        for start in dpad_list {
-            for end in dpad_list {
-                println!("starting {start:?}, ending {end:?}");
-                let sequence_list = dpad_list_from_dpad(start, end, &mut HashMap::new());
-                assert_eq!(
-                    validate_dpad_route_count(start, end, sequence_list.len()),
-                    true
-                );
-                for instruction_list in sequence_list {
-                    println!("start: {start:?}, end: {end:?} checking {instruction_list:?}");
-                    assert_eq!(find_dpad_endpoint(start, instruction_list), end)
-                }
-            }
-        }
-    }
-
-    #[test]
-    fn test_input() {
-        let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "126384");
+            Doors::parse("door:\n12A\n2\n\n1A\n").unwrap_err(),
+            AocError::parse(3, 1, "2 is on the keypad twice")
+        );
     }
 }
@@ -1,14 +1,36 @@
-use day_22::*;
+use aoc_2024_day_22::*;
 
 fn main() {
     divan::main();
 }
 
+fn input() -> String {
+    aoc_core::input::from_env(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Made up buyers, so the two part 2 approaches can be compared without an
+/// input.
+fn buyers() -> Vec<i64> {
+    (1..=500).map(|i| i * 7919 % 16777216).collect()
+}
+
+#[divan::bench]
+fn part1(bencher: divan::Bencher) {
+    let input = input();
+    bencher.bench(|| process_part1(divan::black_box(&input)));
+}
+#[divan::bench]
+fn part2(bencher: divan::Bencher) {
+    let input = input();
+    bencher.bench(|| process_part2(divan::black_box(&input)));
+}
 #[divan::bench]
-fn part1() {
-    process_part1(divan::black_box(include_str!("../input.txt")));
+fn part2_hash_map_oracle(bencher: divan::Bencher) {
+    let buyers = buyers();
+    bencher.bench(|| part2_oracle(divan::black_box(&buyers)));
 }
 #[divan::bench]
-fn part2() {
-    process_part2(divan::black_box(include_str!("../input.txt")));
+fn part2_flat_market(bencher: divan::Bencher) {
+    let buyers = buyers();
+    bencher.bench(|| part2_market(divan::black_box(&buyers)));
 }
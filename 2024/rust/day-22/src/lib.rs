@@ -1,3 +1,6 @@
+use aoc_core::{Answer, AocError, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use std::collections::HashMap;
 
 fn mix(current: i64, input: i64) -> i64 {
@@ -8,140 +11,159 @@ fn prune(current: i64) -> i64 {
     current % 16777216
 }
 
-fn get_next(input: i64, map: &mut HashMap<i64, i64>) -> i64 {
-    if let Some(v) = map.get(&input) {
-        *v
-    } else {
-        // mix: mult 64, then prune
-        let step1 = prune(mix(input, input * 64));
-        let step2 = prune(mix(step1, step1 / 32));
-        let step3 = prune(mix(step2, step2 * 2048));
-        map.insert(input, step3);
-        step3
-    }
+fn next_secret(input: i64) -> i64 {
+    // mix: mult 64, then prune
+    let step1 = prune(mix(input, input * 64));
+    let step2 = prune(mix(step1, step1 / 32));
+    prune(mix(step2, step2 * 2048))
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut map = HashMap::new();
-    let mut buyer_secrets: Vec<i64> = Vec::new();
-    for line in input.lines() {
-        buyer_secrets.push(line.parse().expect("there should be a number"));
-    }
-    let mut final_values: Vec<i64> = Vec::new();
-    for buyer in buyer_secrets {
-        let mut current_secret = buyer;
-        for _ in 0..2000 {
-            current_secret = get_next(current_secret, &mut map);
-        }
-        final_values.push(current_secret);
-    }
-    final_values.iter().sum::<i64>().to_string()
+/// How many different runs of four price changes there are, each change
+/// being one of the 19 values from -9 to 9.
+const WINDOWS: usize = 19 * 19 * 19 * 19;
+
+/// Bananas bought for each run of four price changes, indexed by the run
+/// read as a base-19 number, summed over the buyers added so far.
+struct Market {
+    totals: Vec<u32>,
+    /// which runs the current buyer has already sold at, one bit each
+    seen: Vec<u64>,
 }
 
-pub fn process_part2(input: &str) -> String {
-    let mut map = HashMap::new();
-    let mut buyer_secrets: Vec<i64> = Vec::new();
-    for line in input.lines() {
-        buyer_secrets.push(line.parse().expect("there should be a number"));
+impl Market {
+    fn new() -> Market {
+        Market {
+            totals: vec![0; WINDOWS],
+            seen: vec![0; WINDOWS.div_ceil(64)],
+        }
     }
-    //let mut all_diff_histories: Vec<Vec<(i64, i64)>> = Vec::new();
-    let mut all_best_map: HashMap<(i64, i64, i64, i64), i64> = HashMap::new();
-    for buyer in buyer_secrets {
-        let mut buyer_best_map: HashMap<(i64, i64, i64, i64), i64> = HashMap::new();
-        let mut current_secret = buyer;
-        //let mut diff_history: Vec<(i64, i64)> = Vec::new();
-        let (mut one, mut two, mut three, mut four): (
-            Option<i64>,
-            Option<i64>,
-            Option<i64>,
-            Option<i64>,
-        ) = (None, None, None, None);
-        let mut prev_price = current_secret % 10;
-        for _ in 0..2000 {
-            current_secret = get_next(current_secret, &mut map);
-            let current_price = current_secret % 10;
-            let diff = current_price - prev_price;
-            //diff_history.push((diff, current_price));
-            one = two;
-            two = three;
-            three = four;
-            four = Some(diff);
-            if let (Some(d1), Some(d2), Some(d3), Some(d4)) = (one, two, three, four) {
-                buyer_best_map
-                    .entry((d1, d2, d3, d4))
-                    .or_insert(current_price);
+
+    /// Adds what `buyer` sells at the first time each run of changes comes up.
+    fn add_buyer(&mut self, buyer: i64) {
+        self.seen.fill(0);
+        let mut secret = buyer;
+        let mut prev_price = secret % 10;
+        let mut window = 0;
+        for i in 0..2000 {
+            secret = next_secret(secret);
+            let price = secret % 10;
+            window = (window * 19 + (price - prev_price + 9) as usize) % WINDOWS;
+            prev_price = price;
+            if i < 3 {
+                continue;
+            }
+            let (word, bit) = (window / 64, 1 << (window % 64));
+            if self.seen[word] & bit == 0 {
+                self.seen[word] |= bit;
+                self.totals[window] += price as u32;
             }
-            prev_price = current_price;
         }
-        //all_diff_histories.push(diff_history);
-        for (key, value) in buyer_best_map {
-            all_best_map
-                .entry(key)
-                .and_modify(|v| *v += value)
-                .or_insert(value);
+    }
+
+    #[cfg(feature = "parallel")]
+    fn merge(mut self, other: Market) -> Market {
+        for (total, other) in self.totals.iter_mut().zip(other.totals) {
+            *total += other;
         }
+        self
+    }
+
+    fn best(&self) -> u32 {
+        self.totals.iter().copied().max().unwrap_or(0)
     }
-    all_best_map
-        .values()
-        .max()
-        .expect("there should be a number")
-        .to_string()
 }
 
-pub fn process_part2_slow(input: &str) -> String {
-    let mut map = HashMap::new();
-    let mut buyer_secrets: Vec<i64> = Vec::new();
-    for line in input.lines() {
-        buyer_secrets.push(line.parse().expect("there should be a number"));
+/// Part 2 on one thread, with the totals in the same flat array as
+/// [`Day22`] uses.
+pub fn part2_market(buyers: &[i64]) -> i64 {
+    let mut market = Market::new();
+    for &buyer in buyers {
+        market.add_buyer(buyer);
     }
-    let mut all_diff_histories: Vec<Vec<(i64, i64)>> = Vec::new();
-    for buyer in buyer_secrets {
-        let mut current_secret = buyer;
-        let mut diff_history: Vec<(i64, i64)> = Vec::new();
-        let mut current_price = current_secret % 10;
+    market.best().into()
+}
+
+/// Part 2 the plain way, a map of each buyer's first sale per run of
+/// changes merged into one map of totals. Slower, but there to check
+/// [`part2_market`] against.
+pub fn part2_oracle(buyers: &[i64]) -> i64 {
+    let mut totals: HashMap<[i64; 4], i64> = HashMap::new();
+    for &buyer in buyers {
+        let mut prices = vec![buyer % 10];
+        let mut secret = buyer;
         for _ in 0..2000 {
-            current_secret = get_next(current_secret, &mut map);
-            diff_history.push((current_secret % 10 - current_price, current_secret % 10));
-            current_price = current_secret % 10;
+            secret = next_secret(secret);
+            prices.push(secret % 10);
+        }
+        let mut first_sales: HashMap<[i64; 4], i64> = HashMap::new();
+        for window in prices.windows(5) {
+            let changes = [1, 2, 3, 4].map(|i| window[i] - window[i - 1]);
+            first_sales.entry(changes).or_insert(window[4]);
+        }
+        for (changes, price) in first_sales {
+            *totals.entry(changes).or_default() += price;
         }
-        all_diff_histories.push(diff_history);
     }
-    let mut bought_map: HashMap<(i64, i64, i64, i64), i64> = HashMap::new();
-    for one in -9i64..9i64 {
-        for two in -9i64..9i64 {
-            for three in -9i64..9i64 {
-                for four in -9i64..9i64 {
-                    'next_buyer: for (buyer_number, buyer) in all_diff_histories.iter().enumerate()
-                    {
-                        for i in 3..buyer.len() {
-                            if one == buyer[i - 3].0
-                                && two == buyer[i - 2].0
-                                && three == buyer[i - 1].0
-                                && four == buyer[i].0
-                            {
-                                println!(
-                                    "[{one}, {two}, {three}, {four}]: on iteration {i} bought {} from {}",
-                                    buyer[i].1, buyer_number
-                                );
-                                bought_map
-                                    .entry((one, two, three, four))
-                                    .and_modify(|e| *e += buyer[i].1)
-                                    .or_insert(buyer[i].1);
-                                continue 'next_buyer;
-                            }
-                        }
-                    }
-                }
-            }
+    totals.into_values().max().unwrap_or(0)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 22;
+    type Parsed<'a> = &'a str;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut buyer_secrets: Vec<i64> = Vec::new();
+        for line in input.lines() {
+            buyer_secrets.push(line.parse().expect("there should be a number"));
         }
+        #[cfg(feature = "parallel")]
+        let buyers = buyer_secrets.into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let buyers = buyer_secrets.into_iter();
+        buyers
+            .map(|buyer| (0..2000).fold(buyer, |secret, _| next_secret(secret)))
+            .sum::<i64>()
     }
-    let max = bought_map.values().max().expect("there should be a max");
-    for (k, v) in bought_map.iter() {
-        if v == max {
-            println!("{k:?}");
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut buyer_secrets: Vec<i64> = Vec::new();
+        for line in input.lines() {
+            buyer_secrets.push(line.parse().expect("there should be a number"));
         }
+        #[cfg(feature = "parallel")]
+        let market = buyer_secrets
+            .into_par_iter()
+            .fold(Market::new, |mut market, buyer| {
+                market.add_buyer(buyer);
+                market
+            })
+            .reduce(Market::new, Market::merge);
+        #[cfg(not(feature = "parallel"))]
+        let market = buyer_secrets
+            .into_iter()
+            .fold(Market::new(), |mut market, buyer| {
+                market.add_buyer(buyer);
+                market
+            });
+        market.best().into()
     }
-    max.to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day22::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day22::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -150,25 +172,29 @@ mod tests {
 
     #[test]
     fn test_next() {
-        let mut map = HashMap::new();
         let start = 123;
-        let mut current = get_next(start, &mut map);
+        let mut current = next_secret(start);
         let check = [
             15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
             5908254,
         ];
         for val in check {
             assert_eq!(current, val);
-            current = get_next(current, &mut map);
+            current = next_secret(current);
         }
     }
 
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "37327623");
+        assert_eq!(process_part1(file).unwrap(), "37327623");
         let file = include_str!("../test-input-2.txt");
-        //assert_eq!(process_part2_slow(file), "23");
-        assert_eq!(process_part2(file), "23");
+        assert_eq!(process_part2(file).unwrap(), "23");
+    }
+
+    #[test]
+    fn test_matches_oracle() {
+        let buyers: Vec<i64> = (1..=40).map(|i| i * 7919 % 16777216).collect();
+        assert_eq!(part2_market(&buyers), part2_oracle(&buyers));
     }
 }
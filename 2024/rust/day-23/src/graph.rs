@@ -0,0 +1,216 @@
+//! The LAN as a graph over two-letter computer names, each name packed into a
+//! `u16` id and each computer's links kept as a bitset over every possible id.
+
+use aoc_core::AocError;
+
+/// Every two-letter lowercase name, `aa` to `zz`.
+pub const NODES: usize = 26 * 26;
+const WORDS: usize = NODES.div_ceil(64);
+
+/// The id of a two-letter lowercase name, which sorts the same way the names
+/// do.
+pub fn node_id(name: &str) -> Option<u16> {
+    match name.as_bytes() {
+        &[a @ b'a'..=b'z', b @ b'a'..=b'z'] => Some(u16::from(a - b'a') * 26 + u16::from(b - b'a')),
+        _ => None,
+    }
+}
+
+pub fn node_name(id: u16) -> String {
+    let letter = |n: u16| char::from(b'a' + n as u8);
+    [letter(id / 26), letter(id % 26)].iter().collect()
+}
+
+/// Whether the computer's name starts with the lowercase `letter`.
+pub fn starts_with(id: u16, letter: u8) -> bool {
+    letter.is_ascii_lowercase() && id / 26 == u16::from(letter - b'a')
+}
+
+/// A set of node ids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeSet([u64; WORDS]);
+
+impl NodeSet {
+    pub const EMPTY: NodeSet = NodeSet([0; WORDS]);
+
+    pub fn insert(&mut self, id: u16) {
+        self.0[usize::from(id) / 64] |= 1 << (id % 64);
+    }
+
+    pub fn remove(&mut self, id: u16) {
+        self.0[usize::from(id) / 64] &= !(1 << (id % 64));
+    }
+
+    pub fn contains(&self, id: u16) -> bool {
+        self.0[usize::from(id) / 64] & 1 << (id % 64) != 0
+    }
+
+    pub fn len(&self) -> u32 {
+        self.0.iter().map(|word| word.count_ones()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().all(|&word| word == 0)
+    }
+
+    pub fn intersection(&self, other: &NodeSet) -> NodeSet {
+        NodeSet(std::array::from_fn(|i| self.0[i] & other.0[i]))
+    }
+
+    pub fn union(&self, other: &NodeSet) -> NodeSet {
+        NodeSet(std::array::from_fn(|i| self.0[i] | other.0[i]))
+    }
+
+    pub fn difference(&self, other: &NodeSet) -> NodeSet {
+        NodeSet(std::array::from_fn(|i| self.0[i] & !other.0[i]))
+    }
+
+    /// The ids, lowest first.
+    pub fn iter(&self) -> impl Iterator<Item = u16> + '_ {
+        self.0.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros();
+                    word &= word - 1;
+                    (i * 64) as u16 + bit as u16
+                })
+            })
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Graph {
+    nodes: NodeSet,
+    adjacency: Vec<NodeSet>,
+}
+
+impl Graph {
+    /// Reads one `ab-cd` link per line.
+    pub fn parse(input: &str) -> Result<Graph, AocError> {
+        let mut graph = Graph {
+            nodes: NodeSet::EMPTY,
+            adjacency: vec![NodeSet::EMPTY; NODES],
+        };
+        for (number, line) in input.lines().enumerate() {
+            let link = line
+                .split_once('-')
+                .and_then(|(a, b)| Some((node_id(a)?, node_id(b)?)));
+            let Some((a, b)) = link else {
+                return Err(AocError::parse(
+                    number + 1,
+                    1,
+                    format!("expected two-letter names like \"kh-tc\", got \"{line}\""),
+                ));
+            };
+            graph.add_link(a, b);
+        }
+        Ok(graph)
+    }
+
+    pub fn add_link(&mut self, a: u16, b: u16) {
+        self.nodes.insert(a);
+        self.nodes.insert(b);
+        self.adjacency[usize::from(a)].insert(b);
+        self.adjacency[usize::from(b)].insert(a);
+    }
+
+    pub fn neighbours(&self, id: u16) -> &NodeSet {
+        &self.adjacency[usize::from(id)]
+    }
+
+    /// Every set of three computers all linked to each other, each listed
+    /// once in ascending order.
+    pub fn triangles(&self) -> Vec<[u16; 3]> {
+        let mut triangles = vec![];
+        for a in self.nodes.iter() {
+            for b in self.neighbours(a).iter().filter(|&b| b > a) {
+                let common = self.neighbours(a).intersection(self.neighbours(b));
+                triangles.extend(common.iter().filter(|&c| c > b).map(|c| [a, b, c]));
+            }
+        }
+        triangles
+    }
+
+    /// The largest set of computers all linked to each other, lowest id
+    /// first, found with Bron–Kerbosch, pivoting on whichever candidate
+    /// leaves the fewest branches.
+    pub fn maximum_clique(&self) -> Vec<u16> {
+        let mut best = vec![];
+        self.bron_kerbosch(&mut vec![], self.nodes, NodeSet::EMPTY, &mut best);
+        best
+    }
+
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<u16>,
+        mut candidates: NodeSet,
+        mut excluded: NodeSet,
+        best: &mut Vec<u16>,
+    ) {
+        if candidates.is_empty() {
+            if excluded.is_empty() && clique.len() > best.len() {
+                best.clone_from(clique);
+                best.sort_unstable();
+            }
+            return;
+        }
+        // no clique through here can beat the best one found so far
+        if clique.len() + candidates.len() as usize <= best.len() {
+            return;
+        }
+        let pivot = candidates
+            .union(&excluded)
+            .iter()
+            .max_by_key(|&u| candidates.intersection(self.neighbours(u)).len())
+            .expect("candidates isn't empty");
+        for v in candidates.difference(self.neighbours(pivot)).iter() {
+            let neighbours = self.neighbours(v);
+            clique.push(v);
+            self.bron_kerbosch(
+                clique,
+                candidates.intersection(neighbours),
+                excluded.intersection(neighbours),
+                best,
+            );
+            clique.pop();
+            candidates.remove(v);
+            excluded.insert(v);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_graph() {
+        assert_eq!(node_id("aa"), Some(0));
+        assert_eq!(node_id("tc").map(node_name), Some("tc".to_string()));
+        assert!(node_id("kh").unwrap() < node_id("ta").unwrap());
+        assert_eq!(node_id("Tc"), None);
+        assert!(starts_with(node_id("tc").unwrap(), b't') && !starts_with(0, b't'));
+
+        // a square ab-cd-ef-gh with one diagonal, plus a lone link
+        let graph = Graph::parse("ab-cd\ncd-ef\nef-gh\ngh-ab\nab-ef\nxy-zz\n").unwrap();
+        let names = |ids: &[u16]| ids.iter().map(|&id| node_name(id)).collect::<Vec<_>>();
+        let triangles: Vec<_> = graph.triangles().iter().map(|t| names(t)).collect();
+        assert_eq!(triangles, [["ab", "cd", "ef"], ["ab", "ef", "gh"]]);
+        assert_eq!(names(&graph.maximum_clique()).len(), 3);
+
+        let mut complete = graph.clone();
+        complete.add_link(node_id("cd").unwrap(), node_id("gh").unwrap());
+        assert_eq!(names(&complete.maximum_clique()), ["ab", "cd", "ef", "gh"]);
+
+        assert_eq!(
+            Graph::parse("ab-cd\nabc-d\n").unwrap_err(),
+            AocError::parse(
+                2,
+                1,
+                "expected two-letter names like \"kh-tc\", got \"abc-d\""
+            )
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Checking a netlist adds its `x` and `y` inputs onto `z`, and finding the
+//! gate output swaps that make a broken ripple-carry adder work again.
+
+use std::cmp::Reverse;
+
+use aoc_core::AocError;
+
+use crate::netlist::Netlist;
+
+/// xorshift64, enough to pick test inputs without another dependency.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn next_wide(&mut self) -> u128 {
+        u128::from(self.next()) << 64 | u128::from(self.next())
+    }
+}
+
+/// The widest `x` and `y` inputs whose sum still fits in a `u128`.
+pub const MAX_WIDTH: usize = 127;
+
+fn mask(bits: usize) -> u128 {
+    if bits >= 128 {
+        u128::MAX
+    } else {
+        (1 << bits) - 1
+    }
+}
+
+/// Some random inputs, which catch most broken circuits straight away, then
+/// ones that single out each bit: one on either side, both, and a carry
+/// rippling up to it from the bottom.
+fn test_inputs(width: usize) -> Vec<(u128, u128)> {
+    let mut rng = Rng(0x2024_1224);
+    let mut tests: Vec<(u128, u128)> = (0..32)
+        .map(|_| (rng.next_wide() & mask(width), rng.next_wide() & mask(width)))
+        .collect();
+    tests.push((0, 0));
+    for bit in 0..width {
+        let one = 1 << bit;
+        tests.extend([
+            (one, 0),
+            (0, one),
+            (one, one),
+            (mask(bit + 1), 1),
+            (1, mask(bit + 1)),
+        ]);
+    }
+    tests
+}
+
+/// How many of the low bits of `z` come out right for every test, up to the
+/// whole output, counting the bits a loop keeps from being set as wrong.
+/// Gives up early once it's clear there are no more than `beat`.
+fn correct_bits(netlist: &Netlist, tests: &[(u128, u128)], beat: usize) -> usize {
+    let order = netlist.evaluation_order();
+    let mut correct = netlist.input_width() + 1;
+    for &(x, y) in tests {
+        let sum = x + y;
+        let right = netlist
+            .z_values(&order, x, y)
+            .iter()
+            .enumerate()
+            .take_while(|&(bit, &value)| {
+                value == Some(sum.checked_shr(bit as u32).unwrap_or(0) & 1 == 1)
+            })
+            .count();
+        correct = correct.min(right);
+        if correct <= beat {
+            break;
+        }
+    }
+    correct
+}
+
+/// Adds `rounds` random pairs, starting from `seed`, and returns the first
+/// pair the circuit gets wrong. Only the low [`MAX_WIDTH`] bits of wider
+/// inputs are ever set.
+pub fn random_test(netlist: &Netlist, rounds: usize, seed: u64) -> Result<(), (u128, u128)> {
+    let width = netlist.input_width().min(MAX_WIDTH);
+    let order = netlist.topological_order();
+    let mut rng = Rng(seed.max(1));
+    for _ in 0..rounds {
+        let (x, y) = (rng.next_wide() & mask(width), rng.next_wide() & mask(width));
+        let sum = order
+            .as_ref()
+            .and_then(|order| netlist.add_in_order(order, x, y));
+        if sum != Some(x + y) {
+            return Err((x, y));
+        }
+    }
+    Ok(())
+}
+
+/// For each gate, the highest `x`/`y` bit it depends on, which for a working
+/// adder is the bit the gate belongs to. Gates in a loop count as bit 0.
+fn gate_bits(netlist: &Netlist, order: &[usize]) -> Vec<usize> {
+    let mut wire_bits: Vec<Option<usize>> = vec![None; netlist.wire_count()];
+    for prefix in ['x', 'y'] {
+        for (bit, wire) in netlist.bus(prefix).into_iter().enumerate() {
+            wire_bits[wire] = Some(bit);
+        }
+    }
+    let mut gate_bits = vec![0; netlist.gate_count()];
+    for &gate in order {
+        let bit = netlist
+            .inputs_of(gate)
+            .iter()
+            .filter_map(|&wire| wire_bits[wire])
+            .max()
+            .unwrap_or(0);
+        gate_bits[gate] = bit;
+        wire_bits[netlist.output_of(gate)] = Some(bit);
+    }
+    gate_bits
+}
+
+/// Depth first over swaps that each get more low bits right, trying the
+/// biggest improvements first. The first bit that comes out wrong is read
+/// from a wire whose proper gate belongs to that bit or the couple below
+/// it, so one side of the swap is always one of those gates.
+fn search(
+    netlist: &mut Netlist,
+    tests: &[(u128, u128)],
+    left: usize,
+    swaps: &mut Vec<(usize, usize)>,
+) -> bool {
+    let correct = correct_bits(netlist, tests, 0);
+    if correct > netlist.input_width() {
+        return random_test(netlist, 1000, 0x5eed).is_ok();
+    }
+    if left == 0 {
+        return false;
+    }
+    let bits = gate_bits(netlist, &netlist.evaluation_order());
+    let nearby: Vec<usize> = (0..netlist.gate_count())
+        .filter(|&gate| (correct.saturating_sub(2)..=correct + 1).contains(&bits[gate]))
+        .collect();
+
+    let mut improving = vec![];
+    for &a in &nearby {
+        for b in 0..netlist.gate_count() {
+            if a == b || (nearby.contains(&b) && b < a) {
+                continue;
+            }
+            netlist.swap_outputs(a, b);
+            let after = correct_bits(netlist, tests, correct);
+            netlist.swap_outputs(a, b);
+            if after > correct {
+                improving.push((after, a, b));
+            }
+        }
+    }
+    improving.sort_by_key(|&(after, _, _)| Reverse(after));
+
+    for (_, a, b) in improving {
+        netlist.swap_outputs(a, b);
+        swaps.push((a, b));
+        if search(netlist, tests, left - 1, swaps) {
+            return true;
+        }
+        swaps.pop();
+        netlist.swap_outputs(a, b);
+    }
+    false
+}
+
+/// At most `limit` pairs of gates whose output wires, once swapped back, make
+/// `netlist` a working adder, as pairs of wire names. Adders of up to
+/// [`MAX_WIDTH`] bits are supported.
+pub fn repair(netlist: &Netlist, limit: usize) -> Result<Vec<(String, String)>, AocError> {
+    if netlist.input_width() > MAX_WIDTH {
+        return Err(AocError::unsupported(format!(
+            "a {}-bit adder is too wide to check, the most is {MAX_WIDTH} bits",
+            netlist.input_width()
+        )));
+    }
+    let mut netlist = netlist.clone();
+    let tests = test_inputs(netlist.input_width());
+    let mut swaps = vec![];
+    if !search(&mut netlist, &tests, limit, &mut swaps) {
+        return Err(AocError::solver(format!(
+            "no {limit} output swaps make the circuit add"
+        )));
+    }
+    // the swaps have been made, so each gate now drives its partner's old wire
+    Ok(swaps
+        .into_iter()
+        .map(|(a, b)| {
+            (
+                netlist.wire_name(netlist.output_of(b)).to_string(),
+                netlist.wire_name(netlist.output_of(a)).to_string(),
+            )
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::netlist::{Gate, GateType};
+
+    fn gate(left: &str, gate_type: GateType, right: &str, output: &str) -> Gate {
+        Gate {
+            left: left.to_string(),
+            right: right.to_string(),
+            output: output.to_string(),
+            gate_type,
+        }
+    }
+
+    fn ripple_carry_adder(width: usize) -> Vec<Gate> {
+        let mut gates = vec![
+            gate("x00", GateType::Xor, "y00", "z00"),
+            gate("x00", GateType::And, "y00", "c00"),
+        ];
+        for bit in 1..width {
+            let (x, y) = (format!("x{bit:02}"), format!("y{bit:02}"));
+            let (sum, carry) = (format!("s{bit:02}"), format!("c{:02}", bit - 1));
+            let carry_out = if bit + 1 == width {
+                format!("z{width:02}")
+            } else {
+                format!("c{bit:02}")
+            };
+            gates.extend([
+                gate(&x, GateType::Xor, &y, &sum),
+                gate(&x, GateType::And, &y, &format!("a{bit:02}")),
+                gate(&sum, GateType::Xor, &carry, &format!("z{bit:02}")),
+                gate(&sum, GateType::And, &carry, &format!("t{bit:02}")),
+                gate(
+                    &format!("a{bit:02}"),
+                    GateType::Or,
+                    &format!("t{bit:02}"),
+                    &carry_out,
+                ),
+            ]);
+        }
+        gates
+    }
+
+    fn swapped(mut gates: Vec<Gate>, pairs: &[(&str, &str)]) -> Vec<Gate> {
+        for gate in gates.iter_mut() {
+            for &(a, b) in pairs {
+                if gate.output == a {
+                    gate.output = b.to_string();
+                } else if gate.output == b {
+                    gate.output = a.to_string();
+                }
+            }
+        }
+        gates
+    }
+
+    fn sorted(mut pairs: Vec<(String, String)>) -> Vec<String> {
+        let mut wires: Vec<String> = pairs.drain(..).flat_map(|(a, b)| [a, b]).collect();
+        wires.sort();
+        wires
+    }
+
+    #[test]
+    fn test_repair() {
+        let adder = Netlist::new(&ripple_carry_adder(12)).unwrap();
+        assert_eq!(random_test(&adder, 100, 1), Ok(()));
+        assert_eq!(repair(&adder, 4).unwrap(), []);
+
+        // an input layer swap, a sum swapped with an output, a carry with an
+        // output further up, and two wires between bits
+        let pairs = [
+            ("s03", "a03"),
+            ("z05", "t05"),
+            ("c07", "z10"),
+            ("t09", "s10"),
+        ];
+        let broken = Netlist::new(&swapped(ripple_carry_adder(12), &pairs)).unwrap();
+        assert!(random_test(&broken, 100, 1).is_err());
+        assert_eq!(
+            sorted(repair(&broken, 4).unwrap()),
+            ["a03", "c07", "s03", "s10", "t05", "t09", "z05", "z10"]
+        );
+        assert!(matches!(repair(&broken, 3), Err(AocError::Solver(_))));
+    }
+
+    #[test]
+    fn test_widest() {
+        let widest = Netlist::new(&ripple_carry_adder(MAX_WIDTH)).unwrap();
+        assert_eq!(widest.add(mask(MAX_WIDTH), 1), Some(1 << MAX_WIDTH));
+        assert_eq!(random_test(&widest, 100, 1), Ok(()));
+        assert_eq!(repair(&widest, 4).unwrap(), []);
+
+        let too_wide = Netlist::new(&ripple_carry_adder(MAX_WIDTH + 1)).unwrap();
+        assert!(matches!(
+            repair(&too_wide, 4),
+            Err(AocError::Unsupported(_))
+        ));
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_2024_day_24::dot;
+
+fn main() {
+    let file = aoc_core::input::from_args(env!("CARGO_MANIFEST_DIR"));
+    aoc_core::print_or_exit(dot(&file).map(Into::into));
+}
@@ -0,0 +1,352 @@
+//! The gates as a circuit: wires numbered in the order they're first seen,
+//! every gate knowing which wires it reads and drives.
+
+use std::collections::{HashMap, VecDeque};
+
+use aoc_core::AocError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GateType {
+    And,
+    Or,
+    Xor,
+}
+
+impl GateType {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            GateType::And => a & b,
+            GateType::Or => a | b,
+            GateType::Xor => a ^ b,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            GateType::And => "AND",
+            GateType::Or => "OR",
+            GateType::Xor => "XOR",
+        }
+    }
+}
+
+/// A gate as the input writes it, with wire names.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Gate {
+    pub left: String,
+    pub right: String,
+    pub output: String,
+    pub gate_type: GateType,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Node {
+    gate_type: GateType,
+    inputs: [usize; 2],
+    output: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Netlist {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    nodes: Vec<Node>,
+    /// the gate driving each wire, if any
+    driver: Vec<Option<usize>>,
+    /// the `x`, `y` and `z` buses, looked up once
+    x: Vec<usize>,
+    y: Vec<usize>,
+    z: Vec<usize>,
+}
+
+impl Netlist {
+    pub fn new(gates: &[Gate]) -> Result<Netlist, AocError> {
+        let mut netlist = Netlist {
+            names: vec![],
+            ids: HashMap::new(),
+            nodes: vec![],
+            driver: vec![],
+            x: vec![],
+            y: vec![],
+            z: vec![],
+        };
+        for gate in gates {
+            let inputs = [netlist.wire(&gate.left), netlist.wire(&gate.right)];
+            let output = netlist.wire(&gate.output);
+            if netlist.driver[output].is_some() {
+                return Err(AocError::unsupported(format!(
+                    "{} is driven by more than one gate",
+                    gate.output
+                )));
+            }
+            netlist.driver[output] = Some(netlist.nodes.len());
+            netlist.nodes.push(Node {
+                gate_type: gate.gate_type,
+                inputs,
+                output,
+            });
+        }
+        netlist.x = netlist.bus('x');
+        netlist.y = netlist.bus('y');
+        netlist.z = netlist.bus('z');
+        Ok(netlist)
+    }
+
+    fn wire(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        self.names.push(name.to_string());
+        self.driver.push(None);
+        self.ids.insert(name.to_string(), self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    pub fn wire_name(&self, wire: usize) -> &str {
+        &self.names[wire]
+    }
+
+    pub fn wire_count(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn gate_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// The wire a gate drives.
+    pub fn output_of(&self, gate: usize) -> usize {
+        self.nodes[gate].output
+    }
+
+    /// The wires a gate reads.
+    pub fn inputs_of(&self, gate: usize) -> [usize; 2] {
+        self.nodes[gate].inputs
+    }
+
+    pub fn driver_of(&self, wire: usize) -> Option<usize> {
+        self.driver[wire]
+    }
+
+    /// How many bits the `x` input has.
+    pub fn input_width(&self) -> usize {
+        self.x.len()
+    }
+
+    pub fn z_wire(&self, bit: usize) -> Option<usize> {
+        self.z.get(bit).copied()
+    }
+
+    /// The wires named `prefix` and a number, like `x00`, lowest bit first.
+    pub fn bus(&self, prefix: char) -> Vec<usize> {
+        let mut bus: Vec<(u32, usize)> = self
+            .names
+            .iter()
+            .enumerate()
+            .filter_map(|(id, name)| {
+                let number = name.strip_prefix(prefix)?.parse().ok()?;
+                Some((number, id))
+            })
+            .collect();
+        bus.sort_unstable();
+        bus.into_iter().map(|(_, id)| id).collect()
+    }
+
+    /// Swaps which wires two gates drive.
+    pub fn swap_outputs(&mut self, a: usize, b: usize) {
+        let (wire_a, wire_b) = (self.nodes[a].output, self.nodes[b].output);
+        self.nodes[a].output = wire_b;
+        self.nodes[b].output = wire_a;
+        self.driver[wire_a] = Some(b);
+        self.driver[wire_b] = Some(a);
+    }
+
+    /// The gates in an order where each comes after the gates driving its
+    /// inputs, or `None` if the wiring loops.
+    pub fn topological_order(&self) -> Option<Vec<usize>> {
+        let order = self.evaluation_order();
+        (order.len() == self.nodes.len()).then_some(order)
+    }
+
+    /// [`Netlist::topological_order`], leaving out the gates in a loop and
+    /// everything they feed.
+    pub fn evaluation_order(&self) -> Vec<usize> {
+        let mut readers: Vec<Vec<usize>> = vec![vec![]; self.names.len()];
+        let mut waiting: Vec<usize> = vec![0; self.nodes.len()];
+        for (gate, node) in self.nodes.iter().enumerate() {
+            for input in node.inputs {
+                readers[input].push(gate);
+                if self.driver[input].is_some() {
+                    waiting[gate] += 1;
+                }
+            }
+        }
+        let mut ready: VecDeque<usize> = (0..self.nodes.len())
+            .filter(|&gate| waiting[gate] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(gate) = ready.pop_front() {
+            order.push(gate);
+            for &reader in &readers[self.nodes[gate].output] {
+                waiting[reader] -= 1;
+                if waiting[reader] == 0 {
+                    ready.push_back(reader);
+                }
+            }
+        }
+        order
+    }
+
+    /// Every wire's value, given the values of the wires no gate drives.
+    /// Wires that can't be worked out are `None`.
+    pub fn evaluate(&self, order: &[usize], inputs: &HashMap<usize, bool>) -> Vec<Option<bool>> {
+        let mut values: Vec<Option<bool>> = (0..self.names.len())
+            .map(|wire| inputs.get(&wire).copied())
+            .collect();
+        self.propagate(order, &mut values);
+        values
+    }
+
+    fn propagate(&self, order: &[usize], values: &mut [Option<bool>]) {
+        for &gate in order {
+            let node = self.nodes[gate];
+            values[node.output] = match (values[node.inputs[0]], values[node.inputs[1]]) {
+                (Some(a), Some(b)) => Some(node.gate_type.apply(a, b)),
+                _ => None,
+            };
+        }
+    }
+
+    /// What the circuit outputs on the `z` wires for `x` and `y` on the `x`
+    /// and `y` wires, or `None` if it loops, leaves an output unset or has
+    /// more than 128 of them.
+    pub fn add(&self, x: u128, y: u128) -> Option<u128> {
+        let order = self.topological_order()?;
+        self.add_in_order(&order, x, y)
+    }
+
+    /// [`Netlist::add`] with the order already worked out.
+    pub fn add_in_order(&self, order: &[usize], x: u128, y: u128) -> Option<u128> {
+        let values = self.z_values(order, x, y);
+        values
+            .iter()
+            .enumerate()
+            .try_fold(0, |number, (bit, value)| {
+                Some(number | u128::from((*value)?).checked_shl(bit as u32)?)
+            })
+    }
+
+    /// Each `z` wire's value for `x` and `y`, lowest bit first, where `order`
+    /// may leave some gates out. Input wires past the 128th are set to 0.
+    pub fn z_values(&self, order: &[usize], x: u128, y: u128) -> Vec<Option<bool>> {
+        let mut values = vec![None; self.names.len()];
+        for (bus, value) in [(&self.x, x), (&self.y, y)] {
+            for (bit, &wire) in bus.iter().enumerate() {
+                let bit_value = value.checked_shr(bit as u32).unwrap_or(0);
+                values[wire] = Some(bit_value & 1 == 1);
+            }
+        }
+        self.propagate(order, &mut values);
+        self.z.iter().map(|&wire| values[wire]).collect()
+    }
+
+    /// The circuit in Graphviz DOT, one box per gate with the wires as
+    /// labelled edges and the `x`, `y` and `z` wires as their own nodes.
+    pub fn to_dot(&self) -> String {
+        let mut dot = vec!["digraph netlist {".to_string(), "  rankdir=LR;".to_string()];
+        for (wire, name) in self.names.iter().enumerate() {
+            let undriven = self.driver[wire].is_none();
+            if undriven || name.starts_with('z') {
+                dot.push(format!("  \"{name}\" [shape=ellipse];"));
+            }
+        }
+        for (gate, node) in self.nodes.iter().enumerate() {
+            dot.push(format!(
+                "  g{gate} [shape=box, label=\"{}\"];",
+                node.gate_type.name()
+            ));
+            for input in node.inputs {
+                let from = match self.driver[input] {
+                    Some(source) => format!("g{source}"),
+                    None => format!("\"{}\"", self.names[input]),
+                };
+                let label = &self.names[input];
+                dot.push(format!("  {from} -> g{gate} [label=\"{label}\"];"));
+            }
+            let output = &self.names[node.output];
+            if output.starts_with('z') {
+                dot.push(format!("  g{gate} -> \"{output}\";"));
+            }
+        }
+        dot.push("}".to_string());
+        dot.join("\n")
+    }
+}
+
+/// The number the wires in `bus` spell out, lowest bit first.
+pub fn bus_value(values: &[Option<bool>], bus: &[usize]) -> Option<u64> {
+    bus.iter().enumerate().try_fold(0, |number, (bit, &wire)| {
+        Some(number | u64::from(values[wire]?) << bit)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gate(left: &str, gate_type: GateType, right: &str, output: &str) -> Gate {
+        Gate {
+            left: left.to_string(),
+            right: right.to_string(),
+            output: output.to_string(),
+            gate_type,
+        }
+    }
+
+    #[test]
+    fn test_netlist() {
+        // a half adder, listed with the carry before what it reads
+        let mut netlist = Netlist::new(&[
+            gate("x00", GateType::And, "y00", "z01"),
+            gate("x00", GateType::Xor, "y00", "z00"),
+        ])
+        .unwrap();
+        assert_eq!(netlist.add(1, 1), Some(0b10));
+        assert_eq!(netlist.add(1, 0), Some(0b01));
+        netlist.swap_outputs(0, 1);
+        assert_eq!(netlist.add(1, 0), Some(0b10));
+        assert_eq!(
+            netlist.to_dot(),
+            "digraph netlist {
+  rankdir=LR;
+  \"x00\" [shape=ellipse];
+  \"y00\" [shape=ellipse];
+  \"z01\" [shape=ellipse];
+  \"z00\" [shape=ellipse];
+  g0 [shape=box, label=\"AND\"];
+  \"x00\" -> g0 [label=\"x00\"];
+  \"y00\" -> g0 [label=\"y00\"];
+  g0 -> \"z00\";
+  g1 [shape=box, label=\"XOR\"];
+  \"x00\" -> g1 [label=\"x00\"];
+  \"y00\" -> g1 [label=\"y00\"];
+  g1 -> \"z01\";
+}"
+        );
+
+        let looped = Netlist::new(&[
+            gate("x00", GateType::And, "b", "a"),
+            gate("a", GateType::Or, "y00", "b"),
+            gate("a", GateType::Xor, "b", "z00"),
+        ])
+        .unwrap();
+        assert_eq!(looped.topological_order(), None);
+        assert_eq!(looped.add(1, 1), None);
+
+        let twice = Netlist::new(&[
+            gate("x00", GateType::And, "y00", "z00"),
+            gate("x00", GateType::Or, "y00", "z00"),
+        ]);
+        assert!(matches!(twice, Err(AocError::Unsupported(_))));
+    }
+}
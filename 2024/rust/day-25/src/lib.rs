@@ -1,5 +1,7 @@
+use aoc_core::{Answer, AocError, Solution};
+
 #[derive(Debug, Clone, Copy)]
-enum KeyLock {
+pub enum KeyLock {
     Lock([i8; 5]),
     Key([i8; 5]),
 }
@@ -21,7 +23,7 @@ impl KeyLock {
 
 fn parse(input: &str) -> Vec<KeyLock> {
     let mut res: Vec<KeyLock> = Vec::new();
-    for (c, split) in input.split("\n\n").enumerate() {
+    for split in input.split("\n\n") {
         let mut is_lock = false;
         let mut stack: [i8; 5] = [0, 0, 0, 0, 0];
         for (y, line) in split.lines().enumerate() {
@@ -54,35 +56,56 @@ fn parse(input: &str) -> Vec<KeyLock> {
     res
 }
 
-pub fn process_part1(input: &str) -> String {
-    let keys_locks = parse(input);
-    let keys: Vec<KeyLock> = keys_locks
-        .iter()
-        .filter(|x| !x.is_lock())
-        .copied()
-        .collect();
-    let locks: Vec<KeyLock> = keys_locks.iter().filter(|x| x.is_lock()).copied().collect();
-    let mut doesnt_fit = 0;
-    for lock in locks.iter() {
-        for key in keys.iter() {
-            for i in 0..5 {
-                //println!("comparing {:?} to {:?}", lock.inner(), key.inner());
-                if (lock.inner()[i] + key.inner()[i]) > 5 {
-                    // does not fit
-                    doesnt_fit += 1;
-                    break;
-                } else {
-                    // does fit
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 25;
+    type Parsed<'a> = Vec<KeyLock>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse(input)
+    }
+
+    fn part1(keys_locks: Self::Parsed<'_>) -> Self::Part1 {
+        let keys: Vec<KeyLock> = keys_locks
+            .iter()
+            .filter(|x| !x.is_lock())
+            .copied()
+            .collect();
+        let locks: Vec<KeyLock> = keys_locks.iter().filter(|x| x.is_lock()).copied().collect();
+        let mut doesnt_fit = 0;
+        for lock in locks.iter() {
+            for key in keys.iter() {
+                for i in 0..5 {
+                    //println!("comparing {:?} to {:?}", lock.inner(), key.inner());
+                    if (lock.inner()[i] + key.inner()[i]) > 5 {
+                        // does not fit
+                        doesnt_fit += 1;
+                        break;
+                    } else {
+                        // does fit
+                    }
                 }
             }
         }
+        (keys.len() * locks.len()) - doesnt_fit
+    }
+
+    // there is no second puzzle on the last day
+    fn part2(_keys_locks: Self::Parsed<'_>) -> Self::Part2 {
+        "works".to_string()
     }
-    let fit_count = (keys.len() * locks.len()) - doesnt_fit;
-    fit_count.to_string()
 }
 
-pub fn process_part2(input: &str) -> String {
-    "works".to_string()
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day25::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day25::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -92,6 +115,6 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "3");
+        assert_eq!(process_part1(file).unwrap(), "3");
     }
 }
@@ -1,3 +1,5 @@
+use aoc_core::{Answer, AocError, Solution};
+
 fn left(distance: i32, start: &mut i32) {
     let res = (*start - distance) % 100;
     if res < 0 {
@@ -13,29 +15,13 @@ fn right(distance: i32, start: &mut i32) {
     //println!("R{distance}: {}", *start);
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut zero_count = 0;
-    let mut value = 50;
-    for line in input.lines() {
-        let (direction, count) = line.split_at(1);
-        match direction {
-            "L" => left(count.parse::<i32>().unwrap(), &mut value),
-            "R" => right(count.parse::<i32>().unwrap(), &mut value),
-            _ => panic!("this isn't a valid letter"),
-        }
-        if value == 0 {
-            zero_count += 1;
-        }
-    }
-    zero_count.to_string()
-}
-
 struct Dial {
     zero_passes: i32,
     reading: i32,
 }
 
 impl Dial {
+    #[allow(dead_code)]
     fn single_left(&mut self) {
         match self.reading {
             0 => self.reading = 99,
@@ -51,6 +37,7 @@ impl Dial {
             }
         }
     }
+    #[allow(dead_code)]
     fn single_right(&mut self) {
         match self.reading {
             99 => {
@@ -65,11 +52,13 @@ impl Dial {
             }
         }
     }
+    #[allow(dead_code)]
     pub fn left_slow(&mut self, distance: i32) {
         for _ in 1..=distance {
             self.single_left();
         }
     }
+    #[allow(dead_code)]
     pub fn right_slow(&mut self, distance: i32) {
         for _ in 1..=distance {
             self.single_right();
@@ -103,7 +92,7 @@ impl Dial {
     }
     pub fn right_faster(&mut self, distance: i32) {
         let start = self.reading;
-        if start > 99 || start < 0 {
+        if !(0..=99).contains(&start) {
             panic!("dial reading is invalid {}", self.reading)
         }
         self.reading += distance;
@@ -114,20 +103,59 @@ impl Dial {
     }
 }
 
-pub fn process_part2(input: &str) -> String {
-    let mut safe: Dial = Dial {
-        zero_passes: 0,
-        reading: 50,
-    };
-    for line in input.lines() {
-        let (direction, count) = line.split_at(1);
-        match direction {
-            "L" => safe.left_faster(count.parse::<i32>().unwrap()),
-            "R" => safe.right_faster(count.parse::<i32>().unwrap()),
-            _ => panic!("this isn't a valid letter"),
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+    type Parsed<'a> = &'a str;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut zero_count = 0;
+        let mut value = 50;
+        for line in input.lines() {
+            let (direction, count) = line.split_at(1);
+            match direction {
+                "L" => left(count.parse::<i32>().unwrap(), &mut value),
+                "R" => right(count.parse::<i32>().unwrap(), &mut value),
+                _ => panic!("this isn't a valid letter"),
+            }
+            if value == 0 {
+                zero_count += 1;
+            }
+        }
+        zero_count
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut safe: Dial = Dial {
+            zero_passes: 0,
+            reading: 50,
+        };
+        for line in input.lines() {
+            let (direction, count) = line.split_at(1);
+            match direction {
+                "L" => safe.left_faster(count.parse::<i32>().unwrap()),
+                "R" => safe.right_faster(count.parse::<i32>().unwrap()),
+                _ => panic!("this isn't a valid letter"),
+            }
         }
+        safe.zero_passes
     }
-    safe.zero_passes.to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day01::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -137,9 +165,9 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "3");
-        assert_eq!(process_part2(file), "6");
+        assert_eq!(process_part1(file).unwrap(), "3");
+        assert_eq!(process_part2(file).unwrap(), "6");
         let file = include_str!("../input.txt");
-        assert_eq!(process_part2(file), "5923");
+        assert_eq!(process_part2(file).unwrap(), "5923");
     }
 }
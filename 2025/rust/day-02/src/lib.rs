@@ -1,50 +1,74 @@
-pub fn process_part1(input: &str) -> String {
-    let mut invalid_ids: Vec<u64> = Vec::new();
-    for group in input.split(',') {
-        let mut range = group.split("-");
-        let left: u64 = range.next().unwrap().parse().unwrap();
-        let right: u64 = range.next().unwrap().parse().unwrap();
-        for value in left..=right {
-            let stringed = format!("{value}");
-            if (stringed.len() % 2) == 0 {
-                let (str_left, str_right) = stringed.split_at(stringed.len() / 2);
-                if str_left == str_right {
-                    invalid_ids.push(value);
+use aoc_core::{Answer, AocError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+    type Parsed<'a> = &'a str;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut invalid_ids: Vec<u64> = Vec::new();
+        for group in input.split(',') {
+            let mut range = group.split("-");
+            let left: u64 = range.next().unwrap().parse().unwrap();
+            let right: u64 = range.next().unwrap().parse().unwrap();
+            for value in left..=right {
+                let stringed = format!("{value}");
+                if (stringed.len() % 2) == 0 {
+                    let (str_left, str_right) = stringed.split_at(stringed.len() / 2);
+                    if str_left == str_right {
+                        invalid_ids.push(value);
+                    }
                 }
             }
         }
+        invalid_ids.iter().sum::<u64>()
     }
-    invalid_ids.iter().sum::<u64>().to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let mut invalid_ids: Vec<u64> = Vec::new();
-    for group in input.split(',') {
-        let mut range = group.split("-");
-        let left: u64 = range.next().unwrap().parse().unwrap();
-        let right: u64 = range.next().unwrap().parse().unwrap();
-        for value in left..=right {
-            let stringed = format!("{value}");
-            'windows: for window_size in 1..=stringed.len() / 2 {
-                if stringed.len() % window_size == 0 {
-                    let str_chunks: Vec<String> = stringed
-                        .chars()
-                        .collect::<Vec<char>>()
-                        .chunks(window_size)
-                        .map(|chunk| chunk.iter().collect::<String>())
-                        .collect();
-                    for i in 0..str_chunks.len() - 1 {
-                        if str_chunks[i] != str_chunks[i + 1] {
-                            continue 'windows;
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut invalid_ids: Vec<u64> = Vec::new();
+        for group in input.split(',') {
+            let mut range = group.split("-");
+            let left: u64 = range.next().unwrap().parse().unwrap();
+            let right: u64 = range.next().unwrap().parse().unwrap();
+            for value in left..=right {
+                let stringed = format!("{value}");
+                'windows: for window_size in 1..=stringed.len() / 2 {
+                    if stringed.len() % window_size == 0 {
+                        let str_chunks: Vec<String> = stringed
+                            .chars()
+                            .collect::<Vec<char>>()
+                            .chunks(window_size)
+                            .map(|chunk| chunk.iter().collect::<String>())
+                            .collect();
+                        for i in 0..str_chunks.len() - 1 {
+                            if str_chunks[i] != str_chunks[i + 1] {
+                                continue 'windows;
+                            }
                         }
+                        invalid_ids.push(value);
+                        break;
                     }
-                    invalid_ids.push(value);
-                    break;
                 }
             }
         }
+        invalid_ids.iter().sum::<u64>()
     }
-    invalid_ids.iter().sum::<u64>().to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day02::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day02::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -54,7 +78,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "1227775554");
-        assert_eq!(process_part2(file), "4174379265");
+        assert_eq!(process_part1(file).unwrap(), "1227775554");
+        assert_eq!(process_part2(file).unwrap(), "4174379265");
     }
 }
@@ -1,5 +1,7 @@
+use aoc_core::{Answer, AocError, Solution};
+
 fn find_largest_pos_with_padding(
-    input: &Vec<u64>,
+    input: &[u64],
     full: bool,
     start: usize,
     remaining: usize,
@@ -55,20 +57,42 @@ fn process_max_value(input: &str, batteries: usize) -> u64 {
     total
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut batteries: Vec<u64> = Vec::new();
-    for line in input.lines() {
-        batteries.push(process_max_value(line, 2))
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+    type Parsed<'a> = &'a str;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut batteries: Vec<u64> = Vec::new();
+        for line in input.lines() {
+            batteries.push(process_max_value(line, 2))
+        }
+        batteries.iter().sum::<u64>()
     }
-    batteries.iter().sum::<u64>().to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let mut batteries: Vec<u64> = Vec::new();
-    for line in input.lines() {
-        batteries.push(process_max_value(line, 12))
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut batteries: Vec<u64> = Vec::new();
+        for line in input.lines() {
+            batteries.push(process_max_value(line, 12))
+        }
+        batteries.iter().sum::<u64>()
     }
-    batteries.iter().sum::<u64>().to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day03::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day03::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -78,7 +102,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "357");
-        assert_eq!(process_part2(file), "3121910778619");
+        assert_eq!(process_part1(file).unwrap(), "357");
+        assert_eq!(process_part2(file).unwrap(), "3121910778619");
     }
 }
@@ -1,140 +1,98 @@
+use aoc_core::{Answer, AocError, Solution};
+use aoc_grid::{Coord, Grid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Square {
     Roll,
     Empty,
 }
 
-struct Coord {
-    row: usize,
-    col: usize,
-}
+impl TryFrom<char> for Square {
+    type Error = char;
 
-fn check_neighbors(map: &Vec<Vec<Square>>, row: usize, col: usize) -> usize {
-    let mut filled = 0;
-    //up left
-    if row > 0 && col > 0 {
-        match map[row - 1][col - 1] {
-            Square::Roll => filled += 1,
-            Square::Empty => {}
-        }
-    }
-    //up
-    if row > 0 {
-        match map[row - 1][col] {
-            Square::Roll => filled += 1,
-            Square::Empty => {}
-        }
-    }
-    //up right
-    if row > 0 && col + 1 < map[0].len() {
-        match map[row - 1][col + 1] {
-            Square::Roll => filled += 1,
-            Square::Empty => {}
-        }
-    }
-    //left
-    if col > 0 {
-        match map[row][col - 1] {
-            Square::Roll => filled += 1,
-            Square::Empty => {}
-        }
-    }
-    //right
-    if col + 1 < map[0].len() {
-        match map[row][col + 1] {
-            Square::Roll => filled += 1,
-            Square::Empty => {}
-        }
-    }
-    //down left
-    if row + 1 < map.len() && col > 0 {
-        match map[row + 1][col - 1] {
-            Square::Roll => filled += 1,
-            Square::Empty => {}
-        }
-    }
-    //down
-    if row + 1 < map.len() {
-        match map[row + 1][col] {
-            Square::Roll => filled += 1,
-            Square::Empty => {}
+    fn try_from(char: char) -> Result<Self, Self::Error> {
+        match char {
+            '.' => Ok(Square::Empty),
+            '@' => Ok(Square::Roll),
+            _ => Err(char),
         }
     }
-    //down right
-    if row + 1 < map.len() && col + 1 < map[0].len() {
-        match map[row + 1][col + 1] {
-            Square::Roll => filled += 1,
-            Square::Empty => {}
-        }
-    }
-    filled
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut accessible = 0;
-    let mut map: Vec<Vec<Square>> = Vec::new();
-    for line in input.lines() {
-        let mut map_row: Vec<Square> = Vec::new();
-        for char in line.chars() {
-            match char {
-                '.' => map_row.push(Square::Empty),
-                '@' => map_row.push(Square::Roll),
-                _ => panic!("invalid character {char}"),
-            }
-        }
-        map.push(map_row);
+fn parse(input: &str) -> Grid<Square> {
+    input
+        .parse()
+        .unwrap_or_else(|e| panic!("invalid character: {e}"))
+}
+
+fn check_neighbors(map: &Grid<Square>, position: Coord) -> usize {
+    map.neighbours8(position)
+        .filter(|neighbor| map[*neighbor] == Square::Roll)
+        .count()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+    type Parsed<'a> = &'a str;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
     }
-    for (row, line) in map.iter().enumerate() {
-        for (col, square) in line.iter().enumerate() {
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut accessible = 0;
+        let map = parse(input);
+        for (position, square) in map.iter() {
             match square {
                 Square::Empty => {}
                 Square::Roll => {
-                    if check_neighbors(&map, row, col) < 4 {
+                    if check_neighbors(&map, position) < 4 {
                         accessible += 1;
                     }
                 }
             }
         }
+        accessible
     }
-    accessible.to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let mut removals = 0;
-    let mut map: Vec<Vec<Square>> = Vec::new();
-    for line in input.lines() {
-        let mut map_row: Vec<Square> = Vec::new();
-        for char in line.chars() {
-            match char {
-                '.' => map_row.push(Square::Empty),
-                '@' => map_row.push(Square::Roll),
-                _ => panic!("invalid character {char}"),
-            }
-        }
-        map.push(map_row);
-    }
-    let mut removals_this_loop = 1;
-    while removals_this_loop > 0 {
-        removals_this_loop = 0;
-        let mut removal_list: Vec<Coord> = Vec::new();
-        for (row, line) in map.iter().enumerate() {
-            for (col, square) in line.iter().enumerate() {
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut removals = 0;
+        let mut map = parse(input);
+        let mut removals_this_loop = 1;
+        while removals_this_loop > 0 {
+            removals_this_loop = 0;
+            let mut removal_list: Vec<Coord> = Vec::new();
+            for (position, square) in map.iter() {
                 match square {
                     Square::Empty => {}
                     Square::Roll => {
-                        if check_neighbors(&map, row, col) < 4 {
-                            removal_list.push(Coord { row, col });
+                        if check_neighbors(&map, position) < 4 {
+                            removal_list.push(position);
                             removals += 1;
                             removals_this_loop += 1;
                         }
                     }
                 }
             }
+            for spot in removal_list {
+                map[spot] = Square::Empty;
+            }
         }
-        for spot in removal_list {
-            map[spot.row][spot.col] = Square::Empty;
-        }
+        removals
     }
-    removals.to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day04::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day04::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -144,7 +102,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "13");
-        assert_eq!(process_part2(file), "43");
+        assert_eq!(process_part1(file).unwrap(), "13");
+        assert_eq!(process_part2(file).unwrap(), "43");
     }
 }
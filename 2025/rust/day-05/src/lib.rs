@@ -1,54 +1,67 @@
-use rangemap::RangeInclusiveSet;
+use aoc_core::{Answer, AocError, Solution};
+use aoc_interval::IntervalSet;
+use std::ops::Range;
 
-fn process_range(input: &str) -> (usize, usize) {
+/// An inclusive `a-b` line as the half-open range `a..b + 1`.
+fn process_range(input: &str) -> Range<i64> {
     let (left, right) = input.split_once("-").unwrap();
-    (left.parse().unwrap(), right.parse().unwrap())
+    left.parse().unwrap()..right.parse::<i64>().unwrap() + 1
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut coverage = RangeInclusiveSet::new();
-    let mut range_mode = true;
-    let mut fresh_count = 0;
-    for line in input.lines() {
-        if line.len() == 0 {
-            range_mode = false;
-            //for range in coverage.clone() {
-            //    println!("{}-{}", range.start(), range.end());
-            //}
-            continue;
-        }
-        if range_mode {
-            let (start, end) = process_range(line);
-            coverage.insert(start..=end);
-        } else {
-            let id: usize = line.parse().unwrap();
-            if coverage.contains(&id) {
-                fresh_count += 1;
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+    type Parsed<'a> = &'a str;
+    type Part1 = i32;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut coverage = IntervalSet::new();
+        let mut range_mode = true;
+        let mut fresh_count = 0;
+        for line in input.lines() {
+            if line.is_empty() {
+                range_mode = false;
+                continue;
+            }
+            if range_mode {
+                coverage.insert(process_range(line));
             } else {
-                //println!("id: {id} spoiled!") // spoiled
+                let id: i64 = line.parse().unwrap();
+                if coverage.contains(id) {
+                    fresh_count += 1;
+                } else {
+                    //println!("id: {id} spoiled!") // spoiled
+                }
             }
         }
+        fresh_count
     }
-    fresh_count.to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let mut coverage = RangeInclusiveSet::new();
-    let mut fresh_count = 0;
-    for line in input.lines() {
-        if line.len() == 0 {
-            //for range in coverage.clone() {
-            //    println!("{}-{}", range.start(), range.end());
-            //}
-            break;
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut coverage = IntervalSet::new();
+        for line in input.lines() {
+            if line.is_empty() {
+                break;
+            }
+            coverage.insert(process_range(line));
         }
-        let (start, end) = process_range(line);
-        coverage.insert(start..=end);
-    }
-    for range in coverage {
-        fresh_count += range.end() - range.start() + 1;
+        coverage.len()
     }
-    fresh_count.to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day05::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day05::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -58,7 +71,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "3");
-        assert_eq!(process_part2(file), "14");
+        assert_eq!(process_part1(file).unwrap(), "3");
+        assert_eq!(process_part2(file).unwrap(), "14");
     }
 }
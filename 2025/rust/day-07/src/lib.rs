@@ -1,3 +1,6 @@
+use aoc_core::{Answer, AocError, Solution};
+use aoc_grid::{Coord, Direction, Grid};
+use aoc_parse::{grid, parse_all};
 use std::collections::BTreeMap;
 
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -8,270 +11,155 @@ enum Tile {
     Beam,
 }
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
-struct Coord {
-    x: usize,
-    y: usize,
-}
+impl TryFrom<char> for Tile {
+    type Error = char;
 
-impl Coord {
-    pub fn new(x: usize, y: usize) -> Coord {
-        Coord { x, y }
+    fn try_from(char: char) -> Result<Self, Self::Error> {
+        match char {
+            '.' => Ok(Tile::Empty),
+            'S' => Ok(Tile::Start),
+            '^' => Ok(Tile::Splitter),
+            _ => Err(char),
+        }
     }
 }
 
-struct ActivatedTiles {
-    beam: Vec<Coord>,
-    splitter: Option<Coord>,
+fn parse(input: &str) -> Result<(Grid<Tile>, Coord), AocError> {
+    let map: Grid<Tile> = parse_all(input, grid)?;
+    let start_pos = map
+        .position(|tile| *tile == Tile::Start)
+        .ok_or_else(|| AocError::unsupported("there should be a start"))?;
+    Ok((map, start_pos))
+}
+
+/// What a beam runs into on its next step down.
+enum Activated {
+    Beam(Coord),
+    Splitter(Coord),
+    /// it left the manifold or joined a beam already there
+    Nothing,
 }
 
 struct Data {
-    map: Vec<Vec<Tile>>,
+    map: Grid<Tile>,
     activated_splitters: Vec<Coord>,
     active_beams: Vec<Coord>,
     active_splitters: Vec<Coord>,
 }
 
 impl Data {
-    fn new(input: &str) -> Data {
-        let mut map: Vec<Vec<Tile>> = Vec::new();
-        let mut start_pos: Coord = Coord { x: 0, y: 0 };
-        let mut start_found = false;
-        for (y, line) in input.lines().enumerate() {
-            let mut row: Vec<Tile> = Vec::new();
-            for (x, char) in line.chars().enumerate() {
-                match char {
-                    '.' => row.push(Tile::Empty),
-                    'S' => {
-                        row.push(Tile::Start);
-                        start_pos = Coord::new(x, y);
-                        start_found = true;
-                    }
-                    '^' => row.push(Tile::Splitter),
-                    _ => panic!("invalid character {char}"),
-                }
-            }
-            map.push(row);
-        }
-        assert!(start_found);
-        let active_beams: Vec<Coord> = vec![Coord {
-            x: start_pos.x,
-            y: start_pos.y,
-        }];
-        Data {
-            map: map.clone(),
+    fn new(input: &str) -> Result<Data, AocError> {
+        let (map, start_pos) = parse(input)?;
+        let active_beams: Vec<Coord> = vec![start_pos];
+        Ok(Data {
+            map,
             active_beams,
             active_splitters: Vec::new(),
             activated_splitters: Vec::new(),
-        }
+        })
     }
 
-    fn propagate_beam(&self, beam: &Coord) -> ActivatedTiles {
-        let y_max = self.map.len();
-        if beam.y + 1 < y_max {
-            match self.map[beam.y + 1][beam.x] {
-                Tile::Start => panic!(
-                    "beam iteration should not be at start: {},{}",
-                    beam.x, beam.y
-                ),
-                Tile::Empty => {
-                    //println!("beam propagated downward {},{}", beam.x, beam.y + 1);
-                    ActivatedTiles {
-                        beam: vec![Coord {
-                            x: beam.x,
-                            y: beam.y + 1,
-                        }],
-                        splitter: None,
-                    }
-                }
-                Tile::Splitter => {
-                    //println!("beam activated splitter {},{}", beam.x, beam.y + 1);
-                    ActivatedTiles {
-                        beam: vec![],
-                        splitter: Some(Coord {
-                            x: beam.x,
-                            y: beam.y + 1,
-                        }),
-                    }
-                }
-                Tile::Beam => {
-                    // println!(
-                    //     "beam propagated downward to beam, subtracting a split {},{}",
-                    //     beam.x,
-                    //     beam.y + 1
-                    // );
-                    ActivatedTiles {
-                        beam: vec![],
-                        splitter: None,
-                    }
-                }
-            }
-        } else {
+    fn propagate_beam(&self, beam: &Coord) -> Result<Activated, AocError> {
+        let Some(below) = self.map.step(*beam, Direction::South) else {
             //println!("beam exited manifold");
-            ActivatedTiles {
-                beam: vec![],
-                splitter: None,
-            }
+            return Ok(Activated::Nothing);
+        };
+        match self.map[below] {
+            Tile::Start => Err(AocError::unsupported(format!(
+                "a beam runs into the start at {},{}",
+                below.x, below.y
+            ))),
+            //println!("beam propagated downward {},{}", below.x, below.y);
+            Tile::Empty => Ok(Activated::Beam(below)),
+            //println!("beam activated splitter {},{}", below.x, below.y);
+            Tile::Splitter => Ok(Activated::Splitter(below)),
+            // the beam merges into one already going down
+            Tile::Beam => Ok(Activated::Nothing),
         }
     }
-    fn propagate_splitter(&self, splitter: &Coord) -> ActivatedTiles {
-        let y_max = self.map.len();
-        let x_max = self.map[0].len();
-        let mut new_active_beams: Vec<Coord> = Vec::with_capacity(2);
-        if splitter.y + 1 < y_max {
-            //left
-            if (splitter.x) > 0 {
-                //print!("beam split left to: {},{}", splitter.x - 1, splitter.y + 1);
-                new_active_beams.push(Coord {
-                    x: splitter.x - 1,
-                    y: splitter.y + 1,
-                });
-            } else {
-                //println!("new beam would be outside manifold going left");
-            }
-            //right
-            if (splitter.x + 1) < x_max {
-                //print!("beam split right to: {},{}", splitter.x + 1, splitter.y + 1);
-                new_active_beams.push(Coord {
-                    x: splitter.x + 1,
-                    y: splitter.y + 1,
-                });
-            } else {
-                //println!("new beam would be outside manifold going right");
-            }
-            ActivatedTiles {
-                beam: new_active_beams,
-                splitter: None,
-            }
-        } else {
-            //println!("new beam would start outside manifold");
-            ActivatedTiles {
-                beam: vec![],
-                splitter: None,
-            }
-        }
+    fn propagate_splitter(&self, splitter: &Coord) -> Vec<Coord> {
+        // split left and right, dropping beams that would be outside the manifold
+        [-1, 1]
+            .into_iter()
+            .filter_map(|dx| self.map.offset(*splitter, dx, 1))
+            .collect()
     }
 
-    fn iterate_active_beams(&mut self) {
+    fn iterate_active_beams(&mut self) -> Result<(), AocError> {
         let mut new_active_beams: Vec<Coord> = Vec::new();
         let active_beams = self.active_beams.clone();
         for beam in active_beams {
-            let result = self.propagate_beam(&beam);
-            match (result.beam.len() > 0, result.splitter) {
-                (false, None) => {
+            match self.propagate_beam(&beam)? {
+                Activated::Nothing => {
                     //exited
                 }
-                (false, Some(split)) => {
+                Activated::Splitter(split) => {
                     if !self.activated_splitters.contains(&split) {
                         self.activated_splitters.push(split);
                         self.active_splitters.push(split);
                     }
                 }
-                (true, None) => {
-                    for res_beam in &result.beam {
-                        self.map[res_beam.y][res_beam.x] = Tile::Beam;
-                    }
-                    new_active_beams.extend(result.beam)
+                Activated::Beam(beam) => {
+                    self.map[beam] = Tile::Beam;
+                    new_active_beams.push(beam)
                 }
-                (true, Some(_splitter)) => panic!("shouldn't be possible"),
             }
         }
         self.active_beams = new_active_beams;
+        Ok(())
     }
-    fn iterate_active_splitters(&mut self) {
+    fn iterate_active_splitters(&mut self) -> Result<(), AocError> {
         let mut new_active_beams: Vec<Coord> = Vec::new();
+        let mut new_active_splitters: Vec<Coord> = Vec::new();
         for splitter in &self.active_splitters {
-            let result = self.propagate_splitter(splitter);
-            match (result.beam.len() > 0, result.splitter) {
-                (false, None) => {
-                    //exited
-                }
-                (false, Some(_split)) => {
-                    panic!("should not be possible");
-                }
-                (true, None) => {
-                    for beam in &result.beam {
-                        match self.map[beam.y][beam.x] {
-                            Tile::Start => panic!("shouldn't go to the start"),
-                            Tile::Empty => {
-                                self.map[beam.y][beam.x] = Tile::Beam;
-                            }
-                            Tile::Splitter => panic!("shouldn't hit another splitter"),
-                            Tile::Beam => {
-                                //println!("we already have a beam here")
-                                // cannot split here
-                            }
-                        };
+            for beam in self.propagate_splitter(splitter) {
+                match self.map[beam] {
+                    Tile::Start => {
+                        return Err(AocError::unsupported(format!(
+                            "the splitter at {},{} sends a beam into the start",
+                            splitter.x, splitter.y
+                        )))
                     }
-                    new_active_beams.extend(result.beam)
-                }
-                (true, Some(_splitter)) => panic!("shouldn't be possible"),
+                    Tile::Empty => {
+                        self.map[beam] = Tile::Beam;
+                        new_active_beams.push(beam);
+                    }
+                    Tile::Splitter => {
+                        // the beam goes straight down into the next splitter
+                        if !self.activated_splitters.contains(&beam) {
+                            self.activated_splitters.push(beam);
+                            new_active_splitters.push(beam);
+                        }
+                    }
+                    Tile::Beam => {
+                        //println!("we already have a beam here")
+                        // cannot split here
+                    }
+                };
             }
         }
         self.active_beams = new_active_beams;
-        self.active_splitters.clear();
+        self.active_splitters = new_active_splitters;
+        Ok(())
     }
 }
 
-pub fn process_part1(input: &str) -> String {
-    let mut data = Data::new(input);
-    while data.active_beams.len() > 0 || data.active_splitters.len() > 0 {
-        while data.active_beams.len() > 0 {
-            data.iterate_active_beams();
-        }
-        while data.active_splitters.len() > 0 {
-            data.iterate_active_splitters();
-        }
-    }
-    // for row in data.map {
-    //     for col in row {
-    //         match col {
-    //             Tile::Start => print!("S"),
-    //             Tile::Empty => print!("."),
-    //             Tile::Splitter => print!("^"),
-    //             Tile::Beam => print!("|"),
-    //         }
-    //     }
-    //     println!()
-    // }
-    data.activated_splitters.len().to_string()
-}
-
 struct TraceData {
     ancestors: Vec<Coord>,
     current_location: Coord,
 }
 
 struct QData {
-    map: Vec<Vec<Tile>>,
+    map: Grid<Tile>,
     active_traces: Vec<TraceData>,
     resolved_traces: BTreeMap<Coord, usize>,
     start_location: Coord,
 }
 
 impl QData {
-    fn new(input: &str) -> QData {
-        let mut map: Vec<Vec<Tile>> = Vec::new();
-        let mut start_pos: Coord = Coord { x: 0, y: 0 };
-        let mut start_found = false;
-        for (y, line) in input.lines().enumerate() {
-            let mut row: Vec<Tile> = Vec::new();
-            for (x, char) in line.chars().enumerate() {
-                match char {
-                    '.' => row.push(Tile::Empty),
-                    'S' => {
-                        row.push(Tile::Start);
-                        start_pos = Coord::new(x, y);
-                        start_found = true;
-                    }
-                    '^' => row.push(Tile::Splitter),
-                    _ => panic!("invalid character {char}"),
-                }
-            }
-            map.push(row);
-        }
-        assert!(start_found);
-        QData {
+    fn new(input: &str) -> Result<QData, AocError> {
+        let (map, start_pos) = parse(input)?;
+        Ok(QData {
             map,
             active_traces: vec![TraceData {
                 ancestors: vec![start_pos],
@@ -279,20 +167,21 @@ impl QData {
             }],
             resolved_traces: BTreeMap::new(),
             start_location: start_pos,
-        }
+        })
     }
-    fn iterate_trace(&mut self, input: TraceData) {
-        let y_max = self.map.len();
-        let x_max = self.map[0].len();
-        if input.current_location.y + 1 < y_max {
-            match self.map[input.current_location.y + 1][input.current_location.x] {
-                Tile::Start => panic!("can't be the start"),
-                Tile::Empty => {
+    fn iterate_trace(&mut self, input: TraceData) -> Result<(), AocError> {
+        if let Some(below) = self.map.step(input.current_location, Direction::South) {
+            match self.map[below] {
+                Tile::Start => {
+                    return Err(AocError::unsupported(format!(
+                        "a beam runs into the start at {},{}",
+                        below.x, below.y
+                    )))
+                }
+                // part 2 never draws beams on the map
+                Tile::Empty | Tile::Beam => {
                     // before we make a new active trace validate we haven't walked it already
-                    let new_location = Coord {
-                        x: input.current_location.x,
-                        y: input.current_location.y + 1,
-                    };
+                    let new_location = below;
                     if let Some(route_count) = self.resolved_traces.get(&new_location).copied() {
                         // we have an answer
                         for ancestor in input.ancestors {
@@ -312,43 +201,19 @@ impl QData {
                 }
 
                 Tile::Splitter => {
-                    //left
-                    if input.current_location.x > 0 {
+                    //left then right
+                    for dx in [-1, 1] {
                         // before we make a new active trace validate we haven't walked it already
-                        let new_location = Coord {
-                            x: input.current_location.x - 1,
-                            y: input.current_location.y + 1,
+                        let Some(new_location) = self.map.offset(input.current_location, dx, 1)
+                        else {
+                            continue;
                         };
-                        if let Some(route_count) = self.resolved_traces.get(&new_location).copied()
-                        {
-                            // we have an answer
-                            for ancestor in &input.ancestors {
-                                self.resolved_traces
-                                    .entry(*ancestor)
-                                    .and_modify(|x| {
-                                        *x += route_count;
-                                    })
-                                    .or_insert(route_count);
-                            }
-                        } else {
-                            let mut new_ancestors = input.ancestors.clone();
-                            new_ancestors.push(Coord {
-                                x: input.current_location.x,
-                                y: input.current_location.y,
-                            });
-                            self.active_traces.push(TraceData {
-                                ancestors: new_ancestors,
-                                current_location: new_location,
-                            })
+                        if self.map[new_location] != Tile::Empty {
+                            return Err(AocError::unsupported(format!(
+                                "the splitter at {},{} sends a beam sideways into another splitter or the start",
+                                below.x, below.y
+                            )));
                         }
-                    }
-                    //right
-                    if input.current_location.x + 1 < x_max {
-                        // before we make a new active trace validate we haven't walked it already
-                        let new_location = Coord {
-                            x: input.current_location.x + 1,
-                            y: input.current_location.y + 1,
-                        };
                         if let Some(route_count) = self.resolved_traces.get(&new_location).copied()
                         {
                             // we have an answer
@@ -362,10 +227,7 @@ impl QData {
                             }
                         } else {
                             let mut new_ancestors = input.ancestors.clone();
-                            new_ancestors.push(Coord {
-                                x: input.current_location.x,
-                                y: input.current_location.y,
-                            });
+                            new_ancestors.push(input.current_location);
                             self.active_traces.push(TraceData {
                                 ancestors: new_ancestors,
                                 current_location: new_location,
@@ -373,7 +235,6 @@ impl QData {
                         }
                     }
                 }
-                Tile::Beam => panic!("can't be a beam"),
             }
         } else {
             // exited manifold
@@ -388,10 +249,10 @@ impl QData {
             }
             // println!();
         }
+        Ok(())
     }
-    fn solve_one_trace(&mut self) {
-        while self.active_traces.len() > 0 {
-            let trace = self.active_traces.pop().unwrap();
+    fn solve_one_trace(&mut self) -> Result<(), AocError> {
+        while let Some(trace) = self.active_traces.pop() {
             // have we solved this one?
             match self.resolved_traces.get(&trace.current_location).copied() {
                 Some(route_count) => {
@@ -415,22 +276,69 @@ impl QData {
                     //     print!("{}; ", self.resolved_traces.get(ancestor).unwrap_or(&0));
                     // }
                     // println!();
-                    self.iterate_trace(trace);
+                    self.iterate_trace(trace)?;
                 }
             }
         }
+        Ok(())
     }
 }
 
-pub fn process_part2(input: &str) -> String {
-    let mut data = QData::new(input);
-    while data.active_traces.len() > 0 {
-        data.solve_one_trace();
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 7;
+    type Parsed<'a> = &'a str;
+    type Part1 = Result<usize, AocError>;
+    type Part2 = Result<usize, AocError>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let mut data = Data::new(input)?;
+        while !data.active_beams.is_empty() || !data.active_splitters.is_empty() {
+            while !data.active_beams.is_empty() {
+                data.iterate_active_beams()?;
+            }
+            while !data.active_splitters.is_empty() {
+                data.iterate_active_splitters()?;
+            }
+        }
+        // for row in data.map {
+        //     for col in row {
+        //         match col {
+        //             Tile::Start => print!("S"),
+        //             Tile::Empty => print!("."),
+        //             Tile::Splitter => print!("^"),
+        //             Tile::Beam => print!("|"),
+        //         }
+        //     }
+        //     println!()
+        // }
+        Ok(data.activated_splitters.len())
+    }
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let mut data = QData::new(input)?;
+        while !data.active_traces.is_empty() {
+            data.solve_one_trace()?;
+        }
+        data.resolved_traces
+            .get(&data.start_location)
+            .copied()
+            .ok_or_else(|| AocError::solver("the start was never resolved"))
     }
-    data.resolved_traces
-        .get(&data.start_location)
-        .expect("there should be a resolved start")
-        .to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day07::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day07::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -440,7 +348,27 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "21");
-        assert_eq!(process_part2(file), "40");
+        assert_eq!(process_part1(file).unwrap(), "21");
+        assert_eq!(process_part2(file).unwrap(), "40");
+    }
+
+    #[test]
+    fn test_invalid_character() {
+        let error = process_part1("..S..\n..^..\n.x...\n").unwrap_err();
+        assert_eq!(error, AocError::parse(3, 2, "expected Char in \".x...\""));
+    }
+
+    #[test]
+    fn test_splitter_into_splitter() {
+        // both beams out of the first splitter go straight down into another
+        let file = ".S.\n.^.\n^.^\n...\n";
+        assert_eq!(process_part1(file).unwrap(), "3");
+        assert_eq!(process_part2(file).unwrap(), "4");
+        // a second S further down is just in the way
+        assert_eq!(
+            process_part1(".S.\n...\n.^.\nS..\n").unwrap_err(),
+            AocError::unsupported("the splitter at 1,2 sends a beam into the start")
+        );
+        assert!(process_part2(".S.\n.^^\n...\n").is_err());
     }
 }
@@ -1,3 +1,4 @@
+use aoc_core::{Answer, AocError, Solution};
 use kd_tree::{KdPoint, KdTree};
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
@@ -72,7 +73,7 @@ impl NodeList {
                 }
             }
         }
-        shortest.sort_by(|a, b| a.sq_dist.cmp(&b.sq_dist));
+        shortest.sort_by_key(|a| a.sq_dist);
         //println!("shortest list len: {}", shortest.len());
         NodeList {
             all,
@@ -236,46 +237,68 @@ fn build_node_vec(input: &str) -> Vec<Node> {
     list
 }
 
-pub fn process_part1(input: &str) -> String {
-    let list: Vec<Node> = build_node_vec(input);
-    let connections: usize = {
-        if list.len() == 20 {
-            10
-        } else {
-            1000
-        }
-    };
-    let mut nodes = NodeList::new(list, 15); // 8 just barely works. First time I ran this I set it to 100
-    nodes.build_all_connections(connections);
-    //println!("nodes: {}, connections: {connections}", list.len());
-    nodes.tag_groups();
-    let mut groups = nodes.group_size.clone();
-    groups.sort();
-    groups.reverse();
-    (groups[0] * groups[1] * groups[2]).to_string()
-}
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 8;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let list: Vec<Node> = build_node_vec(input);
+        let connections: usize = {
+            if list.len() == 20 {
+                10
+            } else {
+                1000
+            }
+        };
+        let mut nodes = NodeList::new(list, 15); // 8 just barely works. First time I ran this I set it to 100
+        nodes.build_all_connections(connections);
+        //println!("nodes: {}, connections: {connections}", list.len());
+        nodes.tag_groups();
+        let mut groups = nodes.group_size.clone();
+        groups.sort();
+        groups.reverse();
+        groups[0] * groups[1] * groups[2]
+    }
 
-pub fn process_part2(input: &str) -> String {
-    let list: Vec<Node> = build_node_vec(input);
-    let starting_connections: usize = {
-        if list.len() == 20 {
-            10
-        } else {
-            1000
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let list: Vec<Node> = build_node_vec(input);
+        let starting_connections: usize = {
+            if list.len() == 20 {
+                10
+            } else {
+                1000
+            }
+        };
+        let mut nodes = NodeList::new(list, 15); // 4 just barely works. First time I ran this I set it to 100
+        nodes.build_next_n_connections(starting_connections);
+        let (mut left, mut right) = nodes.build_one_connection();
+        while !nodes.quick_fill() {
+            (left, right) = nodes.build_one_connection();
         }
-    };
-    let mut nodes = NodeList::new(list, 15); // 4 just barely works. First time I ran this I set it to 100
-    nodes.build_next_n_connections(starting_connections);
-    let (mut left, mut right) = nodes.build_one_connection();
-    while !nodes.quick_fill() {
-        (left, right) = nodes.build_one_connection();
+        // println!(
+        //     "possible connections generated: {}\nconnections made: {}",
+        //     nodes.shortest.len(),
+        //     nodes.connection_index
+        // );
+        left.x * right.x
     }
-    // println!(
-    //     "possible connections generated: {}\nconnections made: {}",
-    //     nodes.shortest.len(),
-    //     nodes.connection_index
-    // );
-    (left.x * right.x).to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day08::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day08::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -285,13 +308,13 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "40");
-        assert_eq!(process_part2(file), "25272");
+        assert_eq!(process_part1(file).unwrap(), "40");
+        assert_eq!(process_part2(file).unwrap(), "25272");
     }
     #[test]
     fn test_real() {
         let real_file = include_str!("../input.txt");
-        assert_eq!(process_part1(real_file), "96672");
-        assert_eq!(process_part2(real_file), "22517595");
+        assert_eq!(process_part1(real_file).unwrap(), "96672");
+        assert_eq!(process_part2(real_file).unwrap(), "22517595");
     }
 }
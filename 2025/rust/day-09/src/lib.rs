@@ -1,90 +1,17 @@
-use std::collections::{BTreeMap, HashMap, VecDeque};
+use aoc_core::{Answer, AocError, Solution};
+use aoc_grid::{Coord, Direction};
+use std::collections::{HashMap, VecDeque};
 
 use itertools::Itertools;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
-struct Coord {
-    x: usize,
-    y: usize,
-}
-
 enum Tile {
     Green,
     Red,
     Neither,
 }
 
-impl Coord {
-    fn _square_distance(&self, b: &Coord) -> usize {
-        if self.x > b.x {
-            if self.y > b.y {
-                ((self.x - b.x) * (self.x - b.x)) + ((self.y - b.y) * (self.y - b.y))
-            } else {
-                ((self.x - b.x) * (self.x - b.x)) + ((b.y - self.y) * (b.y - self.y))
-            }
-        } else {
-            if self.y > b.y {
-                ((b.x - self.x) * (b.x - self.x)) + ((self.y - b.y) * (self.y - b.y))
-            } else {
-                ((b.x - self.x) * (b.x - self.x)) + ((b.y - self.y) * (b.y - self.y))
-            }
-        }
-    }
-    fn area(&self, b: &Coord) -> usize {
-        if self.x > b.x {
-            if self.y > b.y {
-                (self.x - b.x + 1) * (self.y - b.y + 1)
-            } else {
-                (self.x - b.x + 1) * (b.y - self.y + 1)
-            }
-        } else {
-            if self.y > b.y {
-                (b.x - self.x + 1) * (self.y - b.y + 1)
-            } else {
-                (b.x - self.x + 1) * (b.y - self.y + 1)
-            }
-        }
-    }
-    fn left(&self) -> Option<Coord> {
-        if self.x == 0 {
-            None
-        } else {
-            Some(Coord {
-                x: self.x - 1,
-                y: self.y,
-            })
-        }
-    }
-    fn right(&self) -> Option<Coord> {
-        if self.x == usize::MAX {
-            None
-        } else {
-            Some(Coord {
-                x: self.x + 1,
-                y: self.y,
-            })
-        }
-    }
-    fn up(&self) -> Option<Coord> {
-        if self.y == 0 {
-            None
-        } else {
-            Some(Coord {
-                x: self.x,
-                y: self.y - 1,
-            })
-        }
-    }
-    fn down(&self) -> Option<Coord> {
-        if self.y == usize::MAX {
-            None
-        } else {
-            Some(Coord {
-                x: self.x,
-                y: self.y + 1,
-            })
-        }
-    }
+fn area(a: &Coord, b: &Coord) -> usize {
+    (a.x.abs_diff(b.x) + 1) * (a.y.abs_diff(b.y) + 1)
 }
 
 fn get_simple_map(input: &str) -> Vec<Coord> {
@@ -140,6 +67,13 @@ struct Limits {
     min_height: usize,
 }
 
+impl Limits {
+    fn contains(&self, coord: &Coord) -> bool {
+        (self.min_width..=self.width).contains(&coord.x)
+            && (self.min_height..=self.height).contains(&coord.y)
+    }
+}
+
 struct Map {
     //limits: Limits,
     red_tiles: Vec<Coord>,
@@ -154,43 +88,21 @@ fn floodfill(map: &mut HashMap<Coord, Tile>, start: Coord, limits: &Limits) {
         let current = flood_queue
             .pop_front()
             .expect("there should be something, we just checked!");
-        if current.x > limits.width || current.x < limits.min_width {
-            // println!(
-            //     "warning, flood left region: x: {}, {}-{}",
-            //     current.x, limits.min_width, limits.width
-            // );
-            continue;
-        }
-        if current.y > limits.height || current.y < limits.min_height {
-            // println!(
-            //     "warning, flood left region: y: {}, {}-{}",
-            //     current.y, limits.min_height, limits.height
-            // );
+        if !limits.contains(&current) {
+            // println!("warning, flood left region: {},{}", current.x, current.y);
             continue;
         }
         // first flood here
-        if !map.contains_key(&current) {
-            map.insert(current, Tile::Green);
+        if let std::collections::hash_map::Entry::Vacant(e) = map.entry(current) {
+            e.insert(Tile::Green);
             // println!("flooding: {},{}", current.x, current.y);
-            // up
-            let next = current.up().expect("should be able to go up");
-            if map.get(&next).is_none() {
-                flood_queue.push_back(next);
-            }
-            // down
-            let next = current.down().expect("should be able to go down");
-            if map.get(&next).is_none() {
-                flood_queue.push_back(next);
-            }
-            // left
-            let next = current.left().expect("should be able to go left");
-            if map.get(&next).is_none() {
-                flood_queue.push_back(next);
-            }
-            // right
-            let next = current.right().expect("should be able to go right");
-            if map.get(&next).is_none() {
-                flood_queue.push_back(next);
+            for direction in Direction::ALL {
+                let next = current
+                    .step(direction)
+                    .expect("should be able to step away from the edge");
+                if map.get(&next).is_none() {
+                    flood_queue.push_back(next);
+                }
             }
         }
         // println!("current flood_queue: {:?}", flood_queue)
@@ -261,159 +173,65 @@ fn get_full_map(input: &str) -> Map {
     println!("x: {min_width}-{width}");
     println!("y: {min_height}-{height}");
     // try and find the inside of the loop
-    // first go left
-    let mut start = green_tiles[0];
-    let mut seen_neither = false;
-    let mut success = false;
-    while start.x > min_width {
-        start = start.left().expect("should be able to go left");
-        match map.get(&start).unwrap_or(&Tile::Neither) {
-            Tile::Green => {
-                if seen_neither {
-                    success = true;
-                    break;
-                }
-            }
-            Tile::Red => {
-                if seen_neither {
-                    success = true;
-                    break;
+    // go left, then right, then up, then down
+    for direction in [
+        Direction::West,
+        Direction::East,
+        Direction::North,
+        Direction::South,
+    ] {
+        let mut start = green_tiles[0];
+        let mut seen_neither = false;
+        let mut success = false;
+        while let Some(next) = start.step(direction).filter(|next| limits.contains(next)) {
+            start = next;
+            match map.get(&start).unwrap_or(&Tile::Neither) {
+                Tile::Green => {
+                    if seen_neither {
+                        success = true;
+                        break;
+                    }
                 }
-            }
-            Tile::Neither => {
-                seen_neither = true;
-            }
-        }
-    }
-    if success {
-        //flood leftward
-        start = green_tiles[0];
-        floodfill(
-            &mut map,
-            start.left().expect("should be able to go left"),
-            &limits,
-        );
-        return Map { red_tiles, map };
-    }
-    // then try right
-    let mut start = green_tiles[0];
-    let mut seen_neither = false;
-    let mut success = false;
-    while start.x < width {
-        start = start.right().expect("should be able to go right");
-        match map.get(&start).unwrap_or(&Tile::Neither) {
-            Tile::Green => {
-                if seen_neither {
-                    success = true;
-                    break;
+                Tile::Red => {
+                    if seen_neither {
+                        success = true;
+                        break;
+                    }
                 }
-            }
-            Tile::Red => {
-                if seen_neither {
-                    success = true;
-                    break;
+                Tile::Neither => {
+                    seen_neither = true;
                 }
             }
-            Tile::Neither => {
-                seen_neither = true;
-            }
         }
-    }
-    if success {
-        //flood rightward
-        start = green_tiles[0];
-        floodfill(
-            &mut map,
-            start.right().expect("should be able to go right"),
-            &limits,
-        );
-        return Map { red_tiles, map };
-    }
-    // then try up
-    let mut start = green_tiles[0];
-    let mut seen_neither = false;
-    let mut success = false;
-    while start.y > min_height {
-        start = start.up().expect("should be able to go up");
-        match map.get(&start).unwrap_or(&Tile::Neither) {
-            Tile::Green => {
-                if seen_neither {
-                    success = true;
-                    break;
-                }
-            }
-            Tile::Red => {
-                if seen_neither {
-                    success = true;
-                    break;
-                }
-            }
-            Tile::Neither => {
-                seen_neither = true;
-            }
+        if success {
+            //flood that way
+            floodfill(
+                &mut map,
+                green_tiles[0]
+                    .step(direction)
+                    .expect("should be able to step that way"),
+                &limits,
+            );
+            // for y in 0..=height {
+            //     for x in 0..=width {
+            //         match map.get(&Coord { x, y }).unwrap_or(&Tile::Neither) {
+            //             Tile::Green => {
+            //                 print!("X")
+            //             }
+            //             Tile::Red => {
+            //                 print!("#")
+            //             }
+            //             Tile::Neither => {
+            //                 print!(".")
+            //             }
+            //         }
+            //     }
+            //     println!();
+            // }
+            // println!();
+            return Map { red_tiles, map };
         }
     }
-    if success {
-        //flood upward
-        start = green_tiles[0];
-        floodfill(
-            &mut map,
-            start.up().expect("should be able to go up"),
-            &limits,
-        );
-        return Map { red_tiles, map };
-    }
-    // then try down
-    let mut start = green_tiles[0];
-    let mut seen_neither = false;
-    let mut success = false;
-    while start.y < height {
-        start = start.down().expect("should be able to go down");
-        match map.get(&start).unwrap_or(&Tile::Neither) {
-            Tile::Green => {
-                if seen_neither {
-                    success = true;
-                    break;
-                }
-            }
-            Tile::Red => {
-                if seen_neither {
-                    success = true;
-                    break;
-                }
-            }
-            Tile::Neither => {
-                seen_neither = true;
-            }
-        }
-    }
-    if success {
-        //flood downward
-        start = green_tiles[0];
-        floodfill(
-            &mut map,
-            start.down().expect("should be able to go down"),
-            &limits,
-        );
-        // for y in 0..=height {
-        //     for x in 0..=width {
-        //         match map.get(&Coord { x, y }).unwrap_or(&Tile::Neither) {
-        //             Tile::Green => {
-        //                 print!("X")
-        //             }
-        //             Tile::Red => {
-        //                 print!("#")
-        //             }
-        //             Tile::Neither => {
-        //                 print!(".")
-        //             }
-        //         }
-        //     }
-        //     println!();
-        // }
-        // println!();
-        return Map { red_tiles, map };
-    }
     // then give up
     panic!("could not find interior");
 }
@@ -459,32 +277,54 @@ fn all_red_or_green(map: &HashMap<Coord, Tile>, start: Coord, end: Coord) -> boo
     true
 }
 
-pub fn process_part1(input: &str) -> String {
-    let map = get_simple_map(input);
-    let mut biggest_area = 0;
-    for (i, corner) in map.iter().enumerate() {
-        for next_corner in map.iter().skip(i) {
-            let area = corner.area(next_corner);
-            if biggest_area < area {
-                biggest_area = area;
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 9;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let map = get_simple_map(input);
+        let mut biggest_area = 0;
+        for (i, corner) in map.iter().enumerate() {
+            for next_corner in map.iter().skip(i) {
+                let area = area(corner, next_corner);
+                if biggest_area < area {
+                    biggest_area = area;
+                }
             }
         }
+        biggest_area
     }
-    biggest_area.to_string()
-}
 
-pub fn process_part2(input: &str) -> String {
-    let map = get_full_map(input);
-    let mut biggest_area = 0;
-    for (i, corner) in map.red_tiles.iter().enumerate() {
-        for next_corner in map.red_tiles.iter().skip(i + 1) {
-            let area = corner.area(next_corner);
-            if all_red_or_green(&map.map, *corner, *next_corner) && biggest_area < area {
-                biggest_area = area;
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let map = get_full_map(input);
+        let mut biggest_area = 0;
+        for (i, corner) in map.red_tiles.iter().enumerate() {
+            for next_corner in map.red_tiles.iter().skip(i + 1) {
+                let area = area(corner, next_corner);
+                if all_red_or_green(&map.map, *corner, *next_corner) && biggest_area < area {
+                    biggest_area = area;
+                }
             }
         }
+        biggest_area
     }
-    biggest_area.to_string()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day09::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day09::solve_part2(input)
 }
 
 #[cfg(test)]
@@ -494,7 +334,7 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "50");
-        assert_eq!(process_part2(file), "24");
+        assert_eq!(process_part1(file).unwrap(), "50");
+        assert_eq!(process_part2(file).unwrap(), "24");
     }
 }
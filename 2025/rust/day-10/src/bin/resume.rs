@@ -0,0 +1,6 @@
+use aoc_2025_day_10::resume_part2;
+
+fn main() {
+    let file = aoc_core::input::from_args(env!("CARGO_MANIFEST_DIR"));
+    aoc_core::print_or_exit(resume_part2(&file));
+}
@@ -1,9 +1,5 @@
-use std::{
-    collections::BTreeMap,
-    fs::File,
-    io::{Read, Write},
-    sync::{Arc, Mutex},
-};
+use aoc_core::{checkpoint::Checkpoint, Answer, AocError, Solution};
+use aoc_linalg::LinearSystem;
 
 use itertools::Itertools;
 use rayon::prelude::*;
@@ -96,11 +92,6 @@ fn p1_solve_row(input: &P1Row) -> usize {
     panic!("could not solve row");
 }
 
-pub fn process_part1(input: &str) -> String {
-    let machines: Vec<P1Row> = input.lines().map(fast_parse_line).collect();
-    machines.iter().map(p1_solve_row).sum::<usize>().to_string()
-}
-
 struct P2Row {
     goal: Vec<usize>,
     buttons: Vec<Vec<usize>>,
@@ -151,179 +142,98 @@ fn p2_parse_line(input: &str) -> P2Row {
     P2Row { goal, buttons }
 }
 
-fn push_button(counter: &mut [usize], button: &[usize], press_count: usize) -> bool {
-    for target in button {
-        if counter[*target] < press_count {
-            return false;
-        } else {
-            counter[*target] -= press_count;
-        }
-    }
-    true
+/// The fewest presses that bring every counter up to its goal. Each counter
+/// is an equation over the button press counts, so this is the cheapest
+/// non-negative integer solution of `a x = goal` with every press costing one.
+fn p2_solve_row(input: &P2Row) -> Option<usize> {
+    let a: Vec<Vec<i128>> = (0..input.goal.len())
+        .map(|counter| {
+            input
+                .buttons
+                .iter()
+                .map(|button| i128::from(button.contains(&counter)))
+                .collect()
+        })
+        .collect();
+    let goal: Vec<i128> = input.goal.iter().map(|&g| g as i128).collect();
+    // a button can't be pressed more often than any counter it touches allows
+    let upper: Vec<i128> = input
+        .buttons
+        .iter()
+        .map(|button| {
+            button
+                .iter()
+                .map(|&counter| goal[counter])
+                .min()
+                .unwrap_or(0)
+        })
+        .collect();
+    let cost = vec![1; input.buttons.len()];
+    let (presses, _) = LinearSystem::new(&a, &goal)
+        .reduce()?
+        .minimise(&cost, &upper)?;
+    presses.try_into().ok()
 }
 
-fn get_first_constraint(
-    current: &[usize],
-    all_buttons: &[Vec<usize>],
-) -> Option<(usize, Vec<usize>)> {
-    let mut constraints: Vec<(usize, Vec<usize>)> = Vec::with_capacity(current.len());
-    for (counter, current_counter) in current.iter().enumerate() {
-        let mut buttons: Vec<usize> = Vec::new();
-        for (button_number, button) in all_buttons.iter().enumerate() {
-            if button.contains(&counter) && *current_counter > 0 {
-                buttons.push(button_number)
-            }
-        }
-        if buttons.is_empty() {
-            continue;
-        } else {
-            constraints.push((counter, buttons))
-        }
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 10;
+    type Parsed<'a> = &'a str;
+    type Part1 = usize;
+    type Part2 = Result<usize, AocError>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
     }
-    constraints.sort_by_key(|x| x.1.len());
-    // for counter in &current {
-    //     print!("{counter},")
-    // }
-    // println!();
-    //println!("constraints: {constraints:?}");
-    constraints.first().cloned()
-}
 
-fn reduce(
-    current: Vec<usize>,
-    presses: usize,
-    all_buttons: &Vec<Vec<usize>>, //sorted_buttons: &Vec<Vec<usize>>,
-    upper_level_mins: Option<Vec<Arc<Mutex<usize>>>>,
-) -> usize {
-    if current.iter().sum::<usize>() == 0 {
-        return presses;
+    fn part1(input: Self::Parsed<'_>) -> Self::Part1 {
+        let machines: Vec<P1Row> = input.lines().map(fast_parse_line).collect();
+        machines.iter().map(p1_solve_row).sum::<usize>()
     }
-    // for counter in &current {
-    //     print!("{counter},")
-    // }
-    // println!();
-    if let Some((counter, constraint)) = get_first_constraint(&current, all_buttons) {
-        //println!("counter: {counter}, first constraint: {constraint:?}, combos: ");
-        // start with things only affected by small subsets // button_number, button
-        let combos = constraint
-            .iter()
-            .combinations_with_replacement(current[counter]);
-        //.collect::<Vec<Vec<&usize>>>();
-        // for combo in combos.clone() {
-        //     println!("{combo:?}");
-        // }
-        // println!();
-        //let presses_so_far = combos
-        let guarded_current_level_min = Arc::new(Mutex::new(usize::MAX));
-        let upper_level_mins = match upper_level_mins {
-            Some(mut x) => {
-                x.push(guarded_current_level_min.clone());
-                x
-            }
-            None => vec![guarded_current_level_min.clone()],
-        };
-        combos
-            .par_bridge()
-            .map(|combination| {
-                let mut local_current = current.clone();
-                let mut local_presses = presses;
-                for button_number in combination {
-                    //println!("pressing button: {button_number}");
-                    local_presses += 1;
-                    if !push_button(&mut local_current, &all_buttons[*button_number], 1) {
-                        return usize::MAX;
-                    }
-                }
-                // for (level, min) in upper_level_mins.iter().enumerate() {
-                for min in upper_level_mins.iter() {
-                    let min_value = min
-                        .lock()
-                        .expect("threads shouldn't panic while holding this");
-                    if local_presses >= *min_value {
-                        //println!("did not recurse due to smaller min at level: {level}:{min_value} ; current level is: {}:{local_presses}", upper_level_mins.len());
-                        return usize::MAX;
-                    }
-                }
-                reduce(
-                    local_current,
-                    local_presses,
-                    all_buttons,
-                    Some(upper_level_mins.clone()),
-                )
-            })
-            .inspect(|res| {
-                let mut min = guarded_current_level_min
-                    .lock()
-                    .expect("threads shouldn't panic while holding this");
-                if *res < *min {
-                    *min = *res;
-                }
-            })
-            .min()
-            .unwrap_or(usize::MAX)
-        //println!("presses_so_far: {presses_so_far}");
-        //presses_so_far
-    } else {
-        panic!("should always have a button to hit");
+
+    fn part2(input: Self::Parsed<'_>) -> Self::Part2 {
+        let machines: Vec<P2Row> = input.lines().map(p2_parse_line).collect();
+        machines
+            .into_par_iter()
+            .enumerate()
+            .map(|(row_number, row)| solve_row(row_number, &row))
+            .sum()
     }
 }
 
-fn p2_solve_row(/*, row_num: usize,*/ input: &P2Row) -> usize {
-    let current = input.goal.clone();
-    reduce(current, 0, &input.buttons, None)
+fn solve_row(row_number: usize, row: &P2Row) -> Result<usize, AocError> {
+    p2_solve_row(row)
+        .ok_or_else(|| AocError::solver(format!("row {row_number} can't reach its goal")))
 }
 
-pub fn process_part2(input: &str) -> String {
+/// Part 2, saving each row to `checkpoint` as it's solved and skipping the
+/// rows it already has.
+fn part2_checkpointed(input: &str, checkpoint: &Checkpoint) -> Result<usize, AocError> {
     let machines: Vec<P2Row> = input.lines().map(p2_parse_line).collect();
-    let file_name = format!("{}-checkpoint.txt", machines.len());
-    let mut checkpoint_file = match File::options().read(true).write(true).open(&file_name) {
-        Ok(file) => file,
-        Err(e) => {
-            println!("error opening checkpoint file: {e}");
-            match File::create(&file_name) {
-                Ok(file) => file,
-                Err(e) => panic!("{e}: could not even create checkpoint file"),
-            }
-        }
-    };
-    let mut checkpoint_data: String = String::new();
-    match checkpoint_file.read_to_string(&mut checkpoint_data) {
-        Ok(_) => {}
-        Err(e) => panic!("{e}: could not read file to string"),
-    };
-    let mut solutions: BTreeMap<usize, usize> = BTreeMap::new();
-    for data in checkpoint_data.lines() {
-        let (row_id, solution) = data
-            .split_once(':')
-            .expect("if there are rows it should have data");
-        solutions.insert(
-            row_id.parse().expect("row id should be a number"),
-            solution.parse().expect("solution should be a number"),
-        );
-    }
-    let guarded_file: Arc<Mutex<File>> = Arc::new(Mutex::new(checkpoint_file));
-    //let guarded_soltuions: Arc<Mutex<BTreeMap<usize, usize>>> = Arc::new(Mutex::new(solutions));
     machines
         .into_par_iter()
         .enumerate()
-        .map(|(row_number, row)| match solutions.get(&row_number) {
-            Some(count) => *count,
-            None => {
-                let count = p2_solve_row(&row);
-                println!("row {row_number} solved: {count}");
-                let mut shared_file = guarded_file.lock().unwrap();
-                let output_str = format!("{row_number}:{count}\n");
-                match shared_file.write_all(output_str.as_bytes()) {
-                    Ok(_) => {}
-                    Err(e) => {
-                        println!("{e}: could not save line to file: {output_str}")
-                    }
-                };
-                count
-            }
-        })
-        .sum::<usize>()
-        .to_string()
+        .map(|(row_number, row)| checkpoint.resume(row_number, || solve_row(row_number, &row)))
+        .sum()
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day10::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day10::solve_part2(input)
+}
+
+/// Part 2 with a checkpoint, so a run that's stopped carries on where it got
+/// to. Only the `2025-day-10-resume` bin uses it; the runner, tests and
+/// benches always solve from scratch.
+pub fn resume_part2(input: &str) -> Result<Answer, AocError> {
+    let checkpoint = Checkpoint::open(Day10::YEAR, Day10::DAY, 2, 1, input)
+        .map_err(|e| AocError::solver(format!("could not open the checkpoint: {e}")))?;
+    part2_checkpointed(input, &checkpoint).map(Into::into)
 }
 
 #[cfg(test)]
@@ -333,7 +243,42 @@ mod tests {
     #[test]
     fn test_input() {
         let file = include_str!("../test-input-1.txt");
-        assert_eq!(process_part1(file), "7");
-        assert_eq!(process_part2(file), "33");
+        assert_eq!(process_part1(file).unwrap(), "7");
+        assert_eq!(process_part2(file).unwrap(), "33");
+    }
+
+    #[test]
+    fn test_checkpoints() {
+        let dir = std::env::temp_dir().join(format!("aoc-2025-10-test-{}", std::process::id()));
+        let file = include_str!("../test-input-1.txt");
+        let open = |input: &str| Checkpoint::open_in(&dir, Day10::YEAR, Day10::DAY, 2, 1, input);
+
+        // solving normally leaves nothing behind
+        assert_eq!(process_part2(file).unwrap(), "33");
+        assert!(!dir.exists());
+
+        // a run stopped after saving row 0 and partway through writing row 1
+        let interrupted = open(file).unwrap();
+        interrupted.record(0, &100).unwrap();
+        let path = interrupted.path().to_path_buf();
+        drop(interrupted);
+        let mut contents = std::fs::read_to_string(&path).unwrap();
+        contents += "1 1 1";
+        std::fs::write(&path, contents).unwrap();
+        // row 0 comes from the checkpoint, so the total is off by 90
+        assert_eq!(part2_checkpointed(file, &open(file).unwrap()).unwrap(), 123);
+        let resumed = open(file).unwrap();
+        assert_eq!(resumed.get::<usize>(1), Some(12));
+        assert_eq!(resumed.get::<usize>(2), Some(11));
+
+        // a checkpoint for another input isn't used
+        let other = file.lines().next().unwrap();
+        open(other).unwrap().record(0, &100).unwrap();
+        let second = file.lines().nth(1).unwrap();
+        assert_eq!(
+            part2_checkpointed(second, &open(second).unwrap()).unwrap(),
+            12
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 }
@@ -0,0 +1,181 @@
+//! Saving the results of slow solvers row by row, so an interrupted run picks
+//! up where it stopped.
+//!
+//! A checkpoint belongs to one part of one day and one input: the file is
+//! named after the year, day, part and a hash of the input, and its first line
+//! repeats them, so results are never reused for a different input. Each
+//! record is one `version row value` line written with a single append, and a
+//! line cut short by an interruption is skipped when the file is read back.
+//! Solvers pass a version of their own and bump it when they change what they
+//! compute, which makes the older records stale.
+//!
+//! Checkpoints go in `AOC_CHECKPOINT_DIR`, or `.aoc-cache/checkpoints` at the
+//! workspace root.
+
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Mutex,
+};
+
+use crate::AocError;
+
+pub const DIR_VAR: &str = "AOC_CHECKPOINT_DIR";
+
+/// Bumped when the layout of the file itself changes.
+const FORMAT: u32 = 1;
+
+/// FNV-1a, which unlike the std hashers is the same on every build.
+pub fn input_hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+fn default_dir() -> PathBuf {
+    match env::var_os(DIR_VAR) {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../.."))
+            .join(".aoc-cache")
+            .join("checkpoints"),
+    }
+}
+
+/// The saved rows of one run, shared between the threads solving them.
+pub struct Checkpoint {
+    path: PathBuf,
+    version: u32,
+    saved: HashMap<usize, String>,
+    file: Mutex<File>,
+}
+
+impl Checkpoint {
+    /// Opens the checkpoint for `input` in the default directory.
+    pub fn open(year: u16, day: u8, part: u8, version: u32, input: &str) -> io::Result<Checkpoint> {
+        Checkpoint::open_in(&default_dir(), year, day, part, version, input)
+    }
+
+    pub fn open_in(
+        dir: &Path,
+        year: u16,
+        day: u8,
+        part: u8,
+        version: u32,
+        input: &str,
+    ) -> io::Result<Checkpoint> {
+        let hash = input_hash(input);
+        let dir = dir.join(year.to_string());
+        fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("day-{day:02}-part-{part}-{hash:016x}.txt"));
+        let header = format!("aoc checkpoint {FORMAT} {year} {day} {part} {hash:016x}");
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let mut lines = contents.split_inclusive('\n');
+        let resuming = lines.next() == Some(format!("{header}\n").as_str());
+        let saved = if resuming {
+            lines
+                .filter_map(|line| line.strip_suffix('\n'))
+                .filter_map(|line| parse_record(line, version))
+                .collect()
+        } else {
+            // a different format or a missing file, so start over
+            fs::write(&path, format!("{header}\n"))?;
+            HashMap::new()
+        };
+        let mut file = File::options().append(true).open(&path)?;
+        if resuming && !contents.ends_with('\n') {
+            // end the cut off record so the next one starts on its own line
+            file.write_all(b"\n")?;
+        }
+        Ok(Checkpoint {
+            path,
+            version,
+            saved,
+            file: Mutex::new(file),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The saved result for `row`, if it was solved before.
+    pub fn get<T: FromStr>(&self, row: usize) -> Option<T> {
+        self.saved.get(&row)?.parse().ok()
+    }
+
+    /// Saves the result for `row`.
+    pub fn record(&self, row: usize, value: &impl Display) -> io::Result<()> {
+        let line = format!("{} {row} {value}\n", self.version);
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        file.write_all(line.as_bytes())
+    }
+
+    /// The saved result for `row`, or `solve`'s, which is then saved.
+    pub fn resume<T, F>(&self, row: usize, solve: F) -> Result<T, AocError>
+    where
+        T: FromStr + Display,
+        F: FnOnce() -> Result<T, AocError>,
+    {
+        if let Some(value) = self.get(row) {
+            return Ok(value);
+        }
+        let value = solve()?;
+        self.record(row, &value).map_err(|e| {
+            AocError::solver(format!("could not save to {}: {e}", self.path.display()))
+        })?;
+        Ok(value)
+    }
+}
+
+fn parse_record(line: &str, version: u32) -> Option<(usize, String)> {
+    let mut fields = line.splitn(3, ' ');
+    if fields.next()?.parse::<u32>().ok()? != version {
+        return None;
+    }
+    let row = fields.next()?.parse().ok()?;
+    Some((row, fields.next()?.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint() {
+        let dir = env::temp_dir().join(format!("aoc-checkpoint-test-{}", std::process::id()));
+        let open = |version, input| Checkpoint::open_in(&dir, 2025, 10, 2, version, input).unwrap();
+
+        let first = open(1, "1\n2\n");
+        assert_eq!(first.resume(0, || Ok(10)), Ok(10));
+        first.record(1, &20).unwrap();
+        drop(first);
+
+        let second = open(1, "1\n2\n");
+        assert_eq!(second.get::<usize>(1), Some(20));
+        let reused: Result<usize, AocError> = second.resume(0, || panic!("row 0 is saved"));
+        assert_eq!(reused, Ok(10));
+
+        // cut the last record short, as if the run was killed mid-write
+        let contents = fs::read_to_string(second.path()).unwrap();
+        fs::write(second.path(), &contents[..contents.len() - 2]).unwrap();
+        let torn = open(1, "1\n2\n");
+        assert_eq!(torn.get::<usize>(0), Some(10));
+        assert_eq!(torn.get::<usize>(1), None);
+        torn.record(1, &21).unwrap();
+        assert_eq!(open(1, "1\n2\n").get::<usize>(1), Some(21));
+
+        assert_eq!(open(2, "1\n2\n").get::<usize>(0), None);
+        assert_eq!(open(1, "1\n3\n").get::<usize>(0), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
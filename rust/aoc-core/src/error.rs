@@ -0,0 +1,51 @@
+use std::fmt;
+
+/// Why a part couldn't produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// the input isn't in the expected format; `line` and `column` count
+    /// from 1
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// well formed input that the solution doesn't handle
+    Unsupported(String),
+    /// the solver ran out of options without finding an answer
+    Solver(String),
+}
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> AocError {
+        AocError::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn unsupported(message: impl Into<String>) -> AocError {
+        AocError::Unsupported(message.into())
+    }
+
+    pub fn solver(message: impl Into<String>) -> AocError {
+        AocError::Solver(message.into())
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "parse error at line {line}, column {column}: {message}"),
+            AocError::Unsupported(message) => write!(f, "unsupported input: {message}"),
+            AocError::Solver(message) => write!(f, "no solution: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
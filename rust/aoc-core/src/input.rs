@@ -0,0 +1,157 @@
+//! Finding and reading puzzle input at runtime.
+//!
+//! The bins take the input from, in order of preference:
+//! 1. a path given as the first argument (`-` reads stdin),
+//! 2. the `AOC_INPUT` environment variable (also a path or `-`),
+//! 3. `input.txt` in the day's crate directory.
+//!
+//! Benches skip the first step, since divan owns their arguments.
+
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process,
+};
+
+pub const ENV_VAR: &str = "AOC_INPUT";
+
+/// Where an input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Path(PathBuf),
+    Stdin,
+    /// the checked-in `input.txt`, used when nothing else was asked for
+    Default(PathBuf),
+}
+
+impl Source {
+    fn from_arg(arg: &str) -> Source {
+        if arg == "-" {
+            Source::Stdin
+        } else {
+            Source::Path(PathBuf::from(arg))
+        }
+    }
+
+    /// Picks the source from an explicit argument, then `AOC_INPUT`, then
+    /// `input.txt` in `crate_dir`.
+    pub fn choose(arg: Option<&str>, env_value: Option<&str>, crate_dir: &Path) -> Source {
+        match arg.or(env_value).filter(|value| !value.is_empty()) {
+            Some(value) => Source::from_arg(value),
+            None => Source::Default(crate_dir.join("input.txt")),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            Source::Path(path) | Source::Default(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map(|_| input)
+            }
+        };
+        result.map_err(|error| InputError {
+            from: self.clone(),
+            error,
+        })
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Path(path) | Source::Default(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub from: Source,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "could not read input from {}: {}", self.from, self.error)?;
+        if matches!(self.from, Source::Default(_)) && self.error.kind() == io::ErrorKind::NotFound {
+            write!(
+                f,
+                "\nsave your puzzle input there, pass a path (or `-` for stdin), or set {ENV_VAR}"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+fn read_or_exit(source: Source) -> String {
+    match source.read() {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Input for a bin: the first argument, `AOC_INPUT`, or `input.txt` in
+/// `crate_dir`. Exits with a message if it can't be read.
+pub fn from_args(crate_dir: &str) -> String {
+    let arg = env::args().nth(1);
+    let env_value = env::var(ENV_VAR).ok();
+    read_or_exit(Source::choose(
+        arg.as_deref(),
+        env_value.as_deref(),
+        Path::new(crate_dir),
+    ))
+}
+
+/// Input for a bench: `AOC_INPUT` or `input.txt` in `crate_dir`. Exits with a
+/// message if it can't be read.
+pub fn from_env(crate_dir: &str) -> String {
+    let env_value = env::var(ENV_VAR).ok();
+    read_or_exit(Source::choose(
+        None,
+        env_value.as_deref(),
+        Path::new(crate_dir),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_choose() {
+        let dir = Path::new("2024/rust/day-06");
+        assert_eq!(
+            Source::choose(Some("big.txt"), Some("other.txt"), dir),
+            Source::Path(PathBuf::from("big.txt"))
+        );
+        assert_eq!(Source::choose(None, Some("-"), dir), Source::Stdin);
+        assert_eq!(
+            Source::choose(None, Some(""), dir),
+            Source::Default(dir.join("input.txt"))
+        );
+        assert_eq!(
+            Source::choose(None, None, dir),
+            Source::Default(dir.join("input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let source = Source::Default(PathBuf::from("does/not/exist/input.txt"));
+        let message = source.read().unwrap_err().to_string();
+        assert!(message.starts_with("could not read input from does/not/exist/input.txt"));
+        assert!(message.contains(ENV_VAR));
+    }
+}
@@ -0,0 +1,175 @@
+use std::{
+    fmt::{self, Display},
+    process,
+};
+
+pub mod checkpoint;
+mod error;
+pub mod input;
+
+pub use error::AocError;
+
+/// The answer to one part of a puzzle.
+///
+/// Solutions keep their own answer types (`usize`, `String`, ...) and convert
+/// into this so runners can print and compare every day the same way.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! answer_from_integer {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Answer::Number(number) => number.to_string() == other,
+            Answer::Text(text) => text == other,
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+/// What `part1`/`part2` can return: anything that converts into an `Answer`,
+/// or a `Result` of one for parts that can fail.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Answer, AocError>;
+}
+
+impl<T: Into<Answer>> IntoAnswer for T {
+    fn into_answer(self) -> Result<Answer, AocError> {
+        Ok(self.into())
+    }
+}
+
+impl<T: Into<Answer>> IntoAnswer for Result<T, AocError> {
+    fn into_answer(self) -> Result<Answer, AocError> {
+        self.map(Into::into)
+    }
+}
+
+/// Prints a bin's answer, or the error and exits with a failure status.
+pub fn print_or_exit(result: Result<Answer, AocError>) {
+    match result {
+        Ok(answer) => println!("{answer}"),
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// One day of Advent of Code.
+///
+/// `parse` turns the puzzle input into whatever both parts work from, and
+/// `part1`/`part2` solve from that. Days that don't need a separate parse phase
+/// use `&'a str` as `Parsed` and return the input unchanged. A part that can
+/// fail returns `Result<_, AocError>`, and so can `parse` through `Parsed`.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Parsed<'a>;
+    type Part1: IntoAnswer;
+    type Part2: IntoAnswer;
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+    fn part1(parsed: Self::Parsed<'_>) -> Self::Part1;
+    fn part2(parsed: Self::Parsed<'_>) -> Self::Part2;
+
+    fn solve_part1(input: &str) -> Result<Answer, AocError> {
+        Self::part1(Self::parse(input)).into_answer()
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer, AocError> {
+        Self::part2(Self::parse(input)).into_answer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sums;
+
+    impl Solution for Sums {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+        type Parsed<'a> = Result<Vec<i64>, AocError>;
+        type Part1 = Result<i64, AocError>;
+        type Part2 = Result<String, AocError>;
+
+        fn parse(input: &str) -> Result<Vec<i64>, AocError> {
+            input
+                .lines()
+                .enumerate()
+                .map(|(i, line)| {
+                    line.parse()
+                        .map_err(|e| AocError::parse(i + 1, 1, format!("{e}")))
+                })
+                .collect()
+        }
+
+        fn part1(parsed: Self::Parsed<'_>) -> Self::Part1 {
+            Ok(parsed?.iter().sum())
+        }
+
+        fn part2(parsed: Self::Parsed<'_>) -> Self::Part2 {
+            Ok(parsed?
+                .iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join(","))
+        }
+    }
+
+    #[test]
+    fn test_solution() {
+        assert_eq!(Sums::solve_part1("1\n-4\n10"), Ok(Answer::Number(7)));
+        assert_eq!(Sums::solve_part2("1\n-4\n10").unwrap(), "1,-4,10");
+        assert_eq!(Answer::from(u64::MAX), "18446744073709551615");
+        assert_eq!(
+            Sums::solve_part1("1\nx").unwrap_err().to_string(),
+            "parse error at line 2, column 1: invalid digit found in string"
+        );
+    }
+}
@@ -0,0 +1,408 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{Coord, Direction, NEIGHBOURS_8};
+
+/// A rectangular grid stored row by row in one `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError {
+    Empty,
+    /// a line that isn't as long as the first one
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// a character the tile type doesn't accept
+    Tile {
+        coord: Coord,
+        found: char,
+    },
+}
+
+impl fmt::Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGridError::Empty => write!(f, "the grid is empty"),
+            ParseGridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} is {found} characters long, expected {expected}"
+            ),
+            ParseGridError::Tile { coord, found } => {
+                write!(f, "unexpected {found:?} at {},{}", coord.x, coord.y)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseGridError {}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T: TryFrom<char>> Grid<T> {
+    /// Parses one tile per character, one row per line.
+    pub fn parse(input: &str) -> Result<Grid<T>, ParseGridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+        for (y, line) in input.lines().enumerate() {
+            let mut found = 0;
+            for (x, c) in line.chars().enumerate() {
+                let tile = T::try_from(c).map_err(|_| ParseGridError::Tile {
+                    coord: Coord::new(x, y),
+                    found: c,
+                })?;
+                cells.push(tile);
+                found += 1;
+            }
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                return Err(ParseGridError::Ragged {
+                    line: y + 1,
+                    expected,
+                    found,
+                });
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseGridError::Empty),
+        }
+    }
+}
+
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row order.
+    ///
+    /// Panics if `cells` doesn't fill a whole number of rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Grid<T> {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't fill rows of {width}",
+            cells.len()
+        );
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.x < self.width && coord.y < self.height
+    }
+
+    fn index_of(&self, coord: Coord) -> Option<usize> {
+        self.contains(coord)
+            .then_some(coord.y * self.width + coord.x)
+    }
+
+    fn coord_of(&self, index: usize) -> Coord {
+        Coord::new(index % self.width, index / self.width)
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.index_of(coord).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.index_of(coord).map(|i| &mut self.cells[i])
+    }
+
+    /// The coordinate `(dx, dy)` away from `coord`, if it's inside the grid.
+    pub fn offset(&self, coord: Coord, dx: isize, dy: isize) -> Option<Coord> {
+        coord.offset(dx, dy).filter(|&next| self.contains(next))
+    }
+
+    /// The cell `(dx, dy)` away from `coord`, if it's inside the grid.
+    pub fn get_offset(&self, coord: Coord, dx: isize, dy: isize) -> Option<&T> {
+        self.get(coord.offset(dx, dy)?)
+    }
+
+    /// One step from `coord` in `direction`, if that's inside the grid.
+    pub fn step(&self, coord: Coord, direction: Direction) -> Option<Coord> {
+        coord.step(direction).filter(|&next| self.contains(next))
+    }
+
+    /// The orthogonal neighbours of `coord` that are inside the grid.
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(coord, direction))
+    }
+
+    /// The orthogonal and diagonal neighbours of `coord` that are inside the
+    /// grid.
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.offset(coord, dx, dy))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// The cells from `start` (inclusive) stepping `(dx, dy)` at a time until
+    /// leaving the grid.
+    pub fn ray(&self, start: Coord, dx: isize, dy: isize) -> impl Iterator<Item = (Coord, &T)> {
+        std::iter::successors(self.contains(start).then_some(start), move |&coord| {
+            self.offset(coord, dx, dy)
+        })
+        .map(|coord| (coord, &self[coord]))
+    }
+
+    /// The diagonal running down and to the right from `start`.
+    pub fn diagonal(&self, start: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        self.ray(start, 1, 1)
+    }
+
+    /// The diagonal running down and to the left from `start`.
+    pub fn anti_diagonal(&self, start: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        self.ray(start, -1, 1)
+    }
+
+    /// Every coordinate, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Coord::new(i % width, i / width))
+    }
+
+    /// Every cell with its coordinate, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.coord_of(i), cell))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut T)> {
+        let width = self.width;
+        self.cells
+            .iter_mut()
+            .enumerate()
+            .map(move |(i, cell)| (Coord::new(i % width, i / width), cell))
+    }
+
+    /// The coordinate of the first cell, row by row, that matches.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Coord> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.coord_of(i))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        match self.index_of(coord) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "{},{} is outside a {}x{} grid",
+                coord.x, coord.y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        match self.index_of(coord) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "{},{} is outside a {}x{} grid",
+                coord.x, coord.y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Tile {
+        Open,
+        Wall,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = char;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Tile::Open),
+                '#' => Ok(Tile::Wall),
+                _ => Err(c),
+            }
+        }
+    }
+
+    impl fmt::Display for Tile {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Tile::Open => write!(f, "."),
+                Tile::Wall => write!(f, "#"),
+            }
+        }
+    }
+
+    const INPUT: &str = "#..\n.#.\n..#\n#..\n";
+
+    #[test]
+    fn test_parse() {
+        let grid: Grid<Tile> = INPUT.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+        assert_eq!(grid[Coord::new(1, 1)], Tile::Wall);
+        assert_eq!(grid.get(Coord::new(3, 0)), None);
+        assert_eq!(grid.to_string(), INPUT);
+        assert_eq!(
+            Grid::<Tile>::parse("#.\n.x\n"),
+            Err(ParseGridError::Tile {
+                coord: Coord::new(1, 1),
+                found: 'x'
+            })
+        );
+        assert_eq!(
+            Grid::<Tile>::parse("#.\n.\n"),
+            Err(ParseGridError::Ragged {
+                line: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(Grid::<Tile>::parse(""), Err(ParseGridError::Empty));
+        assert_eq!(
+            Grid::<char>::parse("ab\ncd").unwrap()[Coord::new(0, 1)],
+            'c'
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid: Grid<Tile> = INPUT.parse().unwrap();
+        let corner: Vec<Coord> = grid.neighbours4(Coord::new(0, 0)).collect();
+        assert_eq!(corner, vec![Coord::new(1, 0), Coord::new(0, 1)]);
+        assert_eq!(grid.neighbours4(Coord::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Coord::new(1, 1)).count(), 8);
+        assert_eq!(grid.neighbours8(Coord::new(2, 3)).count(), 3);
+        assert_eq!(grid.get_offset(Coord::new(0, 0), -1, 0), None);
+        assert_eq!(grid.get_offset(Coord::new(0, 0), 2, 2), Some(&Tile::Wall));
+        assert_eq!(grid.step(Coord::new(2, 0), Direction::East), None);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid: Grid<Tile> = INPUT.parse().unwrap();
+        assert_eq!(grid.row(1), &[Tile::Open, Tile::Wall, Tile::Open]);
+        let column: Vec<Tile> = grid.column(0).copied().collect();
+        assert_eq!(column, vec![Tile::Wall, Tile::Open, Tile::Open, Tile::Wall]);
+        assert!(grid
+            .diagonal(Coord::new(0, 0))
+            .all(|(_, tile)| *tile == Tile::Wall));
+        assert_eq!(grid.diagonal(Coord::new(0, 0)).count(), 3);
+        let anti: Vec<Coord> = grid
+            .anti_diagonal(Coord::new(2, 1))
+            .map(|(coord, _)| coord)
+            .collect();
+        assert_eq!(
+            anti,
+            vec![Coord::new(2, 1), Coord::new(1, 2), Coord::new(0, 3)]
+        );
+        assert_eq!(grid.ray(Coord::new(5, 5), 1, 0).count(), 0);
+        assert_eq!(
+            grid.position(|tile| *tile == Tile::Wall),
+            Some(Coord::new(0, 0))
+        );
+        assert_eq!(
+            grid.iter().filter(|(_, tile)| **tile == Tile::Wall).count(),
+            4
+        );
+        assert_eq!(grid.coords().last(), Some(Coord::new(2, 3)));
+    }
+
+    #[test]
+    fn test_directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+        }
+        assert_eq!(Direction::from_arrow('<'), Some(Direction::West));
+        assert_eq!(Coord::new(0, 0).step(Direction::North), None);
+        assert_eq!(Coord::new(1, 5).manhattan(Coord::new(4, 1)), 7);
+    }
+}
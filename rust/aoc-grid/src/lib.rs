@@ -0,0 +1,8 @@
+//! A 2D grid with a flat backing store, and the coordinates and directions
+//! that go with it.
+
+mod coord;
+mod grid;
+
+pub use coord::{Coord, Direction, NEIGHBOURS_8};
+pub use grid::{Grid, ParseGridError};
@@ -0,0 +1,9 @@
+//! Sets of integers kept as sorted, merged ranges, and maps that shift parts
+//! of the number line, for puzzles whose inputs are ranges far too big to
+//! walk one value at a time.
+
+mod set;
+mod shift;
+
+pub use set::IntervalSet;
+pub use shift::ShiftMap;
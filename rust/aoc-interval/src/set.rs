@@ -0,0 +1,161 @@
+use std::ops::Range;
+
+/// A set of integers as sorted half-open ranges, with overlapping and
+/// touching ranges merged so each set has exactly one representation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    /// Adds every value in `range`, merging it with the ranges it overlaps or
+    /// touches.
+    pub fn insert(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = if first < last {
+            range.start.min(self.ranges[first].start)..range.end.max(self.ranges[last - 1].end)
+        } else {
+            range
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let i = self.ranges.partition_point(|r| r.end <= value);
+        self.ranges.get(i).is_some_and(|r| r.start <= value)
+    }
+
+    /// The ranges, lowest first, none of them empty, overlapping or touching.
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    /// How many values are in the set.
+    pub fn len(&self) -> i64 {
+        self.ranges.iter().map(|r| r.end - r.start).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|r| r.end - 1)
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (a.start.max(b.start), a.end.min(b.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// The values in `self` that aren't in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = vec![];
+        for range in &self.ranges {
+            let mut start = range.start;
+            let first = other.ranges.partition_point(|r| r.end <= range.start);
+            for cut in other.ranges[first..]
+                .iter()
+                .take_while(|r| r.start < range.end)
+            {
+                if start < cut.start {
+                    ranges.push(start..cut.start);
+                }
+                start = start.max(cut.end);
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Every value moved by `offset`.
+    pub fn shifted(&self, offset: i64) -> IntervalSet {
+        IntervalSet {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|r| r.start + offset..r.end + offset)
+                .collect(),
+        }
+    }
+}
+
+impl From<Range<i64>> for IntervalSet {
+    fn from(range: Range<i64>) -> IntervalSet {
+        [range].into_iter().collect()
+    }
+}
+
+impl FromIterator<Range<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(ranges: I) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+impl Extend<Range<i64>> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Range<i64>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_set() {
+        let set: IntervalSet = [10..15, 1..3, 3..5, 12..20, 30..30].into_iter().collect();
+        assert_eq!(set.ranges(), [1..5, 10..20]);
+        assert_eq!((set.len(), set.min(), set.max()), (14, Some(1), Some(19)));
+        assert!(set.contains(4) && set.contains(10) && !set.contains(5) && !set.contains(20));
+
+        let other: IntervalSet = [0..2, 4..11, 18..25].into_iter().collect();
+        assert_eq!(set.union(&other), IntervalSet::from(0..25));
+        assert_eq!(
+            set.intersection(&other).ranges(),
+            [1..2, 4..5, 10..11, 18..20]
+        );
+        assert_eq!(set.difference(&other).ranges(), [2..4, 11..18]);
+        assert_eq!(other.difference(&set).ranges(), [0..1, 5..10, 20..25]);
+        assert_eq!(set.shifted(-1).ranges(), [0..4, 9..19]);
+        assert!(set.difference(&IntervalSet::from(0..100)).is_empty());
+    }
+}
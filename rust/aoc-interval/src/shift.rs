@@ -0,0 +1,72 @@
+use std::ops::Range;
+
+use crate::IntervalSet;
+
+/// Rules that each move the values in one range by an offset, leaving values
+/// no rule covers where they are. Where rules overlap, the first one added
+/// wins.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShiftMap {
+    rules: Vec<(Range<i64>, i64)>,
+}
+
+impl ShiftMap {
+    pub fn new() -> ShiftMap {
+        ShiftMap::default()
+    }
+
+    /// Moves the values in `source` by `offset`.
+    pub fn add(&mut self, source: Range<i64>, offset: i64) {
+        self.rules.push((source, offset));
+    }
+
+    pub fn get(&self, value: i64) -> i64 {
+        self.rules
+            .iter()
+            .find(|(source, _)| source.contains(&value))
+            .map_or(value, |(_, offset)| value + offset)
+    }
+
+    /// Where every value in `set` ends up, worked out a range at a time.
+    pub fn apply(&self, set: &IntervalSet) -> IntervalSet {
+        let mut unmoved = set.clone();
+        let mut moved = IntervalSet::new();
+        for (source, offset) in &self.rules {
+            let source = IntervalSet::from(source.clone());
+            moved = moved.union(&unmoved.intersection(&source).shifted(*offset));
+            unmoved = unmoved.difference(&source);
+        }
+        moved.union(&unmoved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_map() {
+        // the seed-to-soil map from 2023 day 5
+        let mut map = ShiftMap::new();
+        map.add(98..100, 50 - 98);
+        map.add(50..98, 52 - 50);
+        assert_eq!(
+            [79, 14, 55, 13, 98].map(|v| map.get(v)),
+            [81, 14, 57, 13, 50]
+        );
+
+        let seeds: IntervalSet = [79..93, 55..68, 95..101].into_iter().collect();
+        assert_eq!(
+            map.apply(&seeds).ranges(),
+            [50..52, 57..70, 81..95, 97..101]
+        );
+
+        let mut overlapping = ShiftMap::new();
+        overlapping.add(0..10, 100);
+        overlapping.add(5..15, 200);
+        assert_eq!(
+            overlapping.apply(&IntervalSet::from(0..20)).ranges(),
+            [15..20, 100..110, 210..215]
+        );
+    }
+}
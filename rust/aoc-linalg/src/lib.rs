@@ -0,0 +1,9 @@
+//! Exact linear algebra for puzzles that are systems of equations in
+//! disguise: rationals over `i128`, Gaussian elimination, and searches over
+//! the free variables for bounded non-negative integer solutions.
+
+mod rational;
+mod system;
+
+pub use rational::Rational;
+pub use system::{LinearSystem, Reduced};
@@ -0,0 +1,367 @@
+use crate::{rational::lcm, Rational};
+
+/// `a x = b`, kept as the augmented matrix `[a | b]`.
+#[derive(Debug, Clone)]
+pub struct LinearSystem {
+    rows: Vec<Vec<Rational>>,
+    columns: usize,
+}
+
+impl LinearSystem {
+    /// The system with coefficient rows `a` and right hand side `b`.
+    pub fn new(a: &[Vec<i128>], b: &[i128]) -> LinearSystem {
+        assert_eq!(a.len(), b.len(), "every equation needs a right hand side");
+        let columns = a.first().map_or(0, Vec::len);
+        let rows = a
+            .iter()
+            .zip(b)
+            .map(|(row, &rhs)| {
+                assert_eq!(row.len(), columns, "every equation needs every variable");
+                row.iter()
+                    .map(|&value| value.into())
+                    .chain([rhs.into()])
+                    .collect()
+            })
+            .collect();
+        LinearSystem { rows, columns }
+    }
+
+    /// Gauss-Jordan elimination down to reduced row echelon form, or `None`
+    /// if the equations contradict each other.
+    pub fn reduce(mut self) -> Option<Reduced> {
+        let mut pivots = Vec::new();
+        let mut row = 0;
+        for column in 0..self.columns {
+            let Some(found) = (row..self.rows.len()).find(|&r| !self.rows[r][column].is_zero())
+            else {
+                continue;
+            };
+            self.rows.swap(row, found);
+            let scale = self.rows[row][column];
+            for value in self.rows[row].iter_mut() {
+                *value = *value / scale;
+            }
+            let pivot_row = self.rows[row].clone();
+            for (r, other) in self.rows.iter_mut().enumerate() {
+                let factor = other[column];
+                if r != row && !factor.is_zero() {
+                    for (value, &pivot) in other.iter_mut().zip(&pivot_row) {
+                        *value = *value - factor * pivot;
+                    }
+                }
+            }
+            pivots.push(column);
+            row += 1;
+        }
+        // what's left is all zeros on the left, so it needs zeros on the right
+        if self.rows[row..]
+            .iter()
+            .any(|rest| !rest[self.columns].is_zero())
+        {
+            return None;
+        }
+        self.rows.truncate(row);
+        let free = (0..self.columns).filter(|c| !pivots.contains(c)).collect();
+        Some(Reduced {
+            columns: self.columns,
+            pivots: pivots.into_iter().zip(self.rows).collect(),
+            free,
+        })
+    }
+}
+
+/// A consistent system in reduced row echelon form, where every pivot
+/// variable is its row's right hand side less some multiple of the free
+/// variables.
+#[derive(Debug, Clone)]
+pub struct Reduced {
+    columns: usize,
+    /// each pivot's column and its row of the augmented matrix
+    pivots: Vec<(usize, Vec<Rational>)>,
+    free: Vec<usize>,
+}
+
+impl Reduced {
+    /// The variables that can take any value, in column order.
+    pub fn free_columns(&self) -> &[usize] {
+        &self.free
+    }
+
+    /// Every variable's value once the free ones are set to `free_values`.
+    pub fn solve_with(&self, free_values: &[Rational]) -> Vec<Rational> {
+        assert_eq!(
+            free_values.len(),
+            self.free.len(),
+            "one value per free variable"
+        );
+        let mut values = vec![Rational::ZERO; self.columns];
+        for (&column, &value) in self.free.iter().zip(free_values) {
+            values[column] = value;
+        }
+        for (column, row) in &self.pivots {
+            values[*column] = self
+                .free
+                .iter()
+                .zip(free_values)
+                .fold(row[self.columns], |acc, (&free, &value)| {
+                    acc - row[free] * value
+                });
+        }
+        values
+    }
+
+    /// The only solution, if there are no free variables.
+    pub fn unique_solution(&self) -> Option<Vec<Rational>> {
+        self.free.is_empty().then(|| self.solve_with(&[]))
+    }
+
+    /// Every solution in non-negative integers with each variable at most its
+    /// entry in `upper`.
+    pub fn integer_solutions(&self, upper: &[i128]) -> Vec<Vec<i128>> {
+        let mut solutions = Vec::new();
+        Search::new(self, upper, &vec![0; self.columns]).run(&mut |values| {
+            solutions.push(values);
+            None
+        });
+        solutions
+    }
+
+    /// The non-negative integer solution within `upper` with the smallest
+    /// `cost · x`, and that cost. A branch and bound over the free variables
+    /// that drops branches that can't beat the best so far.
+    pub fn minimise(&self, cost: &[i128], upper: &[i128]) -> Option<(i128, Vec<i128>)> {
+        assert_eq!(cost.len(), self.columns, "one cost per variable");
+        let mut best: Option<(i128, Vec<i128>)> = None;
+        Search::new(self, upper, cost).run(&mut |values| {
+            let total = values.iter().zip(cost).map(|(x, c)| x * c).sum();
+            if best.as_ref().is_none_or(|(least, _)| total < *least) {
+                best = Some((total, values));
+            }
+            best.as_ref().map(|(least, _)| *least)
+        });
+        best
+    }
+}
+
+/// A pivot row cleared of fractions:
+/// `scale * x = constant - Σ coefficients[i] * free[i]`.
+struct ScaledRow {
+    column: usize,
+    scale: i128,
+    constant: i128,
+    coefficients: Vec<i128>,
+}
+
+/// `numerators / scale` over a common denominator.
+fn clear_denominators(values: &[Rational]) -> (i128, Vec<i128>) {
+    let scale = values.iter().fold(1, |acc, value| lcm(acc, value.denom()));
+    let numerators = values
+        .iter()
+        .map(|value| value.numer() * (scale / value.denom()))
+        .collect();
+    (scale, numerators)
+}
+
+/// The least and most `-coefficient * x` can be for `x` in `0..=upper`.
+fn term_range(coefficient: i128, upper: i128) -> (i128, i128) {
+    let far = -coefficient * upper;
+    (far.min(0), far.max(0))
+}
+
+/// Depth first over the free variables one at a time, cutting off branches
+/// where a pivot variable can no longer land in its bounds or, with an
+/// objective, where the cost can no longer beat the best found.
+struct Search<'a> {
+    upper: &'a [i128],
+    free: &'a [usize],
+    columns: usize,
+    rows: Vec<ScaledRow>,
+    /// `reach[r][i]`: how little and how much the free variables from `i` on
+    /// can still add to row `r`
+    reach: Vec<Vec<(i128, i128)>>,
+    /// the cost in terms of the free variables, cleared of fractions:
+    /// `objective_scale * cost = objective_constant + Σ objective[i] * free[i]`
+    objective: Vec<i128>,
+    objective_scale: i128,
+    objective_constant: i128,
+    /// the least the free variables from `i` on can still add to the scaled
+    /// objective
+    objective_reach: Vec<i128>,
+}
+
+impl<'a> Search<'a> {
+    fn new(reduced: &'a Reduced, upper: &'a [i128], cost: &[i128]) -> Search<'a> {
+        assert_eq!(upper.len(), reduced.columns, "one bound per variable");
+        let free = &reduced.free;
+        let rows: Vec<ScaledRow> = reduced
+            .pivots
+            .iter()
+            .map(|(column, row)| {
+                let values: Vec<Rational> = free
+                    .iter()
+                    .map(|&f| row[f])
+                    .chain([row[reduced.columns]])
+                    .collect();
+                let (scale, mut numerators) = clear_denominators(&values);
+                let constant = numerators.pop().expect("the right hand side is last");
+                ScaledRow {
+                    column: *column,
+                    scale,
+                    constant,
+                    coefficients: numerators,
+                }
+            })
+            .collect();
+        let suffix_ranges = |coefficients: &[i128]| {
+            let mut ranges = vec![(0, 0); free.len() + 1];
+            for i in (0..free.len()).rev() {
+                let (low, high) = term_range(coefficients[i], upper[free[i]]);
+                ranges[i] = (ranges[i + 1].0 + low, ranges[i + 1].1 + high);
+            }
+            ranges
+        };
+        let reach = rows
+            .iter()
+            .map(|row| suffix_ranges(&row.coefficients))
+            .collect();
+
+        // cost · x with the pivots written out in terms of the free variables
+        let mut values: Vec<Rational> = free.iter().map(|&f| cost[f].into()).collect();
+        let mut constant = Rational::ZERO;
+        for (column, row) in &reduced.pivots {
+            let weight = Rational::from(cost[*column]);
+            constant = constant + weight * row[reduced.columns];
+            for (value, &f) in values.iter_mut().zip(free) {
+                *value = *value - weight * row[f];
+            }
+        }
+        values.push(constant);
+        let (objective_scale, mut objective) = clear_denominators(&values);
+        let objective_constant = objective.pop().expect("the constant is last");
+        let negated: Vec<i128> = objective.iter().map(|c| -c).collect();
+        let objective_reach = suffix_ranges(&negated)
+            .into_iter()
+            .map(|(low, _)| low)
+            .collect();
+        Search {
+            upper,
+            free,
+            columns: reduced.columns,
+            rows,
+            reach,
+            objective,
+            objective_scale,
+            objective_constant,
+            objective_reach,
+        }
+    }
+
+    /// Calls `found` with every solution the search reaches. `found` returns
+    /// the best cost so far, if branches should be pruned against it.
+    fn run(&self, found: &mut dyn FnMut(Vec<i128>) -> Option<i128>) {
+        let remainders: Vec<i128> = self.rows.iter().map(|row| row.constant).collect();
+        let mut values = vec![0; self.free.len()];
+        let mut best = None;
+        self.visit(
+            0,
+            &mut values,
+            remainders,
+            self.objective_constant,
+            &mut best,
+            found,
+        );
+    }
+
+    fn visit(
+        &self,
+        depth: usize,
+        values: &mut Vec<i128>,
+        remainders: Vec<i128>,
+        objective: i128,
+        best: &mut Option<i128>,
+        found: &mut dyn FnMut(Vec<i128>) -> Option<i128>,
+    ) {
+        let in_reach =
+            self.rows
+                .iter()
+                .zip(&remainders)
+                .zip(&self.reach)
+                .all(|((row, remainder), reach)| {
+                    let (low, high) = reach[depth];
+                    remainder + high >= 0 && remainder + low <= row.scale * self.upper[row.column]
+                });
+        if !in_reach {
+            return;
+        }
+        if let Some(best) = *best {
+            // whole costs are multiples of the scale, so round the bound up
+            let least = objective + self.objective_reach[depth];
+            let scale = self.objective_scale;
+            if least.div_euclid(scale) + i128::from(least.rem_euclid(scale) != 0) >= best {
+                return;
+            }
+        }
+        if depth == self.free.len() {
+            let mut solution = vec![0; self.columns];
+            for (&column, &value) in self.free.iter().zip(values.iter()) {
+                solution[column] = value;
+            }
+            for (row, remainder) in self.rows.iter().zip(&remainders) {
+                if remainder % row.scale != 0 {
+                    return;
+                }
+                solution[row.column] = remainder / row.scale;
+            }
+            *best = found(solution);
+            return;
+        }
+        for value in 0..=self.upper[self.free[depth]] {
+            values[depth] = value;
+            let next: Vec<i128> = self
+                .rows
+                .iter()
+                .zip(&remainders)
+                .map(|(row, remainder)| remainder - row.coefficients[depth] * value)
+                .collect();
+            let next_objective = objective + self.objective[depth] * value;
+            self.visit(depth + 1, values, next, next_objective, best, found);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system() {
+        // 94a + 22b = 8400, 34a + 67b = 5400
+        let system = LinearSystem::new(&[vec![94, 22], vec![34, 67]], &[8400, 5400]);
+        let solution = system.reduce().unwrap().unique_solution().unwrap();
+        assert_eq!(solution, [Rational::from(80), Rational::from(40)]);
+
+        let contradiction = LinearSystem::new(&[vec![1, 1], vec![2, 2]], &[1, 3]);
+        assert!(contradiction.reduce().is_none());
+
+        // a + b = 4, b + c = 3
+        let reduced = LinearSystem::new(&[vec![1, 1, 0], vec![0, 1, 1]], &[4, 3])
+            .reduce()
+            .unwrap();
+        assert_eq!(reduced.free_columns(), [2]);
+        assert_eq!(reduced.unique_solution(), None);
+        let mut solutions = reduced.integer_solutions(&[4, 4, 4]);
+        solutions.sort();
+        assert_eq!(
+            solutions,
+            [vec![1, 3, 0], vec![2, 2, 1], vec![3, 1, 2], vec![4, 0, 3]]
+        );
+        assert_eq!(
+            reduced.minimise(&[1, 1, 1], &[4, 4, 4]),
+            Some((4, vec![1, 3, 0]))
+        );
+        assert_eq!(
+            reduced.minimise(&[1, 5, 1], &[4, 4, 4]),
+            Some((7, vec![4, 0, 3]))
+        );
+    }
+}
@@ -0,0 +1,126 @@
+//! Reads the capital letters some puzzles draw instead of printing an answer,
+//! in either the 6 pixel tall font or the 10 pixel tall one.
+
+mod font;
+
+use std::fmt;
+
+use aoc_core::AocError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// the picture isn't as tall as either font
+    Height(usize),
+    /// the lit columns starting at `column` don't match any letter
+    UnknownGlyph { column: usize, glyph: String },
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OcrError::Height(height) => {
+                write!(f, "no font is {height} pixels tall, expected 6 or 10")
+            }
+            OcrError::UnknownGlyph { column, glyph } => {
+                write!(f, "unknown letter at column {column}:\n{glyph}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+impl From<OcrError> for AocError {
+    fn from(error: OcrError) -> AocError {
+        AocError::Solver(format!("could not read the letters: {error}"))
+    }
+}
+
+fn is_lit(pixel: u8) -> bool {
+    pixel == b'#'
+}
+
+/// The letters drawn in `picture`, one row per line with `#` for lit pixels
+/// and anything else for dark ones. Letters are told apart by the dark
+/// columns between them, so the spacing doesn't matter.
+pub fn recognise(picture: &str) -> Result<String, OcrError> {
+    let rows: Vec<&[u8]> = picture
+        .lines()
+        .map(str::as_bytes)
+        .filter(|row| !row.is_empty())
+        .collect();
+    let font = font::for_height(rows.len()).ok_or(OcrError::Height(rows.len()))?;
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let lit_column = |x: usize| {
+        rows.iter()
+            .any(|row| row.get(x).is_some_and(|&p| is_lit(p)))
+    };
+
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < width {
+        if !lit_column(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < width && lit_column(x) {
+            x += 1;
+        }
+        let glyph: Vec<String> = rows
+            .iter()
+            .map(|row| {
+                (start..x)
+                    .map(|x| match row.get(x) {
+                        Some(&p) if is_lit(p) => '#',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+        let letter = font
+            .iter()
+            .find(|(_, pattern)| pattern.iter().copied().eq(glyph.iter().map(String::as_str)))
+            .map(|(letter, _)| *letter)
+            .ok_or_else(|| OcrError::UnknownGlyph {
+                column: start,
+                glyph: glyph.join("\n"),
+            })?;
+        letters.push(letter);
+    }
+    Ok(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recognise() {
+        let picture = "\
+####...##..##..####.###...##..#....#..#.
+#.......#.#..#.#....#..#.#..#.#....#..#.
+###.....#.#....###..#..#.#....#....####.
+#.......#.#....#....###..#.##.#....#..#.
+#....#..#.#..#.#....#....#..#.#....#..#.
+####..##...##..#....#.....###.####.#..#.";
+        assert_eq!(recognise(picture), Ok("EJCFPGLH".to_string()));
+
+        let tall = "\
+#....#..######
+#....#.......#
+.#..#........#
+.#..#.......#.
+..##.......#..
+..##......#...
+.#..#....#....
+.#..#...#.....
+#....#..#.....
+#....#..######";
+        assert_eq!(recognise(tall), Ok("XZ".to_string()));
+
+        assert_eq!(recognise("#\n#"), Err(OcrError::Height(2)));
+        let error = recognise("#.#\n###\n#.#\n#.#\n#.#\n#.#").unwrap_err();
+        assert!(matches!(error, OcrError::UnknownGlyph { column: 0, .. }));
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt;
+
+use aoc_core::AocError;
+use nom::Parser;
+
+/// Where an input stopped matching its parser. `line` and `column` count from
+/// 1, and `text` is the whole offending line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// The error for `rest`, the part of `input` left when parsing stopped.
+    fn at(input: &str, rest: &str, message: String) -> ParseError {
+        let offset = input.len() - rest.len();
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find(['\r', '\n'])
+            .map_or(input.len(), |i| offset + i);
+        ParseError {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: input[line_start..line_end].to_string(),
+            message,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "  {}", self.text)?;
+        write!(f, "  {:>width$}", "^", width = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for AocError {
+    fn from(error: ParseError) -> AocError {
+        AocError::Parse {
+            line: error.line,
+            column: error.column,
+            message: format!("{} in {:?}", error.message, error.text),
+        }
+    }
+}
+
+/// Runs `parser` over the whole of `input`. Anything but trailing whitespace
+/// left over afterwards is an error too.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, T, nom::error::Error<&'a str>>,
+) -> Result<T, ParseError> {
+    match parser.parse(input) {
+        Ok((rest, value)) if rest.trim().is_empty() => Ok(value),
+        Ok((rest, _)) => Err(ParseError::at(
+            input,
+            rest.trim_start(),
+            "unexpected input".to_string(),
+        )),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError::at(
+            input,
+            e.input,
+            format!("expected {}", e.code.description()),
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(
+            input,
+            "",
+            "unexpected end of input".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{int, lines};
+
+    #[test]
+    fn test_parse_all() {
+        assert_eq!(
+            parse_all("1\n-2\n+3\n", lines(int::<i32>)),
+            Ok(vec![1, -2, 3])
+        );
+
+        let error = parse_all("1\n2\n3x\n4", lines(int::<i32>)).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 2));
+        assert_eq!(error.text, "3x");
+        assert_eq!(error.message, "unexpected input");
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 2: unexpected input\n  3x\n   ^"
+        );
+
+        let error = parse_all("1\nx", int::<i32>).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = parse_all("x", int::<i32>).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.message, "expected Digit");
+        assert_eq!(
+            AocError::from(error).to_string(),
+            "parse error at line 1, column 1: expected Digit in \"x\""
+        );
+    }
+}
@@ -0,0 +1,9 @@
+//! nom parsers for the shapes puzzle inputs keep coming in, and `parse_all`
+//! to run one over a whole input and say where it went wrong if it doesn't
+//! fit.
+
+mod error;
+mod parsers;
+
+pub use error::{parse_all, ParseError};
+pub use parsers::{comma_list, grid, int, key_value, key_values, lines, sections, space_list};
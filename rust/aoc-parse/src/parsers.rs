@@ -0,0 +1,140 @@
+use std::str::FromStr;
+
+use aoc_grid::{Grid, ParseGridError};
+use nom::{
+    bytes::complete::take_till1,
+    character::complete::{char, digit1, line_ending, one_of, space0, space1},
+    combinator::{map_res, opt, recognize},
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    sequence::{delimited, pair, separated_pair},
+    IResult, Parser,
+};
+
+/// An integer of any width, with an optional `+` or `-` in front.
+pub fn int<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// One or more `item`s separated by commas, with optional spaces around them.
+pub fn comma_list<'a, T>(
+    item: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(delimited(space0, char(','), space0), item)
+}
+
+/// One or more `item`s separated by spaces or tabs.
+pub fn space_list<'a, T>(
+    item: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(space1, item)
+}
+
+/// One `item` per line, stopping at a blank line or the end of the input.
+pub fn lines<'a, T>(
+    item: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(line_ending, item)
+}
+
+/// One or more `section`s separated by blank lines.
+pub fn sections<'a, T>(
+    section: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(pair(line_ending, line_ending), section)
+}
+
+/// `key: value`, where the key is everything up to the colon.
+pub fn key_value<'a, T>(
+    value: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (&'a str, T)> {
+    separated_pair(
+        take_till1(|c| c == ':' || c == '\n'),
+        pair(char(':'), space0),
+        value,
+    )
+}
+
+/// A block of `key: value` lines.
+pub fn key_values<'a, T>(
+    value: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<(&'a str, T)>> {
+    lines(key_value(value))
+}
+
+/// A grid of one tile per character, up to a blank line or the end of the
+/// input. A character the tile type rejects, or a line of the wrong length,
+/// fails at that spot.
+pub fn grid<T: TryFrom<char>>(input: &str) -> IResult<&str, Grid<T>> {
+    let (rest, text) = recognize(lines(take_till1(|c| c == '\r' || c == '\n')))(input)?;
+    let (line, column, kind) = match Grid::parse(text) {
+        Ok(grid) => return Ok((rest, grid)),
+        Err(ParseGridError::Empty) => (0, 0, ErrorKind::Eof),
+        Err(ParseGridError::Ragged { line, expected, .. }) => {
+            (line - 1, expected, ErrorKind::Verify)
+        }
+        Err(ParseGridError::Tile { coord, .. }) => (coord.y, coord.x, ErrorKind::Char),
+    };
+    let line_start: usize = text.split_inclusive('\n').take(line).map(str::len).sum();
+    let line_text = text[line_start..].lines().next().unwrap_or("");
+    let offset = line_start
+        + line_text
+            .char_indices()
+            .nth(column)
+            .map_or(line_text.len(), |(i, _)| i);
+    Err(nom::Err::Error(Error::new(&input[offset..], kind)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_all;
+
+    #[test]
+    fn test_parsers() {
+        assert_eq!(int::<u8>("255,"), Ok((",", 255)));
+        assert!(int::<u8>("256").is_err());
+        assert_eq!(
+            int::<i128>("-170141183460469231731687303715884105728"),
+            Ok(("", i128::MIN))
+        );
+        assert_eq!(
+            comma_list(int::<i8>)("1, -2,3 x"),
+            Ok((" x", vec![1, -2, 3]))
+        );
+        assert_eq!(
+            space_list(int::<u32>)("1  2\t3\n4"),
+            Ok(("\n4", vec![1, 2, 3]))
+        );
+        assert_eq!(
+            sections(lines(int::<u32>))("1\n2\n\n3\n"),
+            Ok(("\n", vec![vec![1, 2], vec![3]]))
+        );
+        assert_eq!(
+            key_values(int::<i64>)("Register A: 729\nRegister B: -1"),
+            Ok(("", vec![("Register A", 729), ("Register B", -1)]))
+        );
+
+        let (rest, map) = grid::<char>("ab\ncd\n\nxy").unwrap();
+        assert_eq!((map.width(), map.height(), rest), (2, 2, "\n\nxy"));
+
+        let error = parse_all("..\n.#\n..", grid::<Dot>).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = parse_all("..\n.\n..", grid::<Dot>).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[derive(Debug)]
+    struct Dot;
+
+    impl TryFrom<char> for Dot {
+        type Error = char;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Dot),
+                _ => Err(c),
+            }
+        }
+    }
+}
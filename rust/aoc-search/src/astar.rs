@@ -0,0 +1,87 @@
+use std::{
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::dijkstra::Queued;
+
+/// A* search from `start` to the first node `is_goal` accepts, returning its
+/// cost and the path there, start first. `heuristic` must never overestimate
+/// the remaining cost, or the path found may not be the cheapest.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut costs: HashMap<N, C> = HashMap::new();
+    let mut came_from: HashMap<N, N> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    costs.insert(start.clone(), C::default());
+    queue.push(Queued {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    });
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if costs[&node] < cost {
+            continue;
+        }
+        if is_goal(&node) {
+            let mut path = vec![node];
+            while let Some(previous) = came_from.get(path.last()?) {
+                path.push(previous.clone());
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_some_and(|&best| best <= next_cost) {
+                continue;
+            }
+            costs.insert(next.clone(), next_cost);
+            came_from.insert(next.clone(), node.clone());
+            queue.push(Queued {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_astar() {
+        // an open 10x10 square with a wall across x = 5 apart from at y = 9
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(0, -1), (1, 0), (0, 1), (-1, 0)]
+                .into_iter()
+                .map(move |(dx, dy)| (x + dx, y + dy))
+                .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+                .filter(|&(x, y)| x != 5 || y == 9)
+                .map(|next| (next, 1))
+        };
+        let manhattan = |&(x, y): &(i32, i32)| (9 - x).abs() + y.abs();
+        let (cost, path) = astar((0, 0), neighbours, manhattan, |&node| node == (9, 0)).unwrap();
+        assert_eq!(cost, 27);
+        assert_eq!(path.len(), 28);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert!(path.contains(&(5, 9)));
+        assert_eq!(
+            astar((0, 0), neighbours, manhattan, |&node| node == (20, 0)),
+            None
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use std::{collections::VecDeque, hash::Hash};
+
+use crate::Paths;
+
+/// Breadth first search from every node in `sources` at once, where each call
+/// to `neighbours` lists the nodes one step away.
+pub fn bfs<N, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Paths<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new();
+    let mut queue = VecDeque::new();
+    for source in sources {
+        if paths.costs.insert(source.clone(), 0).is_none() {
+            queue.push_back(source);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let next_cost = paths.costs[&node] + 1;
+        for next in neighbours(&node) {
+            match paths.costs.get(&next) {
+                None => {
+                    paths.costs.insert(next.clone(), next_cost);
+                    paths.predecessors.insert(next.clone(), vec![node.clone()]);
+                    queue.push_back(next);
+                }
+                Some(&cost) if cost == next_cost => {
+                    paths
+                        .predecessors
+                        .entry(next)
+                        .or_default()
+                        .push(node.clone());
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bfs() {
+        // a 3x3 square of nodes, with the middle missing
+        let neighbours = |&(x, y): &(i32, i32)| {
+            [(0, -1), (1, 0), (0, 1), (-1, 0)]
+                .into_iter()
+                .map(move |(dx, dy)| (x + dx, y + dy))
+                .filter(|&(x, y)| (0..3).contains(&x) && (0..3).contains(&y) && (x, y) != (1, 1))
+        };
+        let paths = bfs([(0, 0)], neighbours);
+        assert_eq!(paths.cost(&(2, 2)), Some(4));
+        assert_eq!(paths.cost(&(1, 1)), None);
+        assert_eq!(paths.count_paths(&(2, 2)), 2);
+        assert_eq!(paths.on_cheapest_paths([(2, 2)]).len(), 8);
+        assert_eq!(paths.path_to(&(1, 0)), Some(vec![(0, 0), (1, 0)]));
+
+        let paths = bfs([(0, 0), (2, 2)], neighbours);
+        assert_eq!(paths.cost(&(2, 0)), Some(2));
+        assert_eq!(paths.count_paths(&(2, 0)), 2);
+        assert!(paths.predecessors(&(2, 2)).is_empty());
+    }
+}
@@ -0,0 +1,119 @@
+use std::{cmp::Ordering, collections::BinaryHeap, hash::Hash, ops::Add};
+
+use crate::Paths;
+
+/// A node waiting in the queue, ordered so the cheapest comes out of a
+/// `BinaryHeap` first.
+pub(crate) struct Queued<N, C> {
+    pub(crate) priority: C,
+    pub(crate) cost: C,
+    pub(crate) node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Dijkstra's search from every node in `sources` at once, where each call to
+/// `neighbours` lists the nodes one step away along with what that step
+/// costs. `C::default()` is taken as a cost of nothing.
+pub fn dijkstra<N, C, I>(
+    sources: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Paths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut paths = Paths::new();
+    let mut queue = BinaryHeap::new();
+    for source in sources {
+        if paths.costs.insert(source.clone(), C::default()).is_none() {
+            queue.push(Queued {
+                priority: C::default(),
+                cost: C::default(),
+                node: source,
+            });
+        }
+    }
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if paths.costs[&node] < cost {
+            // a cheaper way here was found after this one was queued
+            continue;
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            match paths.costs.get(&next) {
+                Some(&best) if best < next_cost => {}
+                Some(&best) if best == next_cost => {
+                    paths
+                        .predecessors
+                        .entry(next)
+                        .or_default()
+                        .push(node.clone());
+                }
+                _ => {
+                    paths.costs.insert(next.clone(), next_cost);
+                    paths.predecessors.insert(next.clone(), vec![node.clone()]);
+                    queue.push(Queued {
+                        priority: next_cost,
+                        cost: next_cost,
+                        node: next,
+                    });
+                }
+            }
+        }
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dijkstra() {
+        // two equally cheap routes from a to d, and a dearer direct one
+        let edges = [
+            ('a', 'b', 1),
+            ('a', 'c', 2),
+            ('b', 'd', 3),
+            ('c', 'd', 2),
+            ('a', 'd', 7),
+            ('d', 'e', 1),
+        ];
+        let neighbours = |node: &char| {
+            edges
+                .iter()
+                .filter(|(from, _, _)| from == node)
+                .map(|&(_, to, cost)| (to, cost))
+                .collect::<Vec<_>>()
+        };
+        let paths = dijkstra(['a'], neighbours);
+        assert_eq!(paths.cost(&'d'), Some(4));
+        assert_eq!(paths.cost(&'e'), Some(5));
+        assert_eq!(paths.count_paths(&'e'), 2);
+        let mut on_paths: Vec<char> = paths.on_cheapest_paths(['e']).into_iter().collect();
+        on_paths.sort();
+        assert_eq!(on_paths, vec!['a', 'b', 'c', 'd', 'e']);
+        assert_eq!(paths.cost(&'z'), None);
+        assert_eq!(paths.path_to(&'z'), None);
+    }
+}
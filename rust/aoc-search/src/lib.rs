@@ -0,0 +1,13 @@
+//! Shortest path searches over any graph given as a neighbour closure:
+//! breadth first for unit steps, Dijkstra for weighted ones and A* when
+//! there's a single goal and a decent estimate of the distance to it.
+
+mod astar;
+mod bfs;
+mod dijkstra;
+mod paths;
+
+pub use astar::astar;
+pub use bfs::bfs;
+pub use dijkstra::dijkstra;
+pub use paths::Paths;
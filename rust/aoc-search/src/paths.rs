@@ -0,0 +1,100 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+/// Everything a search reached: the cheapest cost from the nearest source to
+/// each node, and every neighbour the node can be reached from at that cost.
+///
+/// Following the predecessors back from a node walks every cheapest path to
+/// it, as long as all steps cost more than nothing.
+#[derive(Debug, Clone)]
+pub struct Paths<N, C> {
+    pub(crate) costs: HashMap<N, C>,
+    pub(crate) predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy + Ord> Paths<N, C> {
+    pub(crate) fn new() -> Paths<N, C> {
+        Paths {
+            costs: HashMap::new(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    /// The cheapest cost to `node`, or `None` if it can't be reached.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.costs.contains_key(node)
+    }
+
+    pub fn costs(&self) -> &HashMap<N, C> {
+        &self.costs
+    }
+
+    pub fn into_costs(self) -> HashMap<N, C> {
+        self.costs
+    }
+
+    /// The neighbours `node` is reached from on a cheapest path; empty for
+    /// the sources and for anything that wasn't reached.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One cheapest path from a source to `target`, source first.
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>> {
+        if !self.contains(target) {
+            return None;
+        }
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors(path.last()?).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node on any cheapest path from a source to one of `targets`,
+    /// the targets included.
+    pub fn on_cheapest_paths(&self, targets: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<N> = targets
+            .into_iter()
+            .filter(|target| self.contains(target))
+            .collect();
+        while let Some(node) = stack.pop() {
+            if seen.insert(node.clone()) {
+                stack.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+        seen
+    }
+
+    /// How many different cheapest paths lead from the sources to `target`.
+    pub fn count_paths(&self, target: &N) -> usize {
+        let mut by_cost: Vec<(&N, C)> = self
+            .on_cheapest_paths([target.clone()])
+            .into_iter()
+            .map(|node| {
+                let (node, cost) = self.costs.get_key_value(&node).expect("reached");
+                (node, *cost)
+            })
+            .collect();
+        by_cost.sort_by_key(|(_, cost)| *cost);
+        let mut counts: HashMap<&N, usize> = HashMap::new();
+        for (node, _) in by_cost {
+            let predecessors = self.predecessors(node);
+            let count = if predecessors.is_empty() {
+                1
+            } else {
+                predecessors.iter().map(|previous| counts[previous]).sum()
+            };
+            counts.insert(node, count);
+        }
+        counts.get(target).copied().unwrap_or(0)
+    }
+}
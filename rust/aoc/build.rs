@@ -0,0 +1,58 @@
+use std::{env, fs, path::Path};
+
+/// A day crate found in the `[dependencies]` table, e.g. `aoc-2024-day-06`.
+struct DayCrate {
+    year: u16,
+    day: u8,
+}
+
+impl DayCrate {
+    fn from_dependency_line(line: &str) -> Option<DayCrate> {
+        let (name, _) = line.split_once('=')?;
+        let rest = name.trim().strip_prefix("aoc-")?;
+        let (year, day) = rest.split_once("-day-")?;
+        Some(DayCrate {
+            year: year.parse().ok()?,
+            day: day.parse().ok()?,
+        })
+    }
+
+    fn crate_ident(&self) -> String {
+        format!("aoc_{}_day_{:02}", self.year, self.day)
+    }
+
+    fn solution_path(&self) -> String {
+        format!("{}::Day{:02}", self.crate_ident(), self.day)
+    }
+}
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("cargo sets CARGO_MANIFEST_DIR");
+    let manifest_path = Path::new(&manifest_dir).join("Cargo.toml");
+    println!("cargo::rerun-if-changed={}", manifest_path.display());
+    let manifest = fs::read_to_string(&manifest_path).expect("runner manifest should be readable");
+
+    let mut days: Vec<DayCrate> = manifest
+        .lines()
+        .skip_while(|line| line.trim() != "[dependencies]")
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .filter_map(DayCrate::from_dependency_line)
+        .collect();
+    days.sort_by_key(|day| (day.year, day.day));
+
+    let mut registry = String::from("pub static DAYS: &[Day] = &[\n");
+    for day in &days {
+        registry.push_str(&format!(
+            "    Day::of::<{}>(\"{}/rust/day-{:02}\"),\n",
+            day.solution_path(),
+            day.year,
+            day.day,
+        ));
+    }
+    registry.push_str("];\n");
+
+    let out_dir = env::var("OUT_DIR").expect("cargo sets OUT_DIR");
+    fs::write(Path::new(&out_dir).join("registry.rs"), registry)
+        .expect("should be able to write the generated registry");
+}
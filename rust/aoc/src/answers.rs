@@ -0,0 +1,117 @@
+use std::{collections::HashMap, fs};
+
+use crate::registry;
+
+pub const FILE_NAME: &str = "answers.txt";
+
+/// The known answers for each day's `input.txt`, as recorded in `answers.txt`
+/// at the workspace root.
+///
+/// One answer per line as `<year> <day> <part> <answer>`. Blank lines and
+/// lines starting with `#` are ignored. Answers that span several lines are
+/// written with `\n` in place of the line breaks (and `\\` for a backslash).
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u16, u8, u8), String>,
+}
+
+impl Answers {
+    pub fn load() -> Result<Answers, String> {
+        let path = registry::workspace_root().join(FILE_NAME);
+        let text = fs::read_to_string(&path)
+            .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+        Answers::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let mut answers = HashMap::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim_end();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fail = |reason: &str| format!("{FILE_NAME} line {}: {reason}", i + 1);
+            let mut fields = line.splitn(4, ' ');
+            let mut next = |name: &str| {
+                fields
+                    .next()
+                    .ok_or_else(|| fail(&format!("missing {name}")))
+            };
+            let year = next("year")?;
+            let day = next("day")?;
+            let part = next("part")?;
+            let answer = next("answer")?;
+            let key = (
+                year.parse().map_err(|_| fail("invalid year"))?,
+                day.parse().map_err(|_| fail("invalid day"))?,
+                part.parse().map_err(|_| fail("invalid part"))?,
+            );
+            if answers.insert(key, unescape(answer)).is_some() {
+                return Err(fail("answer is recorded twice"));
+            }
+        }
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+}
+
+fn unescape(answer: &str) -> String {
+    let mut result = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                result.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                result.push('\\');
+                chars.next();
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// The inverse of `unescape`, for showing an answer on one line.
+pub fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "# year day part answer\n2024 6 1 4826\n\n2024 06 2 1721\n2022 10 2 #..#\\n.##.\n2024 17 1 2,0,7\n",
+        )
+        .unwrap();
+        assert_eq!(answers.get(2024, 6, 1), Some("4826"));
+        assert_eq!(answers.get(2024, 6, 2), Some("1721"));
+        assert_eq!(answers.get(2022, 10, 2), Some("#..#\n.##."));
+        assert_eq!(answers.get(2024, 17, 1), Some("2,0,7"));
+        assert_eq!(answers.get(2024, 17, 2), None);
+        assert!(Answers::parse("2024 6 1 1\n2024 6 1 2\n").is_err());
+        assert!(Answers::parse("2024 6 x 1\n").is_err());
+        assert!(Answers::parse("2024 6 1\n").is_err());
+    }
+
+    #[test]
+    fn test_escape() {
+        for answer in ["plain", "#..#\n.##.", "back\\slash\nand line"] {
+            assert_eq!(unescape(&escape(answer)), answer);
+        }
+    }
+
+    #[test]
+    fn test_checked_in_answers() {
+        let answers = Answers::load().expect("answers.txt should parse");
+        assert_eq!(answers.get(2024, 6, 1), Some("4826"));
+    }
+}
@@ -0,0 +1,340 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    format_duration,
+    registry::{self, Day},
+};
+
+pub const FILE_VAR: &str = "AOC_BENCH_FILE";
+
+/// How much slower than the baseline, in percent, a part may get before it's
+/// flagged.
+pub const DEFAULT_THRESHOLD: u32 = 10;
+
+/// A part is run until it has this many samples or has used up
+/// [`TIME_BUDGET`], whichever comes first, and always at least once.
+const MAX_SAMPLES: u32 = 25;
+const TIME_BUDGET: Duration = Duration::from_secs(1);
+
+/// The median time one part took.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub nanos: u64,
+    pub samples: u32,
+}
+
+impl Timing {
+    fn key(&self) -> (u16, u8, u8) {
+        (self.year, self.day, self.part)
+    }
+}
+
+/// Everything measured at one commit.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Run {
+    /// seconds since the epoch when the run was last added to
+    pub recorded: u64,
+    pub timings: Vec<Timing>,
+}
+
+impl Run {
+    fn get(&self, key: (u16, u8, u8)) -> Option<&Timing> {
+        self.timings.iter().find(|timing| timing.key() == key)
+    }
+}
+
+/// The stored runs, keyed by commit.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    pub runs: BTreeMap<String, Run>,
+}
+
+impl History {
+    /// Where the history is kept: `AOC_BENCH_FILE`, or
+    /// `target/aoc-bench/benchmarks.json` at the workspace root.
+    pub fn default_path() -> PathBuf {
+        match env::var_os(FILE_VAR) {
+            Some(path) => PathBuf::from(path),
+            None => registry::workspace_root()
+                .join("target")
+                .join("aoc-bench")
+                .join("benchmarks.json"),
+        }
+    }
+
+    /// The history in `path`, or an empty one if nothing's been stored yet.
+    pub fn load(path: &Path) -> Result<History, String> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|e| format!("could not read {}: {e}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(format!("could not read {}: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+        }
+        fs::write(path, json + "\n").map_err(|e| format!("could not write {}: {e}", path.display()))
+    }
+
+    /// Adds `timings` to the run for `commit`, replacing any earlier timings
+    /// of the same parts.
+    pub fn record(&mut self, commit: &str, recorded: u64, timings: &[Timing]) {
+        let run = self.runs.entry(commit.to_string()).or_default();
+        run.recorded = recorded;
+        run.timings
+            .retain(|old| !timings.iter().any(|new| new.key() == old.key()));
+        run.timings.extend_from_slice(timings);
+        run.timings.sort_by_key(Timing::key);
+    }
+
+    /// The run to compare `commit` against: the one for `requested`, which
+    /// may be a prefix of the commit, or else the most recent run at any
+    /// other commit.
+    pub fn baseline(
+        &self,
+        commit: &str,
+        requested: Option<&str>,
+    ) -> Result<Option<(&str, &Run)>, String> {
+        if let Some(requested) = requested {
+            let mut matching = self
+                .runs
+                .iter()
+                .filter(|(key, _)| key.starts_with(requested));
+            return match (matching.next(), matching.next()) {
+                (Some((key, run)), None) => Ok(Some((key.as_str(), run))),
+                (None, _) => Err(format!("no stored run for {requested}")),
+                (Some(_), Some(_)) => Err(format!("{requested} matches more than one stored run")),
+            };
+        }
+        Ok(self
+            .runs
+            .iter()
+            .filter(|(key, _)| key.as_str() != commit)
+            .max_by_key(|(_, run)| run.recorded)
+            .map(|(key, run)| (key.as_str(), run)))
+    }
+}
+
+/// The checked-out commit, marked dirty if tracked files have changed.
+pub fn current_commit(root: &Path) -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(root)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let Some(commit) = git(&["rev-parse", "--short=12", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if changes.is_empty() => commit,
+        _ => format!("{commit}-dirty"),
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+fn time_part(solve: impl Fn() -> bool) -> Option<(Duration, u32)> {
+    let mut samples = vec![];
+    let start = Instant::now();
+    while samples.len() < MAX_SAMPLES as usize
+        && (samples.is_empty() || start.elapsed() < TIME_BUDGET)
+    {
+        let sample = Instant::now();
+        if !solve() {
+            return None;
+        }
+        samples.push(sample.elapsed());
+    }
+    samples.sort_unstable();
+    Some((samples[samples.len() / 2], samples.len() as u32))
+}
+
+/// Times every selected part against its input, printing a note for each
+/// day without an input and each part that fails or panics instead of timing
+/// it.
+pub fn measure(selection: Vec<(&'static Day, Vec<u8>)>) -> Vec<Timing> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut timings = vec![];
+    for (day, parts) in selection {
+        let path = day.input_path();
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                println!(
+                    "{} day {:02}: could not read {}: {e}",
+                    day.year,
+                    day.day,
+                    path.display()
+                );
+                continue;
+            }
+        };
+        for part in parts {
+            let solve = day.part(part).expect("parts are validated while parsing");
+            let solves = || {
+                panic::catch_unwind(AssertUnwindSafe(|| solve(&input)))
+                    .is_ok_and(|result| result.is_ok())
+            };
+            match time_part(solves) {
+                Some((median, samples)) => timings.push(Timing {
+                    year: day.year,
+                    day: day.day,
+                    part,
+                    nanos: u64::try_from(median.as_nanos()).unwrap_or(u64::MAX),
+                    samples,
+                }),
+                None => println!(
+                    "{} day {:02} part {part} failed, so it isn't timed",
+                    day.year, day.day
+                ),
+            }
+        }
+    }
+
+    panic::set_hook(hook);
+    timings
+}
+
+/// A timing next to the baseline's timing of the same part.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub timing: Timing,
+    pub baseline: Option<u64>,
+    /// how much slower than the baseline, in percent
+    pub change: Option<f64>,
+    pub slower: bool,
+}
+
+pub fn compare(timings: &[Timing], baseline: Option<&Run>, threshold: u32) -> Vec<Row> {
+    timings
+        .iter()
+        .map(|timing| {
+            let baseline = baseline
+                .and_then(|run| run.get(timing.key()))
+                .map(|old| old.nanos);
+            let change = baseline
+                .filter(|&old| old > 0)
+                .map(|old| (timing.nanos as f64 - old as f64) / old as f64 * 100.0);
+            Row {
+                timing: timing.clone(),
+                baseline,
+                change,
+                slower: change.is_some_and(|change| change > f64::from(threshold)),
+            }
+        })
+        .collect()
+}
+
+/// The rows as a table per year, then how many parts got slower.
+pub fn print_table(rows: &[Row], commit: &str, baseline: Option<&str>, threshold: u32) {
+    match baseline {
+        Some(baseline) => {
+            println!("{commit} against {baseline}, flagging anything over {threshold}% slower")
+        }
+        None => println!("{commit}, with no earlier run to compare against"),
+    }
+    let mut years: Vec<u16> = rows.iter().map(|row| row.timing.year).collect();
+    years.dedup();
+    for year in years {
+        println!("\n{year}");
+        println!(
+            "{:>6} {:>4} {:>10} {:>10} {:>8}",
+            "day", "part", "time", "baseline", "change"
+        );
+        for row in rows.iter().filter(|row| row.timing.year == year) {
+            let nanos = |nanos: u64| format_duration(Duration::from_nanos(nanos));
+            println!(
+                "{:>6} {:>4} {:>10} {:>10} {:>8}{}",
+                format!("{:02}", row.timing.day),
+                row.timing.part,
+                nanos(row.timing.nanos),
+                row.baseline.map(nanos).unwrap_or_default(),
+                row.change
+                    .map(|change| format!("{change:+.0}%"))
+                    .unwrap_or_default(),
+                if row.slower { "  SLOWER" } else { "" }
+            );
+        }
+    }
+    let slower = rows.iter().filter(|row| row.slower).count();
+    println!("\n{} parts timed, {slower} slower", rows.len());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::temp_dir;
+
+    fn timing(day: u8, part: u8, nanos: u64) -> Timing {
+        Timing {
+            year: 2024,
+            day,
+            part,
+            nanos,
+            samples: 1,
+        }
+    }
+
+    #[test]
+    fn test_history() {
+        let mut history = History::default();
+        history.record("aaa", 10, &[timing(6, 1, 100), timing(6, 2, 1000)]);
+        history.record("bbb", 20, &[timing(6, 2, 1200), timing(7, 1, 50)]);
+        history.record("aaa", 30, &[timing(6, 1, 90)]);
+        assert_eq!(
+            history.runs["aaa"].timings,
+            [timing(6, 1, 90), timing(6, 2, 1000)]
+        );
+
+        // the latest other commit, unless one is asked for
+        assert_eq!(history.baseline("ccc", None).unwrap().unwrap().0, "aaa");
+        assert_eq!(history.baseline("aaa", None).unwrap().unwrap().0, "bbb");
+        assert_eq!(
+            history.baseline("aaa", Some("b")).unwrap().unwrap().0,
+            "bbb"
+        );
+        assert!(history.baseline("aaa", Some("c")).is_err());
+        assert!(History::default().baseline("aaa", None).unwrap().is_none());
+
+        let rows = compare(&history.runs["bbb"].timings, Some(&history.runs["aaa"]), 10);
+        assert_eq!(rows[0].change, Some(20.0));
+        assert!(rows[0].slower);
+        assert_eq!((rows[1].baseline, rows[1].slower), (None, false));
+        assert!(!compare(&[timing(6, 2, 1200)], Some(&history.runs["aaa"]), 25)[0].slower);
+
+        let path = temp_dir("bench").join("benchmarks.json");
+        history.save(&path).unwrap();
+        assert_eq!(History::load(&path).unwrap(), history);
+        assert_eq!(
+            History::load(&path.with_extension("missing")).unwrap(),
+            History::default()
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::{registry, submissions::Verdict};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const CACHE_VAR: &str = "AOC_CACHE_DIR";
+
+/// Where puzzle inputs come from and where answers go: adventofcode.com, or a
+/// stand-in for it in tests.
+pub trait Fetcher {
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String, String>;
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict, String>;
+}
+
+/// Talks to adventofcode.com through `curl`, logged in with the session
+/// cookie from `AOC_SESSION`.
+pub struct HttpFetcher {
+    session: Option<String>,
+}
+
+impl HttpFetcher {
+    /// Doesn't fail without a session, so cached inputs can still be used;
+    /// requests will fail instead.
+    pub fn from_env() -> HttpFetcher {
+        HttpFetcher {
+            session: env::var(SESSION_VAR)
+                .ok()
+                .map(|session| session.trim().to_string())
+                .filter(|session| !session.is_empty()),
+        }
+    }
+
+    fn curl(&self, url: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let session = self.session.as_ref().ok_or_else(|| {
+            format!("set {SESSION_VAR} to your adventofcode.com session cookie to reach the site")
+        })?;
+        let mut command = Command::new("curl");
+        command
+            .args(["--silent", "--show-error", "--fail"])
+            .args(["--user-agent", "aoc sketchbook runner (curl)"])
+            .args(["--cookie", &format!("session={session}")]);
+        for (key, value) in form {
+            command.args(["--data-urlencode", &format!("{key}={value}")]);
+        }
+        let output = command
+            .arg(url)
+            .output()
+            .map_err(|e| format!("could not run curl: {e}"))?;
+        if !output.status.success() {
+            return Err(format!(
+                "request to {url} failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        String::from_utf8(output.stdout).map_err(|_| format!("{url} did not return text"))
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch_input(&self, year: u16, day: u8) -> Result<String, String> {
+        self.curl(
+            &format!("https://adventofcode.com/{year}/day/{day}/input"),
+            &[],
+        )
+    }
+
+    fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict, String> {
+        let page = self.curl(
+            &format!("https://adventofcode.com/{year}/day/{day}/answer"),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+        Verdict::from_response(&page)
+    }
+}
+
+/// Inputs that have already been downloaded, stored as
+/// `<cache dir>/<year>/day-<dd>.txt`.
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> InputCache {
+        InputCache { dir: dir.into() }
+    }
+
+    /// The cache in `AOC_CACHE_DIR`, or `.aoc-cache` at the workspace root.
+    pub fn from_env() -> InputCache {
+        match env::var_os(CACHE_VAR) {
+            Some(dir) => InputCache::new(dir),
+            None => InputCache::new(registry::workspace_root().join(".aoc-cache")),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day-{day:02}.txt"))
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<String> {
+        fs::read_to_string(self.path(year, day)).ok()
+    }
+
+    /// The cached input, fetching and caching it first if it isn't there yet.
+    pub fn get_or_fetch(
+        &self,
+        year: u16,
+        day: u8,
+        fetcher: &dyn Fetcher,
+    ) -> Result<String, String> {
+        if let Some(input) = self.get(year, day) {
+            return Ok(input);
+        }
+        let input = fetcher.fetch_input(year, day)?;
+        let path = self.path(year, day);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("could not create {}: {e}", parent.display()))?;
+        }
+        fs::write(&path, &input).map_err(|e| format!("could not write {}: {e}", path.display()))?;
+        Ok(input)
+    }
+}
+
+/// Puts the input for a day into its crate's `input.txt`, going through the
+/// cache. An `input.txt` that already has something in it is left alone.
+pub fn install_input(
+    cache: &InputCache,
+    fetcher: &dyn Fetcher,
+    year: u16,
+    day: u8,
+) -> Result<PathBuf, String> {
+    let crate_dir = registry::workspace_root().join(format!("{year}/rust/day-{day:02}"));
+    if !crate_dir.is_dir() {
+        return Err(format!(
+            "{} doesn't exist, create it with `aoc new-day {year} {day}` first",
+            crate_dir.display()
+        ));
+    }
+    let path = crate_dir.join("input.txt");
+    if fs::metadata(&path).is_ok_and(|meta| meta.len() > 0) {
+        return Err(format!("{} already has an input", path.display()));
+    }
+    let input = cache.get_or_fetch(year, day, fetcher)?;
+    fs::write(&path, input).map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    Ok(path)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use std::{cell::Cell, collections::HashMap};
+
+    /// Serves inputs from memory and judges answers against a fixed list.
+    pub struct StubFetcher {
+        pub inputs: HashMap<(u16, u8), String>,
+        pub answers: HashMap<(u16, u8, u8), i64>,
+        pub requests: Cell<usize>,
+    }
+
+    impl Fetcher for StubFetcher {
+        fn fetch_input(&self, year: u16, day: u8) -> Result<String, String> {
+            self.requests.set(self.requests.get() + 1);
+            self.inputs
+                .get(&(year, day))
+                .cloned()
+                .ok_or_else(|| format!("no input for {year} day {day}"))
+        }
+
+        fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict, String> {
+            self.requests.set(self.requests.get() + 1);
+            let expected = self.answers[&(year, day, part)];
+            Ok(match answer.parse::<i64>() {
+                Ok(guess) if guess == expected => Verdict::Correct,
+                Ok(guess) if guess > expected => Verdict::TooHigh,
+                Ok(_) => Verdict::TooLow,
+                Err(_) => Verdict::Wrong,
+            })
+        }
+    }
+
+    pub fn stub() -> StubFetcher {
+        StubFetcher {
+            inputs: HashMap::from([((2024, 1), "3   4\n4   3\n".to_string())]),
+            answers: HashMap::from([((2024, 1, 1), 11)]),
+            requests: Cell::new(0),
+        }
+    }
+
+    pub fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = temp_dir("cache");
+        let cache = InputCache::new(&dir);
+        let fetcher = stub();
+        assert_eq!(cache.get(2024, 1), None);
+        assert_eq!(
+            cache.get_or_fetch(2024, 1, &fetcher).unwrap(),
+            "3   4\n4   3\n"
+        );
+        assert_eq!(
+            cache.get_or_fetch(2024, 1, &fetcher).unwrap(),
+            "3   4\n4   3\n"
+        );
+        assert_eq!(fetcher.requests.get(), 1);
+        assert!(cache.path(2024, 1).ends_with("2024/day-01.txt"));
+        assert!(cache.get_or_fetch(2024, 2, &fetcher).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
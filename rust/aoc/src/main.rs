@@ -0,0 +1,606 @@
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    process::ExitCode,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+mod answers;
+mod bench;
+mod fetch;
+mod registry;
+mod scaffold;
+mod submissions;
+mod verify;
+
+use aoc_core::Answer;
+use registry::Day;
+
+const USAGE: &str = "usage:
+    aoc run <year> <day> [<part>]   run one day, or a single part of it
+    aoc run <year> [--all]          run every day of a year
+    aoc run --all                   run every day of every year
+    aoc run ... --parallel          run the selected days side by side
+    aoc list                        list the registered days
+    aoc verify [<year> [<day>]]     check answers against answers.txt
+    aoc bench [<year> [<day>]] [--threshold <percent>] [--baseline <commit>]
+                                    time the parts, store the times under the
+                                    current commit and flag any that slowed down
+    aoc new-day <year> <day>        create and register a crate for a new day
+    aoc fetch <year> <day>          download a day's input into its input.txt
+    aoc submit <year> <day> <part> <answer>
+                                    submit an answer, unless earlier guesses rule it out";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Selection {
+    Everything,
+    Year(u16),
+    Day {
+        year: u16,
+        day: u8,
+        part: Option<u8>,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Run {
+        selection: Selection,
+        concurrent: bool,
+    },
+    List,
+    Verify(Selection),
+    Bench {
+        selection: Selection,
+        /// percent slower than the baseline that counts as a slowdown
+        threshold: u32,
+        baseline: Option<String>,
+    },
+    NewDay {
+        year: u16,
+        day: u8,
+    },
+    Fetch {
+        year: u16,
+        day: u8,
+    },
+    Submit {
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: String,
+    },
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args: Vec<&str> = args.iter().map(|arg| arg.as_str()).collect();
+    if args.first() == Some(&"run") && args.contains(&"--parallel") {
+        args.retain(|&arg| arg != "--parallel");
+        return match parse_args_in(&args)? {
+            Command::Run { selection, .. } => Ok(Command::Run {
+                selection,
+                concurrent: true,
+            }),
+            _ => unreachable!("run arguments always parse to a run"),
+        };
+    }
+    if args.first() == Some(&"bench") {
+        return parse_bench_args(&args[1..]);
+    }
+    parse_args_in(&args)
+}
+
+fn parse_bench_args(args: &[&str]) -> Result<Command, String> {
+    let mut threshold = bench::DEFAULT_THRESHOLD;
+    let mut baseline = None;
+    let mut rest = vec![];
+    let mut args = args.iter();
+    while let Some(&arg) = args.next() {
+        match arg {
+            "--threshold" => {
+                let value = args.next().ok_or(USAGE)?;
+                threshold = value
+                    .parse()
+                    .map_err(|_| format!("invalid threshold: {value}"))?;
+            }
+            "--baseline" => baseline = Some(args.next().ok_or(USAGE)?.to_string()),
+            _ => rest.push(arg),
+        }
+    }
+    let selection = match rest.as_slice() {
+        [] => Selection::Everything,
+        [year] => Selection::Year(parse_year(year)?),
+        [year, day] => Selection::Day {
+            year: parse_year(year)?,
+            day: parse_day(day)?,
+            part: None,
+        },
+        _ => return Err(USAGE.to_string()),
+    };
+    Ok(Command::Bench {
+        selection,
+        threshold,
+        baseline,
+    })
+}
+
+fn parse_args_in(args: &[&str]) -> Result<Command, String> {
+    match args {
+        ["list"] => Ok(Command::List),
+        ["verify"] => Ok(Command::Verify(Selection::Everything)),
+        ["verify", year] => Ok(Command::Verify(Selection::Year(parse_year(year)?))),
+        ["verify", year, day] => Ok(Command::Verify(Selection::Day {
+            year: parse_year(year)?,
+            day: parse_day(day)?,
+            part: None,
+        })),
+        ["new-day", year, day] => Ok(Command::NewDay {
+            year: parse_year(year)?,
+            day: parse_day(day)?,
+        }),
+        ["fetch", year, day] => Ok(Command::Fetch {
+            year: parse_year(year)?,
+            day: parse_day(day)?,
+        }),
+        ["submit", year, day, part, answer] => Ok(Command::Submit {
+            year: parse_year(year)?,
+            day: parse_day(day)?,
+            part: parse_part(part)?,
+            answer: answer.to_string(),
+        }),
+        ["run", "--all"] => Ok(run_of(Selection::Everything)),
+        ["run", year] | ["run", year, "--all"] => Ok(run_of(Selection::Year(parse_year(year)?))),
+        ["run", year, day] => Ok(run_of(Selection::Day {
+            year: parse_year(year)?,
+            day: parse_day(day)?,
+            part: None,
+        })),
+        ["run", year, day, part] => Ok(run_of(Selection::Day {
+            year: parse_year(year)?,
+            day: parse_day(day)?,
+            part: Some(parse_part(part)?),
+        })),
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+/// A run of `selection`, one day at a time.
+fn run_of(selection: Selection) -> Command {
+    Command::Run {
+        selection,
+        concurrent: false,
+    }
+}
+
+fn parse_year(input: &str) -> Result<u16, String> {
+    input.parse().map_err(|_| format!("invalid year: {input}"))
+}
+
+fn parse_day(input: &str) -> Result<u8, String> {
+    match input.parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day: {input}")),
+    }
+}
+
+fn parse_part(input: &str) -> Result<u8, String> {
+    match input.parse() {
+        Ok(part @ 1..=2) => Ok(part),
+        _ => Err(format!("invalid part: {input}")),
+    }
+}
+
+fn select(selection: &Selection) -> Result<Vec<(&'static Day, Vec<u8>)>, String> {
+    match *selection {
+        Selection::Everything => Ok(registry::DAYS.iter().map(|day| (day, vec![1, 2])).collect()),
+        Selection::Year(year) => {
+            let days: Vec<_> = registry::days_in(year)
+                .map(|day| (day, vec![1, 2]))
+                .collect();
+            if days.is_empty() {
+                return Err(format!("no days registered for {year}"));
+            }
+            Ok(days)
+        }
+        Selection::Day { year, day, part } => {
+            let found = registry::find(year, day)
+                .ok_or_else(|| format!("{year} day {day:02} is not registered"))?;
+            let parts = part.map_or(vec![1, 2], |part| vec![part]);
+            Ok(vec![(found, parts)])
+        }
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_micros();
+    if micros < 1_000 {
+        format!("{micros}µs")
+    } else if micros < 1_000_000 {
+        format!("{:.2}ms", micros as f64 / 1_000.0)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+fn format_answer(day: &Day, part: u8, answer: &Answer, elapsed: Duration) -> String {
+    let answer = answer.to_string();
+    let label = format!("{} day {:02} part {part}", day.year, day.day);
+    if answer.contains('\n') {
+        format!("{label} ({}):\n{answer}", format_duration(elapsed))
+    } else {
+        format!("{label}: {answer} ({})", format_duration(elapsed))
+    }
+}
+
+/// What running one day printed, how long its parts took, whether it had an
+/// input to run against, and how many of its parts failed or panicked.
+struct DayRun {
+    lines: Vec<String>,
+    elapsed: Duration,
+    ran: bool,
+    failed: usize,
+}
+
+fn run_day(day: &Day, parts: &[u8]) -> DayRun {
+    let path = day.input_path();
+    let input = match fs::read_to_string(&path) {
+        Ok(input) => input,
+        Err(e) => {
+            return DayRun {
+                lines: vec![format!(
+                    "{} day {:02}: could not read {}: {e}",
+                    day.year,
+                    day.day,
+                    path.display()
+                )],
+                elapsed: Duration::ZERO,
+                ran: false,
+                failed: 0,
+            }
+        }
+    };
+    let mut lines = vec![];
+    let mut elapsed = Duration::ZERO;
+    let mut failed = 0;
+    for &part in parts {
+        let solve = day.part(part).expect("parts are validated while parsing");
+        let start = Instant::now();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| solve(&input)));
+        let part_elapsed = start.elapsed();
+        elapsed += part_elapsed;
+        let failure = match result {
+            Ok(Ok(answer)) => {
+                lines.push(format_answer(day, part, &answer, part_elapsed));
+                continue;
+            }
+            Ok(Err(e)) => e.to_string(),
+            Err(payload) => format!("panicked: {}", verify::panic_message(payload.as_ref())),
+        };
+        failed += 1;
+        lines.push(format!(
+            "{} day {:02} part {part} failed: {failure}",
+            day.year, day.day
+        ));
+    }
+    DayRun {
+        lines,
+        elapsed,
+        ran: true,
+        failed,
+    }
+}
+
+/// Runs every day on a pool of one thread per core, handing each thread the
+/// next day not yet started, and returns the runs in the order given.
+fn run_days_concurrently(selection: &[(&'static Day, Vec<u8>)]) -> Vec<DayRun> {
+    let next = AtomicUsize::new(0);
+    let runs: Mutex<Vec<Option<DayRun>>> = Mutex::new(selection.iter().map(|_| None).collect());
+    let workers = thread::available_parallelism().map_or(1, |n| n.get());
+    thread::scope(|scope| {
+        for _ in 0..workers.min(selection.len()) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some((day, parts)) = selection.get(i) else {
+                    break;
+                };
+                let day_run = run_day(day, parts);
+                runs.lock().expect("no worker panics holding the lock")[i] = Some(day_run);
+            });
+        }
+    });
+    runs.into_inner()
+        .expect("no worker panics holding the lock")
+        .into_iter()
+        .map(|day_run| day_run.expect("every day is run"))
+        .collect()
+}
+
+/// Runs the selected parts, either a day at a time or several days at once,
+/// and returns whether every day had an input to run against and every part
+/// came up with an answer. Output comes out in the same order either way.
+fn run(selection: Vec<(&'static Day, Vec<u8>)>, concurrent: bool) -> bool {
+    let start = Instant::now();
+    let mut all_ran = true;
+    let mut failed = 0;
+    let mut total = Duration::ZERO;
+    let mut report = |day_run: DayRun| {
+        for line in day_run.lines {
+            println!("{line}");
+        }
+        total += day_run.elapsed;
+        all_ran &= day_run.ran;
+        failed += day_run.failed;
+    };
+    if concurrent {
+        run_days_concurrently(&selection)
+            .into_iter()
+            .for_each(&mut report);
+        println!(
+            "total: {} ({} wall clock)",
+            format_duration(total),
+            format_duration(start.elapsed())
+        );
+    } else {
+        for (day, parts) in &selection {
+            report(run_day(day, parts));
+        }
+        println!("total: {}", format_duration(total));
+    }
+    all_ran && failed == 0
+}
+
+/// Times the selected parts, stores the times and prints them against the
+/// baseline, returning whether nothing got slower.
+fn bench(
+    selection: Vec<(&'static Day, Vec<u8>)>,
+    threshold: u32,
+    baseline: Option<&str>,
+) -> Result<bool, String> {
+    let path = bench::History::default_path();
+    let mut history = bench::History::load(&path)?;
+    let commit = bench::current_commit(&registry::workspace_root());
+    let base = history
+        .baseline(&commit, baseline)?
+        .map(|(commit, run)| (commit.to_string(), run.clone()));
+
+    let timings = bench::measure(selection);
+    let rows = bench::compare(&timings, base.as_ref().map(|(_, run)| run), threshold);
+    bench::print_table(
+        &rows,
+        &commit,
+        base.as_ref().map(|(commit, _)| commit.as_str()),
+        threshold,
+    );
+    history.record(&commit, bench::now(), &timings);
+    history.save(&path)?;
+    Ok(rows.iter().all(|row| !row.slower))
+}
+
+fn list() {
+    for year in registry::years() {
+        let days: Vec<String> = registry::days_in(year)
+            .map(|day| day.day.to_string())
+            .collect();
+        println!("{year}: {}", days.join(" "));
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match command {
+        Command::List => {
+            list();
+            ExitCode::SUCCESS
+        }
+        Command::Verify(selection) => {
+            let checks = match answers::Answers::load()
+                .and_then(|answers| Ok(verify::verify(select(&selection)?, &answers)))
+            {
+                Ok(checks) => checks,
+                Err(e) => {
+                    eprintln!("{e}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            verify::print_table(&checks);
+            if verify::passed(&checks) {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Command::Bench {
+            selection,
+            threshold,
+            baseline,
+        } => match select(&selection)
+            .and_then(|selected| bench(selected, threshold, baseline.as_deref()))
+        {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Fetch { year, day } => {
+            let cache = fetch::InputCache::from_env();
+            match fetch::install_input(&cache, &fetch::HttpFetcher::from_env(), year, day) {
+                Ok(path) => {
+                    println!("wrote {}", path.display());
+                    ExitCode::SUCCESS
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        } => {
+            let cache = fetch::InputCache::from_env();
+            let verdict = submissions::SubmissionLog::open(cache.dir().join("submissions.txt"))
+                .and_then(|mut log| {
+                    log.submit(&fetch::HttpFetcher::from_env(), year, day, part, &answer)
+                });
+            match verdict {
+                Ok(verdict) => {
+                    println!("{year} day {day:02} part {part}: {answer} is {verdict}");
+                    if verdict == submissions::Verdict::Correct {
+                        ExitCode::SUCCESS
+                    } else {
+                        ExitCode::FAILURE
+                    }
+                }
+                Err(e) => {
+                    eprintln!("{e}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::NewDay { year, day } => match scaffold::new_day(year, day) {
+            Ok(dir) => {
+                println!("created {dir}");
+                ExitCode::SUCCESS
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Run {
+            selection,
+            concurrent,
+        } => match select(&selection) {
+            Ok(selected) => {
+                if run(selected, concurrent) {
+                    ExitCode::SUCCESS
+                } else {
+                    ExitCode::FAILURE
+                }
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(input: &str) -> Vec<String> {
+        input.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(&args("run 2024 6 2")),
+            Ok(run_of(Selection::Day {
+                year: 2024,
+                day: 6,
+                part: Some(2)
+            }))
+        );
+        assert_eq!(
+            parse_args(&args("run 2024 --all")),
+            Ok(run_of(Selection::Year(2024)))
+        );
+        assert_eq!(
+            parse_args(&args("run --all")),
+            Ok(run_of(Selection::Everything))
+        );
+        assert_eq!(
+            parse_args(&args("run --all --parallel")),
+            Ok(Command::Run {
+                selection: Selection::Everything,
+                concurrent: true
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run --parallel 2024")),
+            Ok(Command::Run {
+                selection: Selection::Year(2024),
+                concurrent: true
+            })
+        );
+        assert_eq!(parse_args(&args("list")), Ok(Command::List));
+        assert_eq!(
+            parse_args(&args("submit 2024 6 2 1721")),
+            Ok(Command::Submit {
+                year: 2024,
+                day: 6,
+                part: 2,
+                answer: "1721".to_string()
+            })
+        );
+        assert_eq!(
+            parse_args(&args("verify 2024")),
+            Ok(Command::Verify(Selection::Year(2024)))
+        );
+        assert_eq!(
+            parse_args(&args("new-day 2025 11")),
+            Ok(Command::NewDay {
+                year: 2025,
+                day: 11
+            })
+        );
+        assert!(parse_args(&args("run 2024 26")).is_err());
+        assert!(parse_args(&args("run 2024 6 3")).is_err());
+        assert_eq!(
+            parse_args(&args("bench 2024 --threshold 25 --baseline 9bf24de")),
+            Ok(Command::Bench {
+                selection: Selection::Year(2024),
+                threshold: 25,
+                baseline: Some("9bf24de".to_string())
+            })
+        );
+        assert_eq!(
+            parse_args(&args("bench")),
+            Ok(Command::Bench {
+                selection: Selection::Everything,
+                threshold: bench::DEFAULT_THRESHOLD,
+                baseline: None
+            })
+        );
+        assert!(parse_args(&args("bench --threshold")).is_err());
+        assert!(parse_args(&args("bench 2024 --threshold fast")).is_err());
+        assert!(parse_args(&args("run")).is_err());
+        assert!(parse_args(&args("run --parallel")).is_err());
+        assert!(parse_args(&args("verify --parallel")).is_err());
+        // one command per line of the help
+        assert!(USAGE.lines().all(|line| line.matches("aoc ").count() <= 1));
+    }
+
+    #[test]
+    fn test_select() {
+        let selected = select(&Selection::Day {
+            year: 2024,
+            day: 6,
+            part: None,
+        })
+        .unwrap();
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].1, vec![1, 2]);
+        assert!(select(&Selection::Year(1999)).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use aoc_core::{Answer, AocError, Solution};
+
+/// Solves one part from the full puzzle input.
+pub type Solve = fn(&str) -> Result<Answer, AocError>;
+
+/// One solved day, as listed in the runner's `[dependencies]`.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    /// crate directory relative to the workspace root
+    pub dir: &'static str,
+    pub part1: Solve,
+    pub part2: Solve,
+}
+
+impl Day {
+    pub const fn of<S: Solution>(dir: &'static str) -> Day {
+        Day {
+            year: S::YEAR,
+            day: S::DAY,
+            dir,
+            part1: S::solve_part1,
+            part2: S::solve_part2,
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Option<Solve> {
+        match part {
+            1 => Some(self.part1),
+            2 => Some(self.part2),
+            _ => None,
+        }
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        workspace_root().join(self.dir).join("input.txt")
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../.."))
+}
+
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = DAYS.iter().map(|day| day.year).collect();
+    years.dedup();
+    years
+}
+
+pub fn days_in(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |day| day.year == year)
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        assert!(DAYS
+            .windows(2)
+            .all(|pair| (pair[0].year, pair[0].day) < (pair[1].year, pair[1].day)));
+        assert!(DAYS
+            .iter()
+            .all(|day| day.dir == format!("{}/rust/day-{:02}", day.year, day.day)));
+        assert_eq!(years().first(), Some(&2015));
+        let day = find(2024, 6).expect("2024 day 6 should be registered");
+        assert_eq!(day.dir, "2024/rust/day-06");
+        assert!(day.input_path().ends_with("2024/rust/day-06/input.txt"));
+        assert!(find(2024, 0).is_none());
+    }
+}
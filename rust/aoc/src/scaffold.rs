@@ -0,0 +1,168 @@
+use std::{fs, path::Path};
+
+use crate::registry;
+
+// the crate manifest is kept as `Cargo.toml.template` so cargo doesn't try to
+// load it as a package of its own
+const TEMPLATES: &[(&str, &str)] = &[
+    (
+        "Cargo.toml",
+        include_str!("../templates/Cargo.toml.template"),
+    ),
+    ("src/lib.rs", include_str!("../templates/lib.rs")),
+    ("src/bin/part-1.rs", include_str!("../templates/part-1.rs")),
+    ("src/bin/part-2.rs", include_str!("../templates/part-2.rs")),
+    (
+        "benches/benchmarks.rs",
+        include_str!("../templates/benchmarks.rs"),
+    ),
+    ("input.txt", ""),
+    ("test-input-1.txt", ""),
+];
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{dd}}", &format!("{day:02}"))
+        .replace("{{day}}", &day.to_string())
+}
+
+fn day_dir(year: u16, day: u8) -> String {
+    format!("{year}/rust/day-{day:02}")
+}
+
+/// Adds `member` to the workspace `members`, keeping the days in order.
+fn insert_member(manifest: &str, member: &str) -> Result<String, String> {
+    let entry = format!("    \"{member}\",");
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    if lines.contains(&entry.as_str()) {
+        return Err(format!("{member} is already a workspace member"));
+    }
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "members = [")
+        .ok_or("workspace manifest has no `members = [` list")?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == "]")
+            .ok_or("workspace `members` list is never closed")?;
+    let at = (start + 1..end)
+        .find(|&i| lines[i].starts_with("    \"20") && lines[i] > entry.as_str())
+        .unwrap_or(end);
+    lines.insert(at, &entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the day to the runner's `[dependencies]`, keeping the days in order.
+fn insert_dependency(manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let name = format!("aoc-{year}-day-{day:02}");
+    let entry = format!("{name} = {{ path = \"../../{}\" }}", day_dir(year, day));
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    if lines
+        .iter()
+        .any(|line| line.starts_with(&format!("{name} ")))
+    {
+        return Err(format!("{name} is already a runner dependency"));
+    }
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "[dependencies]")
+        .ok_or("runner manifest has no [dependencies]")?;
+    let end = (start + 1..lines.len())
+        .find(|&i| lines[i].starts_with('['))
+        .unwrap_or(lines.len());
+    let days: Vec<usize> = (start + 1..end)
+        .filter(|&i| lines[i].starts_with("aoc-20"))
+        .collect();
+    let at = days
+        .iter()
+        .copied()
+        .find(|&i| lines[i] > entry.as_str())
+        .or_else(|| days.last().map(|&i| i + 1))
+        .unwrap_or(end);
+    lines.insert(at, &entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates `<year>/rust/day-<dd>` from the templates and registers it with
+/// the workspace and the runner. Returns the new crate's directory.
+pub fn new_day(year: u16, day: u8) -> Result<String, String> {
+    let root = registry::workspace_root();
+    let dir = day_dir(year, day);
+    let crate_dir = root.join(&dir);
+    if crate_dir.exists() {
+        return Err(format!("{dir} already exists"));
+    }
+
+    let workspace_manifest = root.join("Cargo.toml");
+    let runner_manifest = root.join("rust/aoc/Cargo.toml");
+    let workspace = insert_member(&read(&workspace_manifest)?, &dir)?;
+    let runner = insert_dependency(&read(&runner_manifest)?, year, day)?;
+
+    for (path, template) in TEMPLATES {
+        let path = crate_dir.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("could not create {}: {e}", parent.display()))?;
+        }
+        write(&path, &fill(template, year, day))?;
+    }
+    write(&workspace_manifest, &workspace)?;
+    write(&runner_manifest, &runner)?;
+    Ok(dir)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("could not write {}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill() {
+        let manifest = fill(TEMPLATES[0].1, 2026, 3);
+        assert!(manifest.contains("name = \"aoc-2026-day-03\""));
+        assert!(manifest.contains("name = \"2026-day-03-part-1\""));
+        assert!(manifest.contains("name = \"day-03-bench\""));
+        let lib = fill(TEMPLATES[1].1, 2026, 3);
+        assert!(lib.contains("pub struct Day03;"));
+        assert!(lib.contains("const DAY: u8 = 3;"));
+        assert!(fill(TEMPLATES[2].1, 2026, 3).contains("use aoc_2026_day_03::process_part1;"));
+        assert!(TEMPLATES
+            .iter()
+            .all(|(_, template)| !fill(template, 2026, 3).contains("{{")));
+    }
+
+    #[test]
+    fn test_insert_member() {
+        let manifest = "[workspace]\nmembers = [\n    \"rust/aoc\",\n    \"2024/rust/day-01\",\n    \"2024/rust/day-03\",\n]\nexclude = []\n";
+        assert_eq!(
+            insert_member(manifest, "2024/rust/day-02").unwrap(),
+            "[workspace]\nmembers = [\n    \"rust/aoc\",\n    \"2024/rust/day-01\",\n    \"2024/rust/day-02\",\n    \"2024/rust/day-03\",\n]\nexclude = []\n"
+        );
+        assert!(insert_member(manifest, "2025/rust/day-01")
+            .unwrap()
+            .contains("    \"2024/rust/day-03\",\n    \"2025/rust/day-01\",\n]"));
+        assert!(insert_member(manifest, "2024/rust/day-03").is_err());
+    }
+
+    #[test]
+    fn test_insert_dependency() {
+        let manifest = "[dependencies]\naoc-core = { path = \"../aoc-core\" }\naoc-2024-day-01 = { path = \"../../2024/rust/day-01\" }\n\n[build-dependencies]\n";
+        assert_eq!(
+            insert_dependency(manifest, 2024, 2).unwrap(),
+            "[dependencies]\naoc-core = { path = \"../aoc-core\" }\naoc-2024-day-01 = { path = \"../../2024/rust/day-01\" }\naoc-2024-day-02 = { path = \"../../2024/rust/day-02\" }\n\n[build-dependencies]\n"
+        );
+        assert!(insert_dependency(manifest, 2023, 25)
+            .unwrap()
+            .contains("aoc-core = { path = \"../aoc-core\" }\naoc-2023-day-25"));
+        assert!(insert_dependency(manifest, 2024, 1).is_err());
+    }
+}
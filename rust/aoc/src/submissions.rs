@@ -0,0 +1,247 @@
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::PathBuf,
+};
+
+use crate::fetch::Fetcher;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// wrong, without a hint about which way
+    Wrong,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page adventofcode.com answers a
+    /// submission with.
+    pub fn from_response(page: &str) -> Result<Verdict, String> {
+        if page.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if page.contains("your answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Ok(Verdict::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Ok(Verdict::Wrong)
+        } else if page.contains("You gave an answer too recently") {
+            Err("answered too recently, wait a bit before trying again".to_string())
+        } else if page.contains("Did you already complete it") {
+            Err("that part is already solved".to_string())
+        } else {
+            Err("could not make sense of the response".to_string())
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+
+    fn parse(input: &str) -> Option<Verdict> {
+        match input {
+            "correct" => Some(Verdict::Correct),
+            "too-high" => Some(Verdict::TooHigh),
+            "too-low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// Every answer that has been submitted, kept as
+/// `<year> <day> <part> <verdict> <answer>` lines in `submissions.txt` in the
+/// input cache directory.
+pub struct SubmissionLog {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn open(path: impl Into<PathBuf>) -> Result<SubmissionLog, String> {
+        let path = path.into();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("could not read {}: {e}", path.display())),
+        };
+        let submissions = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                parse_line(line)
+                    .ok_or_else(|| format!("{} line {}: malformed", path.display(), i + 1))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(SubmissionLog { path, submissions })
+    }
+
+    pub fn submissions(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |s| s.year == year && s.day == day && s.part == part)
+    }
+
+    /// Why `answer` can't be right given the earlier guesses, if it can't.
+    pub fn reject(&self, year: u16, day: u8, part: u8, answer: &str) -> Option<String> {
+        let guess = answer.parse::<i128>().ok();
+        for earlier in self.submissions(year, day, part) {
+            if earlier.verdict == Verdict::Correct {
+                return Some(format!("already solved with {}", earlier.answer));
+            }
+            if earlier.answer == answer {
+                return Some(format!(
+                    "{answer} was already guessed and was {}",
+                    earlier.verdict
+                ));
+            }
+            let (Some(guess), Ok(earlier_guess)) = (guess, earlier.answer.parse::<i128>()) else {
+                continue;
+            };
+            match earlier.verdict {
+                Verdict::TooHigh if guess >= earlier_guess => {
+                    return Some(format!("{earlier_guess} was already too high"));
+                }
+                Verdict::TooLow if guess <= earlier_guess => {
+                    return Some(format!("{earlier_guess} was already too low"));
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    pub fn record(&mut self, submission: Submission) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("could not create {}: {e}", parent.display()))?;
+        }
+        let write_error =
+            |e: std::io::Error| format!("could not write {}: {e}", self.path.display());
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(write_error)?;
+        writeln!(
+            file,
+            "{} {} {} {} {}",
+            submission.year,
+            submission.day,
+            submission.part,
+            submission.verdict.as_str(),
+            submission.answer
+        )
+        .map_err(write_error)?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    /// Checks `answer` against the earlier guesses, submits it if it could be
+    /// right, and records the verdict.
+    pub fn submit(
+        &mut self,
+        fetcher: &dyn Fetcher,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Verdict, String> {
+        if answer.is_empty() || answer.contains('\n') {
+            return Err("answers have to be a single, non-empty line".to_string());
+        }
+        if let Some(reason) = self.reject(year, day, part, answer) {
+            return Err(format!("not submitting {answer}: {reason}"));
+        }
+        let verdict = fetcher.submit(year, day, part, answer)?;
+        self.record(Submission {
+            year,
+            day,
+            part,
+            verdict,
+            answer: answer.to_string(),
+        })?;
+        Ok(verdict)
+    }
+}
+
+fn parse_line(line: &str) -> Option<Submission> {
+    let mut fields = line.splitn(5, ' ');
+    Some(Submission {
+        year: fields.next()?.parse().ok()?,
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        verdict: Verdict::parse(fields.next()?)?,
+        answer: fields.next()?.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::{stub, temp_dir};
+
+    #[test]
+    fn test_from_response() {
+        assert_eq!(
+            Verdict::from_response("<p>That's the right answer!</p>"),
+            Ok(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_response("That's not the right answer; your answer is too low."),
+            Ok(Verdict::TooLow)
+        );
+        assert!(Verdict::from_response("You gave an answer too recently").is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let dir = temp_dir("submissions");
+        let path = dir.join("submissions.txt");
+        let fetcher = stub();
+        let mut log = SubmissionLog::open(&path).unwrap();
+
+        assert_eq!(log.submit(&fetcher, 2024, 1, 1, "20"), Ok(Verdict::TooHigh));
+        assert_eq!(log.submit(&fetcher, 2024, 1, 1, "5"), Ok(Verdict::TooLow));
+        assert!(log.submit(&fetcher, 2024, 1, 1, "25").is_err());
+        assert!(log.submit(&fetcher, 2024, 1, 1, "4").is_err());
+        assert!(log.submit(&fetcher, 2024, 1, 1, "5").is_err());
+        assert_eq!(fetcher.requests.get(), 2);
+        assert_eq!(log.submit(&fetcher, 2024, 1, 1, "11"), Ok(Verdict::Correct));
+        assert!(log.submit(&fetcher, 2024, 1, 1, "12").is_err());
+
+        let reopened = SubmissionLog::open(&path).unwrap();
+        assert_eq!(reopened.submissions(2024, 1, 1).count(), 3);
+        assert_eq!(reopened.reject(2024, 1, 2, "11"), None);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::{self, Answers},
+    format_duration,
+    registry::Day,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Mismatch {
+        expected: String,
+        got: String,
+    },
+    /// nothing recorded in `answers.txt`, so the part isn't run
+    Missing,
+    /// the part returned an error
+    Failed(String),
+    Panicked(String),
+    NoInput(String),
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Correct => "ok",
+            Status::Mismatch { .. } => "MISMATCH",
+            Status::Missing => "missing",
+            Status::Failed(_) => "FAILED",
+            Status::Panicked(_) => "PANIC",
+            Status::NoInput(_) => "no input",
+        }
+    }
+
+    fn detail(&self) -> String {
+        match self {
+            Status::Correct => String::new(),
+            Status::Mismatch { expected, got } => format!(
+                "expected {}, got {}",
+                answers::escape(expected),
+                answers::escape(got)
+            ),
+            Status::Missing => "no answer recorded".to_string(),
+            Status::Failed(message) => message.clone(),
+            Status::Panicked(message) => message.clone(),
+            Status::NoInput(message) => message.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Check {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub elapsed: Option<Duration>,
+}
+
+pub fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+fn check_part(day: &Day, part: u8, input: &str, expected: &str) -> Check {
+    let solve = day.part(part).expect("parts are validated while parsing");
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| solve(input)));
+    let elapsed = start.elapsed();
+    let status = match result {
+        Ok(Ok(answer)) if answer == expected => Status::Correct,
+        Ok(Ok(answer)) => Status::Mismatch {
+            expected: expected.to_string(),
+            got: answer.to_string(),
+        },
+        Ok(Err(e)) => Status::Failed(e.to_string()),
+        Err(payload) => Status::Panicked(panic_message(payload.as_ref())),
+    };
+    Check {
+        year: day.year,
+        day: day.day,
+        part,
+        status,
+        elapsed: Some(elapsed),
+    }
+}
+
+/// Runs every selected part that has a recorded answer and compares the
+/// result against it.
+pub fn verify(selection: Vec<(&'static Day, Vec<u8>)>, answers: &Answers) -> Vec<Check> {
+    // the table reports panics, so keep the default hook from printing them too
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut checks = Vec::new();
+    for (day, parts) in selection {
+        let check = |part: u8, status: Status| Check {
+            year: day.year,
+            day: day.day,
+            part,
+            status,
+            elapsed: None,
+        };
+        let expected: Vec<(u8, Option<&str>)> = parts
+            .iter()
+            .map(|&part| (part, answers.get(day.year, day.day, part)))
+            .collect();
+        let input = if expected.iter().any(|(_, answer)| answer.is_some()) {
+            let path = day.input_path();
+            fs::read_to_string(&path).map_err(|e| format!("could not read {}: {e}", path.display()))
+        } else {
+            Ok(String::new())
+        };
+        for (part, answer) in expected {
+            checks.push(match (answer, &input) {
+                (None, _) => check(part, Status::Missing),
+                (Some(_), Err(e)) => check(part, Status::NoInput(e.clone())),
+                (Some(answer), Ok(input)) => check_part(day, part, input, answer),
+            });
+        }
+    }
+
+    panic::set_hook(hook);
+    checks
+}
+
+pub fn print_table(checks: &[Check]) {
+    println!(
+        "{:<12} {:>4}  {:<9} {:>9}  detail",
+        "day", "part", "status", "time"
+    );
+    for check in checks {
+        let elapsed = check.elapsed.map(format_duration).unwrap_or_default();
+        println!(
+            "{:<12} {:>4}  {:<9} {:>9}  {}",
+            format!("{} day {:02}", check.year, check.day),
+            check.part,
+            check.status.label(),
+            elapsed,
+            check.status.detail()
+        );
+    }
+
+    let count = |label: &str| {
+        checks
+            .iter()
+            .filter(|check| check.status.label() == label)
+            .count()
+    };
+    println!(
+        "\n{} ok, {} mismatched, {} failed, {} panicked, {} missing, {} without input",
+        count("ok"),
+        count("MISMATCH"),
+        count("FAILED"),
+        count("PANIC"),
+        count("missing"),
+        count("no input")
+    );
+}
+
+/// Whether everything that was checked came out right. Missing answers don't
+/// count as failures, so unsolved parts don't fail the run.
+pub fn passed(checks: &[Check]) -> bool {
+    checks
+        .iter()
+        .all(|check| matches!(check.status, Status::Correct | Status::Missing))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_verify() {
+        let day = registry::find(2017, 1).unwrap();
+        let answers = Answers::parse("2017 1 1 1029\n2017 1 2 1\n").unwrap();
+        let checks = verify(vec![(day, vec![1, 2])], &answers);
+        assert_eq!(checks[0].status, Status::Correct);
+        assert_eq!(
+            checks[1].status,
+            Status::Mismatch {
+                expected: "1".to_string(),
+                got: "1220".to_string()
+            }
+        );
+        assert!(!passed(&checks));
+
+        let checks = verify(vec![(day, vec![1, 2])], &Answers::default());
+        assert!(checks.iter().all(|check| check.status == Status::Missing));
+        assert!(passed(&checks));
+
+        let day = registry::find(2024, 24).unwrap();
+        let check = check_part(day, 1, "x00: 1\n\nnot a gate\n", "1");
+        assert!(matches!(check.status, Status::Failed(_)));
+        assert!(!passed(&[check]));
+    }
+}
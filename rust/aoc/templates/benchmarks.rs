@@ -0,0 +1,20 @@
+use aoc_{{year}}_day_{{dd}}::*;
+
+fn main() {
+    divan::main();
+}
+
+fn input() -> String {
+    aoc_core::input::from_env(env!("CARGO_MANIFEST_DIR"))
+}
+
+#[divan::bench]
+fn part1(bencher: divan::Bencher) {
+    let input = input();
+    bencher.bench(|| process_part1(divan::black_box(&input)));
+}
+#[divan::bench]
+fn part2(bencher: divan::Bencher) {
+    let input = input();
+    bencher.bench(|| process_part2(divan::black_box(&input)));
+}
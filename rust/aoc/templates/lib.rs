@@ -0,0 +1,42 @@
+use aoc_core::{Answer, AocError, Solution};
+
+pub struct Day{{dd}};
+
+impl Solution for Day{{dd}} {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day}};
+    type Parsed<'a> = &'a str;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        input
+    }
+
+    fn part1(_input: Self::Parsed<'_>) -> Self::Part1 {
+        "works".to_string()
+    }
+
+    fn part2(_input: Self::Parsed<'_>) -> Self::Part2 {
+        "works".to_string()
+    }
+}
+
+pub fn process_part1(input: &str) -> Result<Answer, AocError> {
+    Day{{dd}}::solve_part1(input)
+}
+
+pub fn process_part2(input: &str) -> Result<Answer, AocError> {
+    Day{{dd}}::solve_part2(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let file = include_str!("../test-input-1.txt");
+        assert_eq!(process_part1(file).unwrap(), "works");
+    }
+}
@@ -0,0 +1,6 @@
+use aoc_{{year}}_day_{{dd}}::process_part1;
+
+fn main() {
+    let file = aoc_core::input::from_args(env!("CARGO_MANIFEST_DIR"));
+    aoc_core::print_or_exit(process_part1(&file));
+}